@@ -9,6 +9,7 @@ import * as beet from '@metaplex-foundation/beet';
 import * as web3 from '@solana/web3.js';
 import * as beetSolana from '@metaplex-foundation/beet-solana';
 import { Key, keyBeet } from '../types/Key';
+import { FrequencyState, frequencyStateBeet } from '../types/FrequencyState';
 
 /**
  * Arguments used to create {@link FrequencyAccount}
@@ -17,8 +18,7 @@ import { Key, keyBeet } from '../types/Key';
  */
 export type FrequencyAccountArgs = {
   key: Key;
-  lastUpdate: beet.bignum;
-  period: beet.bignum;
+  frequencies: FrequencyState[];
 };
 /**
  * Holds the data for the {@link FrequencyAccount} Account and provides de/serialization
@@ -30,15 +30,14 @@ export type FrequencyAccountArgs = {
 export class FrequencyAccount implements FrequencyAccountArgs {
   private constructor(
     readonly key: Key,
-    readonly lastUpdate: beet.bignum,
-    readonly period: beet.bignum,
+    readonly frequencies: FrequencyState[],
   ) {}
 
   /**
    * Creates a {@link FrequencyAccount} instance from the provided args.
    */
   static fromArgs(args: FrequencyAccountArgs) {
-    return new FrequencyAccount(args.key, args.lastUpdate, args.period);
+    return new FrequencyAccount(args.key, args.frequencies);
   }
 
   /**
@@ -100,31 +99,33 @@ export class FrequencyAccount implements FrequencyAccountArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link FrequencyAccount}
+   * {@link FrequencyAccount} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return frequencyAccountBeet.byteSize;
+  static byteSize(args: FrequencyAccountArgs) {
+    const instance = FrequencyAccount.fromArgs(args);
+    return frequencyAccountBeet.toFixedFromValue(instance).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link FrequencyAccount} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: FrequencyAccountArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
-    return connection.getMinimumBalanceForRentExemption(FrequencyAccount.byteSize, commitment);
-  }
-
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link FrequencyAccount} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === FrequencyAccount.byteSize;
+    return connection.getMinimumBalanceForRentExemption(
+      FrequencyAccount.byteSize(args),
+      commitment,
+    );
   }
 
   /**
@@ -134,28 +135,7 @@ export class FrequencyAccount implements FrequencyAccountArgs {
   pretty() {
     return {
       key: 'Key.' + Key[this.key],
-      lastUpdate: (() => {
-        const x = <{ toNumber: () => number }>this.lastUpdate;
-        if (typeof x.toNumber === 'function') {
-          try {
-            return x.toNumber();
-          } catch (_) {
-            return x;
-          }
-        }
-        return x;
-      })(),
-      period: (() => {
-        const x = <{ toNumber: () => number }>this.period;
-        if (typeof x.toNumber === 'function') {
-          try {
-            return x.toNumber();
-          } catch (_) {
-            return x;
-          }
-        }
-        return x;
-      })(),
+      frequencies: this.frequencies,
     };
   }
 }
@@ -164,11 +144,13 @@ export class FrequencyAccount implements FrequencyAccountArgs {
  * @category Accounts
  * @category generated
  */
-export const frequencyAccountBeet = new beet.BeetStruct<FrequencyAccount, FrequencyAccountArgs>(
+export const frequencyAccountBeet = new beet.FixableBeetStruct<
+  FrequencyAccount,
+  FrequencyAccountArgs
+>(
   [
     ['key', keyBeet],
-    ['lastUpdate', beet.i64],
-    ['period', beet.i64],
+    ['frequencies', beet.array(frequencyStateBeet)],
   ],
   FrequencyAccount.fromArgs,
   'FrequencyAccount',
@@ -856,6 +856,610 @@ export class DuplicatedOperationNameError extends Error {
 createErrorFromCodeLookup.set(0x27, () => new DuplicatedOperationNameError());
 createErrorFromNameLookup.set('DuplicatedOperationName', () => new DuplicatedOperationNameError());
 
+/**
+ * AlignmentError: 'Could not determine alignemnt'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AlignmentErrorError extends Error {
+  readonly code: number = 0x28;
+  readonly name: string = 'AlignmentError';
+  constructor() {
+    super('Could not determine alignemnt');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AlignmentErrorError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x28, () => new AlignmentErrorError());
+createErrorFromNameLookup.set('AlignmentError', () => new AlignmentErrorError());
+
+/**
+ * UpdateAuthorityMismatch: 'Signer is not the RuleSet update authority'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class UpdateAuthorityMismatchError extends Error {
+  readonly code: number = 0x29;
+  readonly name: string = 'UpdateAuthorityMismatch';
+  constructor() {
+    super('Signer is not the RuleSet update authority');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, UpdateAuthorityMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x29, () => new UpdateAuthorityMismatchError());
+createErrorFromNameLookup.set('UpdateAuthorityMismatch', () => new UpdateAuthorityMismatchError());
+
+/**
+ * PendingUpdateAuthorityMismatch: 'Signer is not the pending RuleSet update authority'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PendingUpdateAuthorityMismatchError extends Error {
+  readonly code: number = 0x2a;
+  readonly name: string = 'PendingUpdateAuthorityMismatch';
+  constructor() {
+    super('Signer is not the pending RuleSet update authority');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PendingUpdateAuthorityMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x2a, () => new PendingUpdateAuthorityMismatchError());
+createErrorFromNameLookup.set(
+  'PendingUpdateAuthorityMismatch',
+  () => new PendingUpdateAuthorityMismatchError(),
+);
+
+/**
+ * RuleSetFrozen: 'RuleSet is frozen'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RuleSetFrozenError extends Error {
+  readonly code: number = 0x2b;
+  readonly name: string = 'RuleSetFrozen';
+  constructor() {
+    super('RuleSet is frozen');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RuleSetFrozenError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x2b, () => new RuleSetFrozenError());
+createErrorFromNameLookup.set('RuleSetFrozen', () => new RuleSetFrozenError());
+
+/**
+ * RuleSetHashMismatch: 'RuleSet revision hash does not match the expected hash'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RuleSetHashMismatchError extends Error {
+  readonly code: number = 0x2c;
+  readonly name: string = 'RuleSetHashMismatch';
+  constructor() {
+    super('RuleSet revision hash does not match the expected hash');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RuleSetHashMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x2c, () => new RuleSetHashMismatchError());
+createErrorFromNameLookup.set('RuleSetHashMismatch', () => new RuleSetHashMismatchError());
+
+/**
+ * MissingNamespaceFallback: 'Namespace rule has no operation to fall back to'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingNamespaceFallbackError extends Error {
+  readonly code: number = 0x2d;
+  readonly name: string = 'MissingNamespaceFallback';
+  constructor() {
+    super('Namespace rule has no operation to fall back to');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingNamespaceFallbackError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x2d, () => new MissingNamespaceFallbackError());
+createErrorFromNameLookup.set(
+  'MissingNamespaceFallback',
+  () => new MissingNamespaceFallbackError(),
+);
+
+/**
+ * EmptyRuleGroup: 'All or Any rule does not contain any rules'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EmptyRuleGroupError extends Error {
+  readonly code: number = 0x2e;
+  readonly name: string = 'EmptyRuleGroup';
+  constructor() {
+    super('All or Any rule does not contain any rules');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EmptyRuleGroupError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x2e, () => new EmptyRuleGroupError());
+createErrorFromNameLookup.set('EmptyRuleGroup', () => new EmptyRuleGroupError());
+
+/**
+ * FieldTooLong: 'Field does not fit in 32 bytes'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FieldTooLongError extends Error {
+  readonly code: number = 0x2f;
+  readonly name: string = 'FieldTooLong';
+  constructor() {
+    super('Field does not fit in 32 bytes');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FieldTooLongError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x2f, () => new FieldTooLongError());
+createErrorFromNameLookup.set('FieldTooLong', () => new FieldTooLongError());
+
+/**
+ * UnreachableOperation: 'Operation can never be selected'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class UnreachableOperationError extends Error {
+  readonly code: number = 0x30;
+  readonly name: string = 'UnreachableOperation';
+  constructor() {
+    super('Operation can never be selected');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, UnreachableOperationError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x30, () => new UnreachableOperationError());
+createErrorFromNameLookup.set('UnreachableOperation', () => new UnreachableOperationError());
+
+/**
+ * TimeWindowCheckFailed: 'Time window check failed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TimeWindowCheckFailedError extends Error {
+  readonly code: number = 0x31;
+  readonly name: string = 'TimeWindowCheckFailed';
+  constructor() {
+    super('Time window check failed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TimeWindowCheckFailedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x31, () => new TimeWindowCheckFailedError());
+createErrorFromNameLookup.set('TimeWindowCheckFailed', () => new TimeWindowCheckFailedError());
+
+/**
+ * InvalidTimeSource: 'Invalid time source'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTimeSourceError extends Error {
+  readonly code: number = 0x32;
+  readonly name: string = 'InvalidTimeSource';
+  constructor() {
+    super('Invalid time source');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTimeSourceError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x32, () => new InvalidTimeSourceError());
+createErrorFromNameLookup.set('InvalidTimeSource', () => new InvalidTimeSourceError());
+
+/**
+ * AccountDataCheckFailed: 'Account Data check failed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AccountDataCheckFailedError extends Error {
+  readonly code: number = 0x33;
+  readonly name: string = 'AccountDataCheckFailed';
+  constructor() {
+    super('Account Data check failed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AccountDataCheckFailedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x33, () => new AccountDataCheckFailedError());
+createErrorFromNameLookup.set('AccountDataCheckFailed', () => new AccountDataCheckFailedError());
+
+/**
+ * TokenAccountCheckFailed: 'Token Account check failed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TokenAccountCheckFailedError extends Error {
+  readonly code: number = 0x34;
+  readonly name: string = 'TokenAccountCheckFailed';
+  constructor() {
+    super('Token Account check failed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TokenAccountCheckFailedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x34, () => new TokenAccountCheckFailedError());
+createErrorFromNameLookup.set('TokenAccountCheckFailed', () => new TokenAccountCheckFailedError());
+
+/**
+ * InvalidTokenAccountCheck: 'Invalid token account check'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTokenAccountCheckError extends Error {
+  readonly code: number = 0x35;
+  readonly name: string = 'InvalidTokenAccountCheck';
+  constructor() {
+    super('Invalid token account check');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTokenAccountCheckError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x35, () => new InvalidTokenAccountCheckError());
+createErrorFromNameLookup.set(
+  'InvalidTokenAccountCheck',
+  () => new InvalidTokenAccountCheckError(),
+);
+
+/**
+ * TokenGateCheckFailed: 'Token Gate check failed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class TokenGateCheckFailedError extends Error {
+  readonly code: number = 0x36;
+  readonly name: string = 'TokenGateCheckFailed';
+  constructor() {
+    super('Token Gate check failed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, TokenGateCheckFailedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x36, () => new TokenGateCheckFailedError());
+createErrorFromNameLookup.set('TokenGateCheckFailed', () => new TokenGateCheckFailedError());
+
+/**
+ * FieldCompareCheckFailed: 'Field Compare check failed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FieldCompareCheckFailedError extends Error {
+  readonly code: number = 0x37;
+  readonly name: string = 'FieldCompareCheckFailed';
+  constructor() {
+    super('Field Compare check failed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FieldCompareCheckFailedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x37, () => new FieldCompareCheckFailedError());
+createErrorFromNameLookup.set('FieldCompareCheckFailed', () => new FieldCompareCheckFailedError());
+
+/**
+ * ThresholdCheckFailed: 'Threshold check failed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ThresholdCheckFailedError extends Error {
+  readonly code: number = 0x38;
+  readonly name: string = 'ThresholdCheckFailed';
+  constructor() {
+    super('Threshold check failed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ThresholdCheckFailedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x38, () => new ThresholdCheckFailedError());
+createErrorFromNameLookup.set('ThresholdCheckFailed', () => new ThresholdCheckFailedError());
+
+/**
+ * InvalidThreshold: 'Invalid threshold'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidThresholdError extends Error {
+  readonly code: number = 0x39;
+  readonly name: string = 'InvalidThreshold';
+  constructor() {
+    super('Invalid threshold');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidThresholdError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x39, () => new InvalidThresholdError());
+createErrorFromNameLookup.set('InvalidThreshold', () => new InvalidThresholdError());
+
+/**
+ * InstructionMatchCheckFailed: 'Instruction Match check failed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InstructionMatchCheckFailedError extends Error {
+  readonly code: number = 0x3a;
+  readonly name: string = 'InstructionMatchCheckFailed';
+  constructor() {
+    super('Instruction Match check failed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InstructionMatchCheckFailedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x3a, () => new InstructionMatchCheckFailedError());
+createErrorFromNameLookup.set(
+  'InstructionMatchCheckFailed',
+  () => new InstructionMatchCheckFailedError(),
+);
+
+/**
+ * RoyaltyPaymentCheckFailed: 'Royalty Payment check failed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RoyaltyPaymentCheckFailedError extends Error {
+  readonly code: number = 0x3b;
+  readonly name: string = 'RoyaltyPaymentCheckFailed';
+  constructor() {
+    super('Royalty Payment check failed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RoyaltyPaymentCheckFailedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x3b, () => new RoyaltyPaymentCheckFailedError());
+createErrorFromNameLookup.set(
+  'RoyaltyPaymentCheckFailed',
+  () => new RoyaltyPaymentCheckFailedError(),
+);
+
+/**
+ * Ed25519AttestationCheckFailed: 'Ed25519 attestation is missing or stale'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class Ed25519AttestationCheckFailedError extends Error {
+  readonly code: number = 0x3c;
+  readonly name: string = 'Ed25519AttestationCheckFailed';
+  constructor() {
+    super('Ed25519 attestation is missing or stale');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, Ed25519AttestationCheckFailedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x3c, () => new Ed25519AttestationCheckFailedError());
+createErrorFromNameLookup.set(
+  'Ed25519AttestationCheckFailed',
+  () => new Ed25519AttestationCheckFailedError(),
+);
+
+/**
+ * MintListMatchCheckFailed: 'Mint List Match check failed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MintListMatchCheckFailedError extends Error {
+  readonly code: number = 0x3d;
+  readonly name: string = 'MintListMatchCheckFailed';
+  constructor() {
+    super('Mint List Match check failed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MintListMatchCheckFailedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x3d, () => new MintListMatchCheckFailedError());
+createErrorFromNameLookup.set(
+  'MintListMatchCheckFailed',
+  () => new MintListMatchCheckFailedError(),
+);
+
+/**
+ * MintTreeMatchCheckFailed: 'Mint Tree Match check failed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MintTreeMatchCheckFailedError extends Error {
+  readonly code: number = 0x3e;
+  readonly name: string = 'MintTreeMatchCheckFailed';
+  constructor() {
+    super('Mint Tree Match check failed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MintTreeMatchCheckFailedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x3e, () => new MintTreeMatchCheckFailedError());
+createErrorFromNameLookup.set(
+  'MintTreeMatchCheckFailed',
+  () => new MintTreeMatchCheckFailedError(),
+);
+
+/**
+ * RuleSetReferenceCycle: 'RuleSet reference cycle detected'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RuleSetReferenceCycleError extends Error {
+  readonly code: number = 0x3f;
+  readonly name: string = 'RuleSetReferenceCycle';
+  constructor() {
+    super('RuleSet reference cycle detected');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RuleSetReferenceCycleError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x3f, () => new RuleSetReferenceCycleError());
+createErrorFromNameLookup.set('RuleSetReferenceCycle', () => new RuleSetReferenceCycleError());
+
+/**
+ * RuleSetReferenceTooDeep: 'RuleSet references nested too deeply'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RuleSetReferenceTooDeepError extends Error {
+  readonly code: number = 0x40;
+  readonly name: string = 'RuleSetReferenceTooDeep';
+  constructor() {
+    super('RuleSet references nested too deeply');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RuleSetReferenceTooDeepError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x40, () => new RuleSetReferenceTooDeepError());
+createErrorFromNameLookup.set('RuleSetReferenceTooDeep', () => new RuleSetReferenceTooDeepError());
+
+/**
+ * RuleSetHashUnavailable: 'RuleSet revision hash is not available'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RuleSetHashUnavailableError extends Error {
+  readonly code: number = 0x41;
+  readonly name: string = 'RuleSetHashUnavailable';
+  constructor() {
+    super('RuleSet revision hash is not available');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RuleSetHashUnavailableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x41, () => new RuleSetHashUnavailableError());
+createErrorFromNameLookup.set('RuleSetHashUnavailable', () => new RuleSetHashUnavailableError());
+
+/**
+ * InvalidAmountRange: 'Invalid amount range'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidAmountRangeError extends Error {
+  readonly code: number = 0x42;
+  readonly name: string = 'InvalidAmountRange';
+  constructor() {
+    super('Invalid amount range');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidAmountRangeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x42, () => new InvalidAmountRangeError());
+createErrorFromNameLookup.set('InvalidAmountRange', () => new InvalidAmountRangeError());
+
+/**
+ * UnsortedProgramSet: 'Program set is not sorted or contains duplicates'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class UnsortedProgramSetError extends Error {
+  readonly code: number = 0x43;
+  readonly name: string = 'UnsortedProgramSet';
+  constructor() {
+    super('Program set is not sorted or contains duplicates');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, UnsortedProgramSetError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x43, () => new UnsortedProgramSetError());
+createErrorFromNameLookup.set('UnsortedProgramSet', () => new UnsortedProgramSetError());
+
+/**
+ * InvalidTimeWindow: 'Time window start is not before its end'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTimeWindowError extends Error {
+  readonly code: number = 0x44;
+  readonly name: string = 'InvalidTimeWindow';
+  constructor() {
+    super('Time window start is not before its end');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTimeWindowError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x44, () => new InvalidTimeWindowError());
+createErrorFromNameLookup.set('InvalidTimeWindow', () => new InvalidTimeWindowError());
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
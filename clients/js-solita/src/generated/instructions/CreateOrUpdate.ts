@@ -36,7 +36,7 @@ export const CreateOrUpdateStruct = new beet.FixableBeetArgsStruct<
 /**
  * Accounts required by the _CreateOrUpdate_ instruction
  *
- * @property [_writable_, **signer**] payer Payer and creator of the RuleSet
+ * @property [_writable_, **signer**] payer Payer and update authority of the RuleSet
  * @property [_writable_] ruleSetPda The PDA account where the RuleSet is stored
  * @property [] bufferPda (optional) The buffer to copy a complete ruleset from
  * @category Instructions
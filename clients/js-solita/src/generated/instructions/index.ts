@@ -1,4 +1,9 @@
+export * from './AcceptUpdateAuthority';
+export * from './CloseRuleSet';
 export * from './CreateOrUpdate';
+export * from './FreezeRuleSet';
 export * from './PuffRuleSet';
+export * from './Simulate';
+export * from './TransferUpdateAuthority';
 export * from './Validate';
 export * from './WriteToBuffer';
@@ -17,6 +17,7 @@ import * as beet from '@metaplex-foundation/beet';
  */
 export type CreateOrUpdateArgsRecord = {
   V1: { serializedRuleSet: Uint8Array };
+  V2: { serializedRuleSet: Uint8Array; lint: boolean };
 };
 
 /**
@@ -35,6 +36,9 @@ export type CreateOrUpdateArgs = beet.DataEnumKeyAsKind<CreateOrUpdateArgsRecord
 export const isCreateOrUpdateArgsV1 = (
   x: CreateOrUpdateArgs,
 ): x is CreateOrUpdateArgs & { __kind: 'V1' } => x.__kind === 'V1';
+export const isCreateOrUpdateArgsV2 = (
+  x: CreateOrUpdateArgs,
+): x is CreateOrUpdateArgs & { __kind: 'V2' } => x.__kind === 'V2';
 
 /**
  * @category userTypes
@@ -48,4 +52,14 @@ export const createOrUpdateArgsBeet = beet.dataEnum<CreateOrUpdateArgsRecord>([
       'CreateOrUpdateArgsRecord["V1"]',
     ),
   ],
+  [
+    'V2',
+    new beet.FixableBeetArgsStruct<CreateOrUpdateArgsRecord['V2']>(
+      [
+        ['serializedRuleSet', beet.bytes],
+        ['lint', beet.bool],
+      ],
+      'CreateOrUpdateArgsRecord["V2"]',
+    ),
+  ],
 ]) as beet.FixableBeet<CreateOrUpdateArgs, CreateOrUpdateArgs>;
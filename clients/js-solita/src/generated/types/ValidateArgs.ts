@@ -23,6 +23,13 @@ export type ValidateArgsRecord = {
     updateRuleState: boolean;
     ruleSetRevision: beet.COption<beet.bignum>;
   };
+  V2: {
+    operation: string;
+    payload: Payload;
+    updateRuleState: boolean;
+    ruleSetRevision: beet.COption<beet.bignum>;
+    ruleSetHash: number[] /* size: 32 */;
+  };
 };
 
 /**
@@ -40,6 +47,8 @@ export type ValidateArgs = beet.DataEnumKeyAsKind<ValidateArgsRecord>;
 
 export const isValidateArgsV1 = (x: ValidateArgs): x is ValidateArgs & { __kind: 'V1' } =>
   x.__kind === 'V1';
+export const isValidateArgsV2 = (x: ValidateArgs): x is ValidateArgs & { __kind: 'V2' } =>
+  x.__kind === 'V2';
 
 /**
  * @category userTypes
@@ -58,4 +67,17 @@ export const validateArgsBeet = beet.dataEnum<ValidateArgsRecord>([
       'ValidateArgsRecord["V1"]',
     ),
   ],
+  [
+    'V2',
+    new beet.FixableBeetArgsStruct<ValidateArgsRecord['V2']>(
+      [
+        ['operation', beet.utf8String],
+        ['payload', payloadBeet],
+        ['updateRuleState', beet.bool],
+        ['ruleSetRevision', beet.coption(beet.u64)],
+        ['ruleSetHash', beet.uniformFixedSizeArray(beet.u8, 32)],
+      ],
+      'ValidateArgsRecord["V2"]',
+    ),
+  ],
 ]) as beet.FixableBeet<ValidateArgs, ValidateArgs>;
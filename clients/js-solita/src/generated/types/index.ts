@@ -1,11 +1,21 @@
+export * from './AcceptUpdateAuthorityArgs';
+export * from './CloseRuleSetArgs';
 export * from './CreateOrUpdateArgs';
+export * from './FreezeRuleSetArgs';
+export * from './FrequencyState';
 export * from './Key';
 export * from './Payload';
 export * from './PayloadType';
 export * from './ProofInfo';
 export * from './PuffRuleSetArgs';
+export * from './RuleOutcome';
 export * from './RuleSetHeader';
 export * from './RuleSetRevisionMapV1';
+export * from './RuleSetRevisionMapV2';
+export * from './RuleTrace';
+export * from './RuleTraceEntry';
 export * from './SeedsVec';
+export * from './SimulateArgs';
+export * from './TransferUpdateAuthorityArgs';
 export * from './ValidateArgs';
 export * from './WriteToBufferArgs';
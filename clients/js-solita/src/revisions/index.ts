@@ -2,3 +2,4 @@ export * from './shared';
 export * from './v1';
 export * from './v2';
 export * from './revision';
+export * from './revisionMap';
@@ -1,5 +1,5 @@
 import { BN } from 'bn.js';
-import { getHeader, getRevisionMapV1 } from './revisionMap';
+import { getHeader, getRevisionMap } from './revisionMap';
 import { RuleSetRevisionV1, deserializeRuleSetRevisionV1, serializeRuleSetRevisionV1 } from './v1';
 import { RuleSetRevisionV2, deserializeRuleSetRevisionV2, serializeRuleSetRevisionV2 } from './v2';
 
@@ -37,7 +37,7 @@ export const getRuleSetRevisionFromJson = (json: string): RuleSetRevision => {
 
 export const getLatestRuleSetRevision = (data: Buffer): RuleSetRevision => {
   const header = getHeader(data);
-  const revmap = getRevisionMapV1(data);
+  const revmap = getRevisionMap(data);
   const latestRevision = new BN(
     revmap.ruleSetRevisions[revmap.ruleSetRevisions.length - 1],
   ).toNumber();
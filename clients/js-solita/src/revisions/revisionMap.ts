@@ -2,20 +2,59 @@ import { BN } from 'bn.js';
 import {
   RuleSetHeader,
   RuleSetRevisionMapV1,
+  RuleSetRevisionMapV2,
   ruleSetHeaderBeet,
   ruleSetRevisionMapV1Beet,
+  ruleSetRevisionMapV2Beet,
 } from '../generated';
 
+export type RuleSetRevisionMap =
+  | ({ version: 1 } & RuleSetRevisionMapV1)
+  | ({ version: 2 } & RuleSetRevisionMapV2);
+
 export const getHeader = (data: Buffer): RuleSetHeader => {
   const [header] = ruleSetHeaderBeet.deserialize(data.subarray(0, 9));
   return header;
 };
 
-export const getRevisionMapV1 = (data: Buffer): RuleSetRevisionMapV1 => {
+const getRevisionMapLocation = (data: Buffer, version: number): number => {
   const header = getHeader(data);
   const revisionMapLocation = new BN(header.revMapVersionLocation).toNumber();
+  if (data[revisionMapLocation] !== version) {
+    throw new Error('Unsupported revision map version: ' + data[revisionMapLocation]);
+  }
+  return revisionMapLocation;
+};
+
+export const getRevisionMapV1 = (data: Buffer): RuleSetRevisionMapV1 => {
+  const revisionMapLocation = getRevisionMapLocation(data, 1);
   const [revisionMap] = ruleSetRevisionMapV1Beet.deserialize(
     data.subarray(revisionMapLocation + 1, data.length),
   );
   return revisionMap;
 };
+
+export const getRevisionMapV2 = (data: Buffer): RuleSetRevisionMapV2 => {
+  const revisionMapLocation = getRevisionMapLocation(data, 2);
+  const [revisionMap] = ruleSetRevisionMapV2Beet.deserialize(
+    data.subarray(revisionMapLocation + 1, data.length),
+  );
+  return revisionMap;
+};
+
+export const getRevisionMap = (data: Buffer): RuleSetRevisionMap => {
+  const header = getHeader(data);
+  const version = data[new BN(header.revMapVersionLocation).toNumber()];
+  if (version === 1) return { version, ...getRevisionMapV1(data) };
+  if (version === 2) return { version, ...getRevisionMapV2(data) };
+  throw new Error('Unsupported revision map version: ' + version);
+};
+
+/**
+ * The frozen flag is stored in the revision map since the account header is
+ * never versioned. RuleSets with a version 1 revision map are never frozen.
+ */
+export const isRuleSetFrozen = (data: Buffer): boolean => {
+  const revisionMap = getRevisionMap(data);
+  return revisionMap.version === 2 && revisionMap.frozen;
+};
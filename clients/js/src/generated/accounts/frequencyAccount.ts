@@ -19,21 +19,30 @@ import {
   gpaBuilder,
   publicKey as toPublicKey,
 } from '@metaplex-foundation/umi';
-import { Serializer, i64, struct } from '@metaplex-foundation/umi/serializers';
-import { Key, KeyArgs, getKeySerializer } from '../types';
+import {
+  Serializer,
+  array,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  FrequencyState,
+  FrequencyStateArgs,
+  Key,
+  KeyArgs,
+  getFrequencyStateSerializer,
+  getKeySerializer,
+} from '../types';
 
 export type FrequencyAccount = Account<FrequencyAccountAccountData>;
 
 export type FrequencyAccountAccountData = {
   key: Key;
-  lastUpdate: bigint;
-  period: bigint;
+  frequencies: Array<FrequencyState>;
 };
 
 export type FrequencyAccountAccountDataArgs = {
   key: KeyArgs;
-  lastUpdate: number | bigint;
-  period: number | bigint;
+  frequencies: Array<FrequencyStateArgs>;
 };
 
 export function getFrequencyAccountAccountDataSerializer(): Serializer<
@@ -43,8 +52,7 @@ export function getFrequencyAccountAccountDataSerializer(): Serializer<
   return struct<FrequencyAccountAccountData>(
     [
       ['key', getKeySerializer()],
-      ['lastUpdate', i64()],
-      ['period', i64()],
+      ['frequencies', array(getFrequencyStateSerializer())],
     ],
     { description: 'FrequencyAccountAccountData' }
   ) as Serializer<FrequencyAccountAccountDataArgs, FrequencyAccountAccountData>;
@@ -125,18 +133,12 @@ export function getFrequencyAccountGpaBuilder(
   return gpaBuilder(context, programId)
     .registerFields<{
       key: KeyArgs;
-      lastUpdate: number | bigint;
-      period: number | bigint;
+      frequencies: Array<FrequencyStateArgs>;
     }>({
       key: [0, getKeySerializer()],
-      lastUpdate: [1, i64()],
-      period: [9, i64()],
+      frequencies: [1, array(getFrequencyStateSerializer())],
     })
     .deserializeUsing<FrequencyAccount>((account) =>
       deserializeFrequencyAccount(account)
     );
 }
-
-export function getFrequencyAccountSize(): number {
-  return 17;
-}
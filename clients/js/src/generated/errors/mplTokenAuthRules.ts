@@ -585,6 +585,383 @@ export class AlignmentErrorError extends ProgramError {
 codeToErrorMap.set(0x28, AlignmentErrorError);
 nameToErrorMap.set('AlignmentError', AlignmentErrorError);
 
+/** UpdateAuthorityMismatch: Signer is not the RuleSet update authority */
+export class UpdateAuthorityMismatchError extends ProgramError {
+  readonly name: string = 'UpdateAuthorityMismatch';
+
+  readonly code: number = 0x29; // 41
+
+  constructor(program: Program, cause?: Error) {
+    super('Signer is not the RuleSet update authority', program, cause);
+  }
+}
+codeToErrorMap.set(0x29, UpdateAuthorityMismatchError);
+nameToErrorMap.set('UpdateAuthorityMismatch', UpdateAuthorityMismatchError);
+
+/** PendingUpdateAuthorityMismatch: Signer is not the pending RuleSet update authority */
+export class PendingUpdateAuthorityMismatchError extends ProgramError {
+  readonly name: string = 'PendingUpdateAuthorityMismatch';
+
+  readonly code: number = 0x2a; // 42
+
+  constructor(program: Program, cause?: Error) {
+    super('Signer is not the pending RuleSet update authority', program, cause);
+  }
+}
+codeToErrorMap.set(0x2a, PendingUpdateAuthorityMismatchError);
+nameToErrorMap.set(
+  'PendingUpdateAuthorityMismatch',
+  PendingUpdateAuthorityMismatchError
+);
+
+/** RuleSetFrozen: RuleSet is frozen */
+export class RuleSetFrozenError extends ProgramError {
+  readonly name: string = 'RuleSetFrozen';
+
+  readonly code: number = 0x2b; // 43
+
+  constructor(program: Program, cause?: Error) {
+    super('RuleSet is frozen', program, cause);
+  }
+}
+codeToErrorMap.set(0x2b, RuleSetFrozenError);
+nameToErrorMap.set('RuleSetFrozen', RuleSetFrozenError);
+
+/** RuleSetHashMismatch: RuleSet revision hash does not match the expected hash */
+export class RuleSetHashMismatchError extends ProgramError {
+  readonly name: string = 'RuleSetHashMismatch';
+
+  readonly code: number = 0x2c; // 44
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'RuleSet revision hash does not match the expected hash',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x2c, RuleSetHashMismatchError);
+nameToErrorMap.set('RuleSetHashMismatch', RuleSetHashMismatchError);
+
+/** MissingNamespaceFallback: Namespace rule has no operation to fall back to */
+export class MissingNamespaceFallbackError extends ProgramError {
+  readonly name: string = 'MissingNamespaceFallback';
+
+  readonly code: number = 0x2d; // 45
+
+  constructor(program: Program, cause?: Error) {
+    super('Namespace rule has no operation to fall back to', program, cause);
+  }
+}
+codeToErrorMap.set(0x2d, MissingNamespaceFallbackError);
+nameToErrorMap.set('MissingNamespaceFallback', MissingNamespaceFallbackError);
+
+/** EmptyRuleGroup: All or Any rule does not contain any rules */
+export class EmptyRuleGroupError extends ProgramError {
+  readonly name: string = 'EmptyRuleGroup';
+
+  readonly code: number = 0x2e; // 46
+
+  constructor(program: Program, cause?: Error) {
+    super('All or Any rule does not contain any rules', program, cause);
+  }
+}
+codeToErrorMap.set(0x2e, EmptyRuleGroupError);
+nameToErrorMap.set('EmptyRuleGroup', EmptyRuleGroupError);
+
+/** FieldTooLong: Field does not fit in 32 bytes */
+export class FieldTooLongError extends ProgramError {
+  readonly name: string = 'FieldTooLong';
+
+  readonly code: number = 0x2f; // 47
+
+  constructor(program: Program, cause?: Error) {
+    super('Field does not fit in 32 bytes', program, cause);
+  }
+}
+codeToErrorMap.set(0x2f, FieldTooLongError);
+nameToErrorMap.set('FieldTooLong', FieldTooLongError);
+
+/** UnreachableOperation: Operation can never be selected */
+export class UnreachableOperationError extends ProgramError {
+  readonly name: string = 'UnreachableOperation';
+
+  readonly code: number = 0x30; // 48
+
+  constructor(program: Program, cause?: Error) {
+    super('Operation can never be selected', program, cause);
+  }
+}
+codeToErrorMap.set(0x30, UnreachableOperationError);
+nameToErrorMap.set('UnreachableOperation', UnreachableOperationError);
+
+/** TimeWindowCheckFailed: Time window check failed */
+export class TimeWindowCheckFailedError extends ProgramError {
+  readonly name: string = 'TimeWindowCheckFailed';
+
+  readonly code: number = 0x31; // 49
+
+  constructor(program: Program, cause?: Error) {
+    super('Time window check failed', program, cause);
+  }
+}
+codeToErrorMap.set(0x31, TimeWindowCheckFailedError);
+nameToErrorMap.set('TimeWindowCheckFailed', TimeWindowCheckFailedError);
+
+/** InvalidTimeSource: Invalid time source */
+export class InvalidTimeSourceError extends ProgramError {
+  readonly name: string = 'InvalidTimeSource';
+
+  readonly code: number = 0x32; // 50
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid time source', program, cause);
+  }
+}
+codeToErrorMap.set(0x32, InvalidTimeSourceError);
+nameToErrorMap.set('InvalidTimeSource', InvalidTimeSourceError);
+
+/** AccountDataCheckFailed: Account Data check failed */
+export class AccountDataCheckFailedError extends ProgramError {
+  readonly name: string = 'AccountDataCheckFailed';
+
+  readonly code: number = 0x33; // 51
+
+  constructor(program: Program, cause?: Error) {
+    super('Account Data check failed', program, cause);
+  }
+}
+codeToErrorMap.set(0x33, AccountDataCheckFailedError);
+nameToErrorMap.set('AccountDataCheckFailed', AccountDataCheckFailedError);
+
+/** TokenAccountCheckFailed: Token Account check failed */
+export class TokenAccountCheckFailedError extends ProgramError {
+  readonly name: string = 'TokenAccountCheckFailed';
+
+  readonly code: number = 0x34; // 52
+
+  constructor(program: Program, cause?: Error) {
+    super('Token Account check failed', program, cause);
+  }
+}
+codeToErrorMap.set(0x34, TokenAccountCheckFailedError);
+nameToErrorMap.set('TokenAccountCheckFailed', TokenAccountCheckFailedError);
+
+/** InvalidTokenAccountCheck: Invalid token account check */
+export class InvalidTokenAccountCheckError extends ProgramError {
+  readonly name: string = 'InvalidTokenAccountCheck';
+
+  readonly code: number = 0x35; // 53
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid token account check', program, cause);
+  }
+}
+codeToErrorMap.set(0x35, InvalidTokenAccountCheckError);
+nameToErrorMap.set('InvalidTokenAccountCheck', InvalidTokenAccountCheckError);
+
+/** TokenGateCheckFailed: Token Gate check failed */
+export class TokenGateCheckFailedError extends ProgramError {
+  readonly name: string = 'TokenGateCheckFailed';
+
+  readonly code: number = 0x36; // 54
+
+  constructor(program: Program, cause?: Error) {
+    super('Token Gate check failed', program, cause);
+  }
+}
+codeToErrorMap.set(0x36, TokenGateCheckFailedError);
+nameToErrorMap.set('TokenGateCheckFailed', TokenGateCheckFailedError);
+
+/** FieldCompareCheckFailed: Field Compare check failed */
+export class FieldCompareCheckFailedError extends ProgramError {
+  readonly name: string = 'FieldCompareCheckFailed';
+
+  readonly code: number = 0x37; // 55
+
+  constructor(program: Program, cause?: Error) {
+    super('Field Compare check failed', program, cause);
+  }
+}
+codeToErrorMap.set(0x37, FieldCompareCheckFailedError);
+nameToErrorMap.set('FieldCompareCheckFailed', FieldCompareCheckFailedError);
+
+/** ThresholdCheckFailed: Threshold check failed */
+export class ThresholdCheckFailedError extends ProgramError {
+  readonly name: string = 'ThresholdCheckFailed';
+
+  readonly code: number = 0x38; // 56
+
+  constructor(program: Program, cause?: Error) {
+    super('Threshold check failed', program, cause);
+  }
+}
+codeToErrorMap.set(0x38, ThresholdCheckFailedError);
+nameToErrorMap.set('ThresholdCheckFailed', ThresholdCheckFailedError);
+
+/** InvalidThreshold: Invalid threshold */
+export class InvalidThresholdError extends ProgramError {
+  readonly name: string = 'InvalidThreshold';
+
+  readonly code: number = 0x39; // 57
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid threshold', program, cause);
+  }
+}
+codeToErrorMap.set(0x39, InvalidThresholdError);
+nameToErrorMap.set('InvalidThreshold', InvalidThresholdError);
+
+/** InstructionMatchCheckFailed: Instruction Match check failed */
+export class InstructionMatchCheckFailedError extends ProgramError {
+  readonly name: string = 'InstructionMatchCheckFailed';
+
+  readonly code: number = 0x3a; // 58
+
+  constructor(program: Program, cause?: Error) {
+    super('Instruction Match check failed', program, cause);
+  }
+}
+codeToErrorMap.set(0x3a, InstructionMatchCheckFailedError);
+nameToErrorMap.set(
+  'InstructionMatchCheckFailed',
+  InstructionMatchCheckFailedError
+);
+
+/** RoyaltyPaymentCheckFailed: Royalty Payment check failed */
+export class RoyaltyPaymentCheckFailedError extends ProgramError {
+  readonly name: string = 'RoyaltyPaymentCheckFailed';
+
+  readonly code: number = 0x3b; // 59
+
+  constructor(program: Program, cause?: Error) {
+    super('Royalty Payment check failed', program, cause);
+  }
+}
+codeToErrorMap.set(0x3b, RoyaltyPaymentCheckFailedError);
+nameToErrorMap.set('RoyaltyPaymentCheckFailed', RoyaltyPaymentCheckFailedError);
+
+/** Ed25519AttestationCheckFailed: Ed25519 attestation is missing or stale */
+export class Ed25519AttestationCheckFailedError extends ProgramError {
+  readonly name: string = 'Ed25519AttestationCheckFailed';
+
+  readonly code: number = 0x3c; // 60
+
+  constructor(program: Program, cause?: Error) {
+    super('Ed25519 attestation is missing or stale', program, cause);
+  }
+}
+codeToErrorMap.set(0x3c, Ed25519AttestationCheckFailedError);
+nameToErrorMap.set(
+  'Ed25519AttestationCheckFailed',
+  Ed25519AttestationCheckFailedError
+);
+
+/** MintListMatchCheckFailed: Mint List Match check failed */
+export class MintListMatchCheckFailedError extends ProgramError {
+  readonly name: string = 'MintListMatchCheckFailed';
+
+  readonly code: number = 0x3d; // 61
+
+  constructor(program: Program, cause?: Error) {
+    super('Mint List Match check failed', program, cause);
+  }
+}
+codeToErrorMap.set(0x3d, MintListMatchCheckFailedError);
+nameToErrorMap.set('MintListMatchCheckFailed', MintListMatchCheckFailedError);
+
+/** MintTreeMatchCheckFailed: Mint Tree Match check failed */
+export class MintTreeMatchCheckFailedError extends ProgramError {
+  readonly name: string = 'MintTreeMatchCheckFailed';
+
+  readonly code: number = 0x3e; // 62
+
+  constructor(program: Program, cause?: Error) {
+    super('Mint Tree Match check failed', program, cause);
+  }
+}
+codeToErrorMap.set(0x3e, MintTreeMatchCheckFailedError);
+nameToErrorMap.set('MintTreeMatchCheckFailed', MintTreeMatchCheckFailedError);
+
+/** RuleSetReferenceCycle: RuleSet reference cycle detected */
+export class RuleSetReferenceCycleError extends ProgramError {
+  readonly name: string = 'RuleSetReferenceCycle';
+
+  readonly code: number = 0x3f; // 63
+
+  constructor(program: Program, cause?: Error) {
+    super('RuleSet reference cycle detected', program, cause);
+  }
+}
+codeToErrorMap.set(0x3f, RuleSetReferenceCycleError);
+nameToErrorMap.set('RuleSetReferenceCycle', RuleSetReferenceCycleError);
+
+/** RuleSetReferenceTooDeep: RuleSet references nested too deeply */
+export class RuleSetReferenceTooDeepError extends ProgramError {
+  readonly name: string = 'RuleSetReferenceTooDeep';
+
+  readonly code: number = 0x40; // 64
+
+  constructor(program: Program, cause?: Error) {
+    super('RuleSet references nested too deeply', program, cause);
+  }
+}
+codeToErrorMap.set(0x40, RuleSetReferenceTooDeepError);
+nameToErrorMap.set('RuleSetReferenceTooDeep', RuleSetReferenceTooDeepError);
+
+/** RuleSetHashUnavailable: RuleSet revision hash is not available */
+export class RuleSetHashUnavailableError extends ProgramError {
+  readonly name: string = 'RuleSetHashUnavailable';
+
+  readonly code: number = 0x41; // 65
+
+  constructor(program: Program, cause?: Error) {
+    super('RuleSet revision hash is not available', program, cause);
+  }
+}
+codeToErrorMap.set(0x41, RuleSetHashUnavailableError);
+nameToErrorMap.set('RuleSetHashUnavailable', RuleSetHashUnavailableError);
+
+/** InvalidAmountRange: Invalid amount range */
+export class InvalidAmountRangeError extends ProgramError {
+  readonly name: string = 'InvalidAmountRange';
+
+  readonly code: number = 0x42; // 66
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid amount range', program, cause);
+  }
+}
+codeToErrorMap.set(0x42, InvalidAmountRangeError);
+nameToErrorMap.set('InvalidAmountRange', InvalidAmountRangeError);
+
+/** UnsortedProgramSet: Program set is not sorted or contains duplicates */
+export class UnsortedProgramSetError extends ProgramError {
+  readonly name: string = 'UnsortedProgramSet';
+
+  readonly code: number = 0x43; // 67
+
+  constructor(program: Program, cause?: Error) {
+    super('Program set is not sorted or contains duplicates', program, cause);
+  }
+}
+codeToErrorMap.set(0x43, UnsortedProgramSetError);
+nameToErrorMap.set('UnsortedProgramSet', UnsortedProgramSetError);
+
+/** InvalidTimeWindow: Time window start is not before its end */
+export class InvalidTimeWindowError extends ProgramError {
+  readonly name: string = 'InvalidTimeWindow';
+
+  readonly code: number = 0x44; // 68
+
+  constructor(program: Program, cause?: Error) {
+    super('Time window start is not before its end', program, cause);
+  }
+}
+codeToErrorMap.set(0x44, InvalidTimeWindowError);
+nameToErrorMap.set('InvalidTimeWindow', InvalidTimeWindowError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
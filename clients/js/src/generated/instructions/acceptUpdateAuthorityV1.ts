@@ -0,0 +1,130 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AcceptUpdateAuthorityV1InstructionAccounts = {
+  /** Payer and pending update authority of the RuleSet */
+  payer?: Signer;
+  /** The PDA account where the RuleSet is stored */
+  ruleSetPda: PublicKey | Pda;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AcceptUpdateAuthorityV1InstructionData = {
+  discriminator: number;
+  acceptUpdateAuthorityV1Discriminator: number;
+};
+
+export type AcceptUpdateAuthorityV1InstructionDataArgs = {};
+
+export function getAcceptUpdateAuthorityV1InstructionDataSerializer(): Serializer<
+  AcceptUpdateAuthorityV1InstructionDataArgs,
+  AcceptUpdateAuthorityV1InstructionData
+> {
+  return mapSerializer<
+    AcceptUpdateAuthorityV1InstructionDataArgs,
+    any,
+    AcceptUpdateAuthorityV1InstructionData
+  >(
+    struct<AcceptUpdateAuthorityV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['acceptUpdateAuthorityV1Discriminator', u8()],
+      ],
+      { description: 'AcceptUpdateAuthorityV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 6,
+      acceptUpdateAuthorityV1Discriminator: 0,
+    })
+  ) as Serializer<
+    AcceptUpdateAuthorityV1InstructionDataArgs,
+    AcceptUpdateAuthorityV1InstructionData
+  >;
+}
+
+// Instruction.
+export function acceptUpdateAuthorityV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: AcceptUpdateAuthorityV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplTokenAuthRules',
+    'auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    payer: { index: 0, isWritable: true, value: input.payer ?? null },
+    ruleSetPda: { index: 1, isWritable: true, value: input.ruleSetPda ?? null },
+    systemProgram: {
+      index: 2,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getAcceptUpdateAuthorityV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
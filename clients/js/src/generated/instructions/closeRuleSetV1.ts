@@ -0,0 +1,132 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CloseRuleSetV1InstructionAccounts = {
+  /** Payer and update authority of the RuleSet */
+  payer?: Signer;
+  /** The account receiving the reclaimed lamports */
+  destination: PublicKey | Pda;
+  /** The PDA account where the RuleSet is stored */
+  ruleSetPda?: PublicKey | Pda;
+  /** The PDA account where the RuleSet buffer is stored */
+  bufferPda?: PublicKey | Pda;
+};
+
+// Data.
+export type CloseRuleSetV1InstructionData = {
+  discriminator: number;
+  closeRuleSetV1Discriminator: number;
+  ruleSetName: string;
+};
+
+export type CloseRuleSetV1InstructionDataArgs = { ruleSetName: string };
+
+export function getCloseRuleSetV1InstructionDataSerializer(): Serializer<
+  CloseRuleSetV1InstructionDataArgs,
+  CloseRuleSetV1InstructionData
+> {
+  return mapSerializer<
+    CloseRuleSetV1InstructionDataArgs,
+    any,
+    CloseRuleSetV1InstructionData
+  >(
+    struct<CloseRuleSetV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['closeRuleSetV1Discriminator', u8()],
+        ['ruleSetName', string()],
+      ],
+      { description: 'CloseRuleSetV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 4, closeRuleSetV1Discriminator: 0 })
+  ) as Serializer<
+    CloseRuleSetV1InstructionDataArgs,
+    CloseRuleSetV1InstructionData
+  >;
+}
+
+// Args.
+export type CloseRuleSetV1InstructionArgs = CloseRuleSetV1InstructionDataArgs;
+
+// Instruction.
+export function closeRuleSetV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: CloseRuleSetV1InstructionAccounts & CloseRuleSetV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplTokenAuthRules',
+    'auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    payer: { index: 0, isWritable: true, value: input.payer ?? null },
+    destination: {
+      index: 1,
+      isWritable: true,
+      value: input.destination ?? null,
+    },
+    ruleSetPda: { index: 2, isWritable: true, value: input.ruleSetPda ?? null },
+    bufferPda: { index: 3, isWritable: true, value: input.bufferPda ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: CloseRuleSetV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCloseRuleSetV1InstructionDataSerializer().serialize(
+    resolvedArgs as CloseRuleSetV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -34,7 +34,7 @@ import {
 
 // Accounts.
 export type CreateOrUpdateV1InstructionAccounts = {
-  /** Payer and creator of the RuleSet */
+  /** Payer and update authority of the RuleSet */
   payer?: Signer;
   /** The PDA account where the RuleSet is stored */
   ruleSetPda: PublicKey | Pda;
@@ -0,0 +1,156 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  none,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  RuleSetRevisionInput,
+  RuleSetRevisionInputArgs,
+  getRuleSetRevisionInputSerializer,
+} from '../../hooked';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CreateOrUpdateV2InstructionAccounts = {
+  /** Payer and update authority of the RuleSet */
+  payer?: Signer;
+  /** The PDA account where the RuleSet is stored */
+  ruleSetPda: PublicKey | Pda;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+  /** The buffer to copy a complete ruleset from */
+  bufferPda?: PublicKey | Pda;
+};
+
+// Data.
+export type CreateOrUpdateV2InstructionData = {
+  discriminator: number;
+  createOrUpdateV2Discriminator: number;
+  ruleSetRevision: RuleSetRevisionInput;
+  lint: boolean;
+};
+
+export type CreateOrUpdateV2InstructionDataArgs = {
+  ruleSetRevision?: RuleSetRevisionInputArgs;
+  lint: boolean;
+};
+
+export function getCreateOrUpdateV2InstructionDataSerializer(): Serializer<
+  CreateOrUpdateV2InstructionDataArgs,
+  CreateOrUpdateV2InstructionData
+> {
+  return mapSerializer<
+    CreateOrUpdateV2InstructionDataArgs,
+    any,
+    CreateOrUpdateV2InstructionData
+  >(
+    struct<CreateOrUpdateV2InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['createOrUpdateV2Discriminator', u8()],
+        ['ruleSetRevision', getRuleSetRevisionInputSerializer()],
+        ['lint', bool()],
+      ],
+      { description: 'CreateOrUpdateV2InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 0,
+      createOrUpdateV2Discriminator: 1,
+      ruleSetRevision: value.ruleSetRevision ?? none(),
+    })
+  ) as Serializer<
+    CreateOrUpdateV2InstructionDataArgs,
+    CreateOrUpdateV2InstructionData
+  >;
+}
+
+// Args.
+export type CreateOrUpdateV2InstructionArgs =
+  CreateOrUpdateV2InstructionDataArgs;
+
+// Instruction.
+export function createOrUpdateV2(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: CreateOrUpdateV2InstructionAccounts & CreateOrUpdateV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplTokenAuthRules',
+    'auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    payer: { index: 0, isWritable: true, value: input.payer ?? null },
+    ruleSetPda: { index: 1, isWritable: true, value: input.ruleSetPda ?? null },
+    systemProgram: {
+      index: 2,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    bufferPda: { index: 3, isWritable: false, value: input.bufferPda ?? null },
+  };
+
+  // Arguments.
+  const resolvedArgs: CreateOrUpdateV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCreateOrUpdateV2InstructionDataSerializer().serialize(
+    resolvedArgs as CreateOrUpdateV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
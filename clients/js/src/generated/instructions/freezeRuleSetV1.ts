@@ -0,0 +1,136 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type FreezeRuleSetV1InstructionAccounts = {
+  /** Payer and update authority of the RuleSet */
+  payer?: Signer;
+  /** The PDA account where the RuleSet is stored */
+  ruleSetPda: PublicKey | Pda;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type FreezeRuleSetV1InstructionData = {
+  discriminator: number;
+  freezeRuleSetV1Discriminator: number;
+  ruleSetName: string;
+};
+
+export type FreezeRuleSetV1InstructionDataArgs = { ruleSetName: string };
+
+export function getFreezeRuleSetV1InstructionDataSerializer(): Serializer<
+  FreezeRuleSetV1InstructionDataArgs,
+  FreezeRuleSetV1InstructionData
+> {
+  return mapSerializer<
+    FreezeRuleSetV1InstructionDataArgs,
+    any,
+    FreezeRuleSetV1InstructionData
+  >(
+    struct<FreezeRuleSetV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['freezeRuleSetV1Discriminator', u8()],
+        ['ruleSetName', string()],
+      ],
+      { description: 'FreezeRuleSetV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 7, freezeRuleSetV1Discriminator: 0 })
+  ) as Serializer<
+    FreezeRuleSetV1InstructionDataArgs,
+    FreezeRuleSetV1InstructionData
+  >;
+}
+
+// Args.
+export type FreezeRuleSetV1InstructionArgs = FreezeRuleSetV1InstructionDataArgs;
+
+// Instruction.
+export function freezeRuleSetV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: FreezeRuleSetV1InstructionAccounts & FreezeRuleSetV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplTokenAuthRules',
+    'auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    payer: { index: 0, isWritable: true, value: input.payer ?? null },
+    ruleSetPda: { index: 1, isWritable: true, value: input.ruleSetPda ?? null },
+    systemProgram: {
+      index: 2,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: FreezeRuleSetV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getFreezeRuleSetV1InstructionDataSerializer().serialize(
+    resolvedArgs as FreezeRuleSetV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -6,7 +6,14 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './acceptUpdateAuthorityV1';
+export * from './closeRuleSetV1';
 export * from './createOrUpdateV1';
+export * from './createOrUpdateV2';
+export * from './freezeRuleSetV1';
 export * from './puffRuleSetV1';
+export * from './simulateV1';
+export * from './transferUpdateAuthorityV1';
 export * from './validateV1';
+export * from './validateV2';
 export * from './writeToBufferV1';
@@ -0,0 +1,145 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  option,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import { Payload, PayloadArgs, getPayloadSerializer } from '../types';
+
+// Accounts.
+export type SimulateV1InstructionAccounts = {
+  /** The PDA account where the RuleSet is stored */
+  ruleSetPda: PublicKey | Pda;
+  /** Mint of token asset */
+  mint: PublicKey | Pda;
+  /** Signing authority for any Rule state updates */
+  ruleAuthority?: Signer;
+  /** The PDA account where any RuleSet state is stored */
+  ruleSetStatePda?: PublicKey | Pda;
+};
+
+// Data.
+export type SimulateV1InstructionData = {
+  discriminator: number;
+  simulateV1Discriminator: number;
+  operation: string;
+  payload: Payload;
+  ruleSetRevision: Option<bigint>;
+};
+
+export type SimulateV1InstructionDataArgs = {
+  operation: string;
+  payload: PayloadArgs;
+  ruleSetRevision: OptionOrNullable<number | bigint>;
+};
+
+export function getSimulateV1InstructionDataSerializer(): Serializer<
+  SimulateV1InstructionDataArgs,
+  SimulateV1InstructionData
+> {
+  return mapSerializer<
+    SimulateV1InstructionDataArgs,
+    any,
+    SimulateV1InstructionData
+  >(
+    struct<SimulateV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['simulateV1Discriminator', u8()],
+        ['operation', string()],
+        ['payload', getPayloadSerializer()],
+        ['ruleSetRevision', option(u64())],
+      ],
+      { description: 'SimulateV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 8, simulateV1Discriminator: 0 })
+  ) as Serializer<SimulateV1InstructionDataArgs, SimulateV1InstructionData>;
+}
+
+// Args.
+export type SimulateV1InstructionArgs = SimulateV1InstructionDataArgs;
+
+// Instruction.
+export function simulateV1(
+  context: Pick<Context, 'programs'>,
+  input: SimulateV1InstructionAccounts & SimulateV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplTokenAuthRules',
+    'auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    ruleSetPda: {
+      index: 0,
+      isWritable: false,
+      value: input.ruleSetPda ?? null,
+    },
+    mint: { index: 1, isWritable: false, value: input.mint ?? null },
+    ruleAuthority: {
+      index: 2,
+      isWritable: false,
+      value: input.ruleAuthority ?? null,
+    },
+    ruleSetStatePda: {
+      index: 3,
+      isWritable: false,
+      value: input.ruleSetStatePda ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: SimulateV1InstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSimulateV1InstructionDataSerializer().serialize(
+    resolvedArgs as SimulateV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
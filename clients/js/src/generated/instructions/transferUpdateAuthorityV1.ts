@@ -0,0 +1,149 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type TransferUpdateAuthorityV1InstructionAccounts = {
+  /** Payer and current update authority of the RuleSet */
+  payer?: Signer;
+  /** The PDA account where the RuleSet is stored */
+  ruleSetPda: PublicKey | Pda;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type TransferUpdateAuthorityV1InstructionData = {
+  discriminator: number;
+  transferUpdateAuthorityV1Discriminator: number;
+  ruleSetName: string;
+  newUpdateAuthority: PublicKey;
+};
+
+export type TransferUpdateAuthorityV1InstructionDataArgs = {
+  ruleSetName: string;
+  newUpdateAuthority: PublicKey;
+};
+
+export function getTransferUpdateAuthorityV1InstructionDataSerializer(): Serializer<
+  TransferUpdateAuthorityV1InstructionDataArgs,
+  TransferUpdateAuthorityV1InstructionData
+> {
+  return mapSerializer<
+    TransferUpdateAuthorityV1InstructionDataArgs,
+    any,
+    TransferUpdateAuthorityV1InstructionData
+  >(
+    struct<TransferUpdateAuthorityV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['transferUpdateAuthorityV1Discriminator', u8()],
+        ['ruleSetName', string()],
+        ['newUpdateAuthority', publicKeySerializer()],
+      ],
+      { description: 'TransferUpdateAuthorityV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 5,
+      transferUpdateAuthorityV1Discriminator: 0,
+    })
+  ) as Serializer<
+    TransferUpdateAuthorityV1InstructionDataArgs,
+    TransferUpdateAuthorityV1InstructionData
+  >;
+}
+
+// Args.
+export type TransferUpdateAuthorityV1InstructionArgs =
+  TransferUpdateAuthorityV1InstructionDataArgs;
+
+// Instruction.
+export function transferUpdateAuthorityV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: TransferUpdateAuthorityV1InstructionAccounts &
+    TransferUpdateAuthorityV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplTokenAuthRules',
+    'auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    payer: { index: 0, isWritable: true, value: input.payer ?? null },
+    ruleSetPda: { index: 1, isWritable: true, value: input.ruleSetPda ?? null },
+    systemProgram: {
+      index: 2,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: TransferUpdateAuthorityV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getTransferUpdateAuthorityV1InstructionDataSerializer().serialize(
+      resolvedArgs as TransferUpdateAuthorityV1InstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,172 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bool,
+  bytes,
+  mapSerializer,
+  option,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+import { Payload, PayloadArgs, getPayloadSerializer } from '../types';
+
+// Accounts.
+export type ValidateV2InstructionAccounts = {
+  /** The PDA account where the RuleSet is stored */
+  ruleSetPda: PublicKey | Pda;
+  /** Mint of token asset */
+  mint: PublicKey | Pda;
+  /** System program */
+  systemProgram?: PublicKey | Pda;
+  /** Payer for RuleSet state PDA account */
+  payer?: Signer;
+  /** Signing authority for any Rule state updates */
+  ruleAuthority?: Signer;
+  /** The PDA account where any RuleSet state is stored */
+  ruleSetStatePda?: PublicKey | Pda;
+};
+
+// Data.
+export type ValidateV2InstructionData = {
+  discriminator: number;
+  validateV2Discriminator: number;
+  operation: string;
+  payload: Payload;
+  updateRuleState: boolean;
+  ruleSetRevision: Option<bigint>;
+  ruleSetHash: Uint8Array;
+};
+
+export type ValidateV2InstructionDataArgs = {
+  operation: string;
+  payload: PayloadArgs;
+  updateRuleState: boolean;
+  ruleSetRevision: OptionOrNullable<number | bigint>;
+  ruleSetHash: Uint8Array;
+};
+
+export function getValidateV2InstructionDataSerializer(): Serializer<
+  ValidateV2InstructionDataArgs,
+  ValidateV2InstructionData
+> {
+  return mapSerializer<
+    ValidateV2InstructionDataArgs,
+    any,
+    ValidateV2InstructionData
+  >(
+    struct<ValidateV2InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['validateV2Discriminator', u8()],
+        ['operation', string()],
+        ['payload', getPayloadSerializer()],
+        ['updateRuleState', bool()],
+        ['ruleSetRevision', option(u64())],
+        ['ruleSetHash', bytes({ size: 32 })],
+      ],
+      { description: 'ValidateV2InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 1, validateV2Discriminator: 1 })
+  ) as Serializer<ValidateV2InstructionDataArgs, ValidateV2InstructionData>;
+}
+
+// Args.
+export type ValidateV2InstructionArgs = ValidateV2InstructionDataArgs;
+
+// Instruction.
+export function validateV2(
+  context: Pick<Context, 'programs'>,
+  input: ValidateV2InstructionAccounts & ValidateV2InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplTokenAuthRules',
+    'auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg'
+  );
+
+  // Accounts.
+  const resolvedAccounts: ResolvedAccountsWithIndices = {
+    ruleSetPda: {
+      index: 0,
+      isWritable: false,
+      value: input.ruleSetPda ?? null,
+    },
+    mint: { index: 1, isWritable: false, value: input.mint ?? null },
+    systemProgram: {
+      index: 2,
+      isWritable: false,
+      value: input.systemProgram ?? null,
+    },
+    payer: { index: 3, isWritable: true, value: input.payer ?? null },
+    ruleAuthority: {
+      index: 4,
+      isWritable: false,
+      value: input.ruleAuthority ?? null,
+    },
+    ruleSetStatePda: {
+      index: 5,
+      isWritable: true,
+      value: input.ruleSetStatePda ?? null,
+    },
+  };
+
+  // Arguments.
+  const resolvedArgs: ValidateV2InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getValidateV2InstructionDataSerializer().serialize(
+    resolvedArgs as ValidateV2InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
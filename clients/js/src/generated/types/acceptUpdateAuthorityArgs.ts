@@ -0,0 +1,24 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum AcceptUpdateAuthorityArgs {
+  V1,
+}
+
+export type AcceptUpdateAuthorityArgsArgs = AcceptUpdateAuthorityArgs;
+
+export function getAcceptUpdateAuthorityArgsSerializer(): Serializer<
+  AcceptUpdateAuthorityArgsArgs,
+  AcceptUpdateAuthorityArgs
+> {
+  return scalarEnum<AcceptUpdateAuthorityArgs>(AcceptUpdateAuthorityArgs, {
+    description: 'AcceptUpdateAuthorityArgs',
+  }) as Serializer<AcceptUpdateAuthorityArgsArgs, AcceptUpdateAuthorityArgs>;
+}
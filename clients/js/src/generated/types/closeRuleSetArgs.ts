@@ -0,0 +1,57 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type CloseRuleSetArgs = { __kind: 'V1'; ruleSetName: string };
+
+export type CloseRuleSetArgsArgs = CloseRuleSetArgs;
+
+export function getCloseRuleSetArgsSerializer(): Serializer<
+  CloseRuleSetArgsArgs,
+  CloseRuleSetArgs
+> {
+  return dataEnum<CloseRuleSetArgs>(
+    [
+      [
+        'V1',
+        struct<GetDataEnumKindContent<CloseRuleSetArgs, 'V1'>>([
+          ['ruleSetName', string()],
+        ]),
+      ],
+    ],
+    { description: 'CloseRuleSetArgs' }
+  ) as Serializer<CloseRuleSetArgsArgs, CloseRuleSetArgs>;
+}
+
+// Data Enum Helpers.
+export function closeRuleSetArgs(
+  kind: 'V1',
+  data: GetDataEnumKindContent<CloseRuleSetArgsArgs, 'V1'>
+): GetDataEnumKind<CloseRuleSetArgsArgs, 'V1'>;
+export function closeRuleSetArgs<K extends CloseRuleSetArgsArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<CloseRuleSetArgsArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isCloseRuleSetArgs<K extends CloseRuleSetArgs['__kind']>(
+  kind: K,
+  value: CloseRuleSetArgs
+): value is CloseRuleSetArgs & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -11,6 +11,7 @@ import {
   GetDataEnumKind,
   GetDataEnumKindContent,
   Serializer,
+  bool,
   dataEnum,
   mapSerializer,
   struct,
@@ -21,15 +22,13 @@ import {
   getRuleSetRevisionInputSerializer,
 } from '../../hooked';
 
-export type CreateOrUpdateArgs = {
-  __kind: 'V1';
-  ruleSetRevision: RuleSetRevisionInput;
-};
+export type CreateOrUpdateArgs =
+  | { __kind: 'V1'; ruleSetRevision: RuleSetRevisionInput }
+  | { __kind: 'V2'; ruleSetRevision: RuleSetRevisionInput; lint: boolean };
 
-export type CreateOrUpdateArgsArgs = {
-  __kind: 'V1';
-  ruleSetRevision?: RuleSetRevisionInputArgs;
-};
+export type CreateOrUpdateArgsArgs =
+  | { __kind: 'V1'; ruleSetRevision?: RuleSetRevisionInputArgs }
+  | { __kind: 'V2'; ruleSetRevision?: RuleSetRevisionInputArgs; lint: boolean };
 
 export function getCreateOrUpdateArgsSerializer(): Serializer<
   CreateOrUpdateArgsArgs,
@@ -53,6 +52,23 @@ export function getCreateOrUpdateArgsSerializer(): Serializer<
           })
         ),
       ],
+      [
+        'V2',
+        mapSerializer<
+          GetDataEnumKindContent<CreateOrUpdateArgsArgs, 'V2'>,
+          any,
+          GetDataEnumKindContent<CreateOrUpdateArgs, 'V2'>
+        >(
+          struct<GetDataEnumKindContent<CreateOrUpdateArgs, 'V2'>>([
+            ['ruleSetRevision', getRuleSetRevisionInputSerializer()],
+            ['lint', bool()],
+          ]),
+          (value) => ({
+            ...value,
+            ruleSetRevision: value.ruleSetRevision ?? none(),
+          })
+        ),
+      ],
     ],
     { description: 'CreateOrUpdateArgs' }
   ) as Serializer<CreateOrUpdateArgsArgs, CreateOrUpdateArgs>;
@@ -63,6 +79,10 @@ export function createOrUpdateArgs(
   kind: 'V1',
   data: GetDataEnumKindContent<CreateOrUpdateArgsArgs, 'V1'>
 ): GetDataEnumKind<CreateOrUpdateArgsArgs, 'V1'>;
+export function createOrUpdateArgs(
+  kind: 'V2',
+  data: GetDataEnumKindContent<CreateOrUpdateArgsArgs, 'V2'>
+): GetDataEnumKind<CreateOrUpdateArgsArgs, 'V2'>;
 export function createOrUpdateArgs<K extends CreateOrUpdateArgsArgs['__kind']>(
   kind: K,
   data?: any
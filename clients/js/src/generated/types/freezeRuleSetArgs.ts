@@ -0,0 +1,57 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type FreezeRuleSetArgs = { __kind: 'V1'; ruleSetName: string };
+
+export type FreezeRuleSetArgsArgs = FreezeRuleSetArgs;
+
+export function getFreezeRuleSetArgsSerializer(): Serializer<
+  FreezeRuleSetArgsArgs,
+  FreezeRuleSetArgs
+> {
+  return dataEnum<FreezeRuleSetArgs>(
+    [
+      [
+        'V1',
+        struct<GetDataEnumKindContent<FreezeRuleSetArgs, 'V1'>>([
+          ['ruleSetName', string()],
+        ]),
+      ],
+    ],
+    { description: 'FreezeRuleSetArgs' }
+  ) as Serializer<FreezeRuleSetArgsArgs, FreezeRuleSetArgs>;
+}
+
+// Data Enum Helpers.
+export function freezeRuleSetArgs(
+  kind: 'V1',
+  data: GetDataEnumKindContent<FreezeRuleSetArgsArgs, 'V1'>
+): GetDataEnumKind<FreezeRuleSetArgsArgs, 'V1'>;
+export function freezeRuleSetArgs<K extends FreezeRuleSetArgsArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<FreezeRuleSetArgsArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isFreezeRuleSetArgs<K extends FreezeRuleSetArgs['__kind']>(
+  kind: K,
+  value: FreezeRuleSetArgs
+): value is FreezeRuleSetArgs & { __kind: K } {
+  return value.__kind === kind;
+}
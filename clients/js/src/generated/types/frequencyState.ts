@@ -0,0 +1,40 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  i64,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type FrequencyState = {
+  operation: string;
+  period: bigint;
+  lastUpdate: bigint;
+};
+
+export type FrequencyStateArgs = {
+  operation: string;
+  period: number | bigint;
+  lastUpdate: number | bigint;
+};
+
+export function getFrequencyStateSerializer(): Serializer<
+  FrequencyStateArgs,
+  FrequencyState
+> {
+  return struct<FrequencyState>(
+    [
+      ['operation', string()],
+      ['period', i64()],
+      ['lastUpdate', i64()],
+    ],
+    { description: 'FrequencyState' }
+  ) as Serializer<FrequencyStateArgs, FrequencyState>;
+}
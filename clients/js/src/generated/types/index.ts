@@ -6,14 +6,24 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './acceptUpdateAuthorityArgs';
+export * from './closeRuleSetArgs';
 export * from './createOrUpdateArgs';
+export * from './freezeRuleSetArgs';
+export * from './frequencyState';
 export * from './key';
 export * from './payload';
 export * from './payloadType';
 export * from './proofInfo';
 export * from './puffRuleSetArgs';
+export * from './ruleOutcome';
 export * from './ruleSetHeader';
 export * from './ruleSetRevisionMapV1';
+export * from './ruleSetRevisionMapV2';
+export * from './ruleTrace';
+export * from './ruleTraceEntry';
 export * from './seedsVec';
+export * from './simulateArgs';
+export * from './transferUpdateAuthorityArgs';
 export * from './validateArgs';
 export * from './writeToBufferArgs';
@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum RuleOutcome {
+  Success,
+  Failure,
+  Error,
+}
+
+export type RuleOutcomeArgs = RuleOutcome;
+
+export function getRuleOutcomeSerializer(): Serializer<
+  RuleOutcomeArgs,
+  RuleOutcome
+> {
+  return scalarEnum<RuleOutcome>(RuleOutcome, {
+    description: 'RuleOutcome',
+  }) as Serializer<RuleOutcomeArgs, RuleOutcome>;
+}
@@ -0,0 +1,51 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable, PublicKey } from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bool,
+  bytes,
+  option,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type RuleSetRevisionMapV2 = {
+  ruleSetRevisions: Array<bigint>;
+  updateAuthority: Option<PublicKey>;
+  pendingUpdateAuthority: Option<PublicKey>;
+  frozen: boolean;
+  revisionHashes: Array<Uint8Array>;
+};
+
+export type RuleSetRevisionMapV2Args = {
+  ruleSetRevisions: Array<number | bigint>;
+  updateAuthority: OptionOrNullable<PublicKey>;
+  pendingUpdateAuthority: OptionOrNullable<PublicKey>;
+  frozen: boolean;
+  revisionHashes: Array<Uint8Array>;
+};
+
+export function getRuleSetRevisionMapV2Serializer(): Serializer<
+  RuleSetRevisionMapV2Args,
+  RuleSetRevisionMapV2
+> {
+  return struct<RuleSetRevisionMapV2>(
+    [
+      ['ruleSetRevisions', array(u64())],
+      ['updateAuthority', option(publicKeySerializer())],
+      ['pendingUpdateAuthority', option(publicKeySerializer())],
+      ['frozen', bool()],
+      ['revisionHashes', array(bytes({ size: 32 }))],
+    ],
+    { description: 'RuleSetRevisionMapV2' }
+  ) as Serializer<RuleSetRevisionMapV2Args, RuleSetRevisionMapV2>;
+}
@@ -0,0 +1,36 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  array,
+  bool,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  RuleTraceEntry,
+  RuleTraceEntryArgs,
+  getRuleTraceEntrySerializer,
+} from '.';
+
+export type RuleTrace = { entries: Array<RuleTraceEntry>; truncated: boolean };
+
+export type RuleTraceArgs = {
+  entries: Array<RuleTraceEntryArgs>;
+  truncated: boolean;
+};
+
+export function getRuleTraceSerializer(): Serializer<RuleTraceArgs, RuleTrace> {
+  return struct<RuleTrace>(
+    [
+      ['entries', array(getRuleTraceEntrySerializer())],
+      ['truncated', bool()],
+    ],
+    { description: 'RuleTrace' }
+  ) as Serializer<RuleTraceArgs, RuleTrace>;
+}
@@ -0,0 +1,45 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Serializer,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { RuleOutcome, RuleOutcomeArgs, getRuleOutcomeSerializer } from '.';
+
+export type RuleTraceEntry = {
+  depth: number;
+  constraintType: number;
+  outcome: RuleOutcome;
+  error: bigint;
+};
+
+export type RuleTraceEntryArgs = {
+  depth: number;
+  constraintType: number;
+  outcome: RuleOutcomeArgs;
+  error: number | bigint;
+};
+
+export function getRuleTraceEntrySerializer(): Serializer<
+  RuleTraceEntryArgs,
+  RuleTraceEntry
+> {
+  return struct<RuleTraceEntry>(
+    [
+      ['depth', u8()],
+      ['constraintType', u32()],
+      ['outcome', getRuleOutcomeSerializer()],
+      ['error', u64()],
+    ],
+    { description: 'RuleTraceEntry' }
+  ) as Serializer<RuleTraceEntryArgs, RuleTraceEntry>;
+}
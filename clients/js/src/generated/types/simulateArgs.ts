@@ -0,0 +1,73 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Option, OptionOrNullable } from '@metaplex-foundation/umi';
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  option,
+  string,
+  struct,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+import { Payload, PayloadArgs, getPayloadSerializer } from '.';
+
+export type SimulateArgs = {
+  __kind: 'V1';
+  operation: string;
+  payload: Payload;
+  ruleSetRevision: Option<bigint>;
+};
+
+export type SimulateArgsArgs = {
+  __kind: 'V1';
+  operation: string;
+  payload: PayloadArgs;
+  ruleSetRevision: OptionOrNullable<number | bigint>;
+};
+
+export function getSimulateArgsSerializer(): Serializer<
+  SimulateArgsArgs,
+  SimulateArgs
+> {
+  return dataEnum<SimulateArgs>(
+    [
+      [
+        'V1',
+        struct<GetDataEnumKindContent<SimulateArgs, 'V1'>>([
+          ['operation', string()],
+          ['payload', getPayloadSerializer()],
+          ['ruleSetRevision', option(u64())],
+        ]),
+      ],
+    ],
+    { description: 'SimulateArgs' }
+  ) as Serializer<SimulateArgsArgs, SimulateArgs>;
+}
+
+// Data Enum Helpers.
+export function simulateArgs(
+  kind: 'V1',
+  data: GetDataEnumKindContent<SimulateArgsArgs, 'V1'>
+): GetDataEnumKind<SimulateArgsArgs, 'V1'>;
+export function simulateArgs<K extends SimulateArgsArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<SimulateArgsArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isSimulateArgs<K extends SimulateArgs['__kind']>(
+  kind: K,
+  value: SimulateArgs
+): value is SimulateArgs & { __kind: K } {
+  return value.__kind === kind;
+}
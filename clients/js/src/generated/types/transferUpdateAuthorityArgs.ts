@@ -0,0 +1,68 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { PublicKey } from '@metaplex-foundation/umi';
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Serializer,
+  dataEnum,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+} from '@metaplex-foundation/umi/serializers';
+
+export type TransferUpdateAuthorityArgs = {
+  __kind: 'V1';
+  ruleSetName: string;
+  newUpdateAuthority: PublicKey;
+};
+
+export type TransferUpdateAuthorityArgsArgs = TransferUpdateAuthorityArgs;
+
+export function getTransferUpdateAuthorityArgsSerializer(): Serializer<
+  TransferUpdateAuthorityArgsArgs,
+  TransferUpdateAuthorityArgs
+> {
+  return dataEnum<TransferUpdateAuthorityArgs>(
+    [
+      [
+        'V1',
+        struct<GetDataEnumKindContent<TransferUpdateAuthorityArgs, 'V1'>>([
+          ['ruleSetName', string()],
+          ['newUpdateAuthority', publicKeySerializer()],
+        ]),
+      ],
+    ],
+    { description: 'TransferUpdateAuthorityArgs' }
+  ) as Serializer<TransferUpdateAuthorityArgsArgs, TransferUpdateAuthorityArgs>;
+}
+
+// Data Enum Helpers.
+export function transferUpdateAuthorityArgs(
+  kind: 'V1',
+  data: GetDataEnumKindContent<TransferUpdateAuthorityArgsArgs, 'V1'>
+): GetDataEnumKind<TransferUpdateAuthorityArgsArgs, 'V1'>;
+export function transferUpdateAuthorityArgs<
+  K extends TransferUpdateAuthorityArgsArgs['__kind']
+>(
+  kind: K,
+  data?: any
+): Extract<TransferUpdateAuthorityArgsArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isTransferUpdateAuthorityArgs<
+  K extends TransferUpdateAuthorityArgs['__kind']
+>(
+  kind: K,
+  value: TransferUpdateAuthorityArgs
+): value is TransferUpdateAuthorityArgs & { __kind: K } {
+  return value.__kind === kind;
+}
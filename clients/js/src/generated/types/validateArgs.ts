@@ -12,6 +12,7 @@ import {
   GetDataEnumKindContent,
   Serializer,
   bool,
+  bytes,
   dataEnum,
   option,
   string,
@@ -20,21 +21,39 @@ import {
 } from '@metaplex-foundation/umi/serializers';
 import { Payload, PayloadArgs, getPayloadSerializer } from '.';
 
-export type ValidateArgs = {
-  __kind: 'V1';
-  operation: string;
-  payload: Payload;
-  updateRuleState: boolean;
-  ruleSetRevision: Option<bigint>;
-};
+export type ValidateArgs =
+  | {
+      __kind: 'V1';
+      operation: string;
+      payload: Payload;
+      updateRuleState: boolean;
+      ruleSetRevision: Option<bigint>;
+    }
+  | {
+      __kind: 'V2';
+      operation: string;
+      payload: Payload;
+      updateRuleState: boolean;
+      ruleSetRevision: Option<bigint>;
+      ruleSetHash: Uint8Array;
+    };
 
-export type ValidateArgsArgs = {
-  __kind: 'V1';
-  operation: string;
-  payload: PayloadArgs;
-  updateRuleState: boolean;
-  ruleSetRevision: OptionOrNullable<number | bigint>;
-};
+export type ValidateArgsArgs =
+  | {
+      __kind: 'V1';
+      operation: string;
+      payload: PayloadArgs;
+      updateRuleState: boolean;
+      ruleSetRevision: OptionOrNullable<number | bigint>;
+    }
+  | {
+      __kind: 'V2';
+      operation: string;
+      payload: PayloadArgs;
+      updateRuleState: boolean;
+      ruleSetRevision: OptionOrNullable<number | bigint>;
+      ruleSetHash: Uint8Array;
+    };
 
 export function getValidateArgsSerializer(): Serializer<
   ValidateArgsArgs,
@@ -51,6 +70,16 @@ export function getValidateArgsSerializer(): Serializer<
           ['ruleSetRevision', option(u64())],
         ]),
       ],
+      [
+        'V2',
+        struct<GetDataEnumKindContent<ValidateArgs, 'V2'>>([
+          ['operation', string()],
+          ['payload', getPayloadSerializer()],
+          ['updateRuleState', bool()],
+          ['ruleSetRevision', option(u64())],
+          ['ruleSetHash', bytes({ size: 32 })],
+        ]),
+      ],
     ],
     { description: 'ValidateArgs' }
   ) as Serializer<ValidateArgsArgs, ValidateArgs>;
@@ -61,6 +90,10 @@ export function validateArgs(
   kind: 'V1',
   data: GetDataEnumKindContent<ValidateArgsArgs, 'V1'>
 ): GetDataEnumKind<ValidateArgsArgs, 'V1'>;
+export function validateArgs(
+  kind: 'V2',
+  data: GetDataEnumKindContent<ValidateArgsArgs, 'V2'>
+): GetDataEnumKind<ValidateArgsArgs, 'V2'>;
 export function validateArgs<K extends ValidateArgsArgs['__kind']>(
   kind: K,
   data?: any
@@ -1,14 +1,26 @@
+import { Option, PublicKey } from '@metaplex-foundation/umi';
 import { Serializer, mergeBytes } from '@metaplex-foundation/umi/serializers';
 import {
   getRuleSetHeaderSerializer,
   getRuleSetRevisionMapV1Serializer,
+  getRuleSetRevisionMapV2Serializer,
 } from '../generated';
 
-export type RuleSetRevisionMap = {
-  version: 1;
-  location: number;
-  revisionLocations: number[];
-};
+export type RuleSetRevisionMap =
+  | {
+      version: 1;
+      location: number;
+      revisionLocations: number[];
+    }
+  | {
+      version: 2;
+      location: number;
+      revisionLocations: number[];
+      updateAuthority: Option<PublicKey>;
+      pendingUpdateAuthority: Option<PublicKey>;
+      frozen: boolean;
+      revisionHashes: Uint8Array[];
+    };
 
 export const getRuleSetRevisionMapSerializer = (
   location: number
@@ -17,27 +29,57 @@ export const getRuleSetRevisionMapSerializer = (
   fixedSize: null,
   maxSize: null,
   serialize: (revisionMap) => {
-    if (revisionMap.version !== 1) {
-      throw new Error(
-        `Unsupported revision map version: ${revisionMap.version}`
-      );
+    const ruleSetRevisions = revisionMap.revisionLocations.map((n) =>
+      BigInt(n)
+    );
+    switch (revisionMap.version) {
+      case 1:
+        return mergeBytes([
+          new Uint8Array([revisionMap.version]),
+          getRuleSetRevisionMapV1Serializer().serialize({ ruleSetRevisions }),
+        ]);
+      case 2:
+        return mergeBytes([
+          new Uint8Array([revisionMap.version]),
+          getRuleSetRevisionMapV2Serializer().serialize({
+            ruleSetRevisions,
+            updateAuthority: revisionMap.updateAuthority,
+            pendingUpdateAuthority: revisionMap.pendingUpdateAuthority,
+            frozen: revisionMap.frozen,
+            revisionHashes: revisionMap.revisionHashes,
+          }),
+        ]);
+      default:
+        throw new Error(
+          // eslint-disable-next-line @typescript-eslint/no-explicit-any
+          `Unsupported revision map version: ${(revisionMap as any).version}`
+        );
     }
-    const revisionMapV1 = getRuleSetRevisionMapV1Serializer().serialize({
-      ruleSetRevisions: revisionMap.revisionLocations.map((n) => BigInt(n)),
-    });
-    return mergeBytes([new Uint8Array([revisionMap.version]), revisionMapV1]);
   },
   deserialize: (buffer, offset = 0) => {
     const version = Number(buffer[offset]);
-    if (version !== 1) {
-      throw new Error(`Unsupported revision map version: ${version}`);
+    switch (version) {
+      case 1: {
+        const [revisionMapV1, newOffset] =
+          getRuleSetRevisionMapV1Serializer().deserialize(buffer, offset + 1);
+        const revisionLocations = revisionMapV1.ruleSetRevisions.map((n) =>
+          Number(n)
+        );
+        return [{ version: 1, location, revisionLocations }, newOffset];
+      }
+      case 2: {
+        const [revisionMapV2, newOffset] =
+          getRuleSetRevisionMapV2Serializer().deserialize(buffer, offset + 1);
+        const { ruleSetRevisions, ...rest } = revisionMapV2;
+        const revisionLocations = ruleSetRevisions.map((n) => Number(n));
+        return [
+          { version: 2, location, revisionLocations, ...rest },
+          newOffset,
+        ];
+      }
+      default:
+        throw new Error(`Unsupported revision map version: ${version}`);
     }
-    const [revisionMapV1, newOffset] =
-      getRuleSetRevisionMapV1Serializer().deserialize(buffer, offset + 1);
-    const revisionLocations = revisionMapV1.ruleSetRevisions.map((n) =>
-      Number(n)
-    );
-    return [{ version, location, revisionLocations }, newOffset];
   },
 });
 
@@ -52,3 +94,12 @@ export const getRuleSetRevisionMapFromAccountData = (
   );
   return revisionMap;
 };
+
+/**
+ * The frozen flag is stored in the revision map since the account header is
+ * never versioned. RuleSets with a version 1 revision map are never frozen.
+ */
+export const isRuleSetFrozen = (accountData: Uint8Array): boolean => {
+  const revisionMap = getRuleSetRevisionMapFromAccountData(accountData);
+  return revisionMap.version === 2 && revisionMap.frozen;
+};
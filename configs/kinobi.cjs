@@ -114,6 +114,11 @@ kinobi.update(
     puffRuleSet: "puffRuleSetArgs",
     validate: "validateArgs",
     writeToBuffer: "writeToBufferArgs",
+    closeRuleSet: "closeRuleSetArgs",
+    transferUpdateAuthority: "transferUpdateAuthorityArgs",
+    acceptUpdateAuthority: "acceptUpdateAuthorityArgs",
+    freezeRuleSet: "freezeRuleSetArgs",
+    simulate: "simulateArgs",
   })
 );
 
@@ -173,6 +173,118 @@ pub enum RuleSetError {
     /// 40 - Could not determine alignemnt
     #[error("Could not determine alignemnt")]
     AlignmentError,
+
+    /// 41 - Signer is not the RuleSet update authority
+    #[error("Signer is not the RuleSet update authority")]
+    UpdateAuthorityMismatch,
+
+    /// 42 - Signer is not the pending RuleSet update authority
+    #[error("Signer is not the pending RuleSet update authority")]
+    PendingUpdateAuthorityMismatch,
+
+    /// 43 - RuleSet is frozen
+    #[error("RuleSet is frozen")]
+    RuleSetFrozen,
+
+    /// 44 - RuleSet revision hash does not match the expected hash
+    #[error("RuleSet revision hash does not match the expected hash")]
+    RuleSetHashMismatch,
+
+    /// 45 - Namespace rule has no operation to fall back to
+    #[error("Namespace rule has no operation to fall back to")]
+    MissingNamespaceFallback,
+
+    /// 46 - All or Any rule does not contain any rules
+    #[error("All or Any rule does not contain any rules")]
+    EmptyRuleGroup,
+
+    /// 47 - Field does not fit in 32 bytes
+    #[error("Field does not fit in 32 bytes")]
+    FieldTooLong,
+
+    /// 48 - Operation can never be selected
+    #[error("Operation can never be selected")]
+    UnreachableOperation,
+
+    /// 49 - Time window check failed
+    #[error("Time window check failed")]
+    TimeWindowCheckFailed,
+
+    /// 50 - Invalid time source
+    #[error("Invalid time source")]
+    InvalidTimeSource,
+
+    /// 51 - Account Data check failed
+    #[error("Account Data check failed")]
+    AccountDataCheckFailed,
+
+    /// 52 - Token Account check failed
+    #[error("Token Account check failed")]
+    TokenAccountCheckFailed,
+
+    /// 53 - Invalid token account check
+    #[error("Invalid token account check")]
+    InvalidTokenAccountCheck,
+
+    /// 54 - Token Gate check failed
+    #[error("Token Gate check failed")]
+    TokenGateCheckFailed,
+
+    /// 55 - Field Compare check failed
+    #[error("Field Compare check failed")]
+    FieldCompareCheckFailed,
+
+    /// 56 - Threshold check failed
+    #[error("Threshold check failed")]
+    ThresholdCheckFailed,
+
+    /// 57 - Invalid threshold
+    #[error("Invalid threshold")]
+    InvalidThreshold,
+
+    /// 58 - Instruction Match check failed
+    #[error("Instruction Match check failed")]
+    InstructionMatchCheckFailed,
+
+    /// 59 - Royalty Payment check failed
+    #[error("Royalty Payment check failed")]
+    RoyaltyPaymentCheckFailed,
+
+    /// 60 - Ed25519 attestation is missing or stale
+    #[error("Ed25519 attestation is missing or stale")]
+    Ed25519AttestationCheckFailed,
+
+    /// 61 - Mint List Match check failed
+    #[error("Mint List Match check failed")]
+    MintListMatchCheckFailed,
+
+    /// 62 - Mint Tree Match check failed
+    #[error("Mint Tree Match check failed")]
+    MintTreeMatchCheckFailed,
+
+    /// 63 - RuleSet reference cycle detected
+    #[error("RuleSet reference cycle detected")]
+    RuleSetReferenceCycle,
+
+    /// 64 - RuleSet references nested too deeply
+    #[error("RuleSet references nested too deeply")]
+    RuleSetReferenceTooDeep,
+
+    /// 65 - RuleSet revision hash is not available
+    #[error("RuleSet revision hash is not available")]
+    RuleSetHashUnavailable,
+
+    /// 66 - Invalid amount range
+    #[error("Invalid amount range")]
+    InvalidAmountRange,
+
+    /// 67 - Program set is not sorted or contains duplicates
+    #[error("Program set is not sorted or contains duplicates")]
+    UnsortedProgramSet,
+
+    /// 68 - Time window start is not before its end
+    #[error("Time window start is not before its end")]
+    InvalidTimeWindow,
 }
 
 impl PrintProgramError for RuleSetError {
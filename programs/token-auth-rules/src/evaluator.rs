@@ -0,0 +1,502 @@
+//! Off-chain evaluation of `RuleSet`s.
+//!
+//! The functions in this module evaluate a `RuleSet` against an operation, a `Payload` and a set
+//! of account snapshots without running the program, using the same `Assertable` and `Constraint`
+//! implementations as the `Validate` instruction.  Rule state is never updated, and rules that
+//! need the `RuleSet` state PDA or sysvars (such as `Frequency`) cannot pass.
+use std::{cell::RefCell, collections::HashMap};
+
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::{
+    error::RuleSetError,
+    payload::Payload,
+    pda::find_rule_set_address,
+    state::{RuleSetV1, RuleSetV2},
+    types::{LibVersion, RuleSet, ValidationContext},
+    utils::{get_revision_map_from_data, get_rule_set_revision_data},
+};
+
+/// Snapshot of an account used by the rules, standing in for the `additional_rule_accounts`
+/// of the `Validate` instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountSnapshot {
+    /// Address of the account.
+    pub key: Pubkey,
+    /// Program owning the account.
+    pub owner: Pubkey,
+    /// Whether the account signed the transaction.
+    pub is_signer: bool,
+    /// Data of the account.
+    pub data: Vec<u8>,
+}
+
+/// Evaluate a user-pre-serialized `RuleSet` (MessagePack `RuleSetV1` or `RuleSetV2` bytes, as
+/// passed to `CreateOrUpdate`) for an operation on a mint.  The `RuleSet` is evaluated as if
+/// stored at the PDA derived from its owner and name.
+pub fn evaluate(
+    serialized_rule_set: &[u8],
+    mint: &Pubkey,
+    operation: &str,
+    payload: &Payload,
+    accounts: &[AccountSnapshot],
+) -> ProgramResult {
+    if let Ok(rule_set) = rmp_serde::from_slice::<RuleSetV1>(serialized_rule_set) {
+        let context = pre_serialized_context(&rule_set, mint, operation);
+        evaluate_rule_set(&rule_set, &context, payload, accounts)
+    } else if let Ok(rule_set) = RuleSetV2::from_bytes(serialized_rule_set) {
+        let context = pre_serialized_context(&rule_set, mint, operation);
+        evaluate_rule_set(&rule_set, &context, payload, accounts)
+    } else {
+        Err(RuleSetError::RuleSetReadFailed.into())
+    }
+}
+
+/// Evaluate a revision of a `RuleSet` stored in the data of the `RuleSet` PDA at `rule_set` for
+/// an operation on a mint.  If `rule_set_revision` is `None`, the latest revision is used.
+pub fn evaluate_rule_set_account(
+    rule_set: &Pubkey,
+    data: &[u8],
+    rule_set_revision: Option<usize>,
+    mint: &Pubkey,
+    operation: &str,
+    payload: &Payload,
+    accounts: &[AccountSnapshot],
+) -> ProgramResult {
+    let (revision_map, _) = get_revision_map_from_data(data)?;
+
+    let context = ValidationContext {
+        mint: *mint,
+        operation: operation.to_string(),
+        rule_set_revision: match rule_set_revision {
+            Some(revision) => Some(revision),
+            None => revision_map.rule_set_revisions.len().checked_sub(1),
+        },
+        rule_sets: vec![*rule_set],
+        frequency_updates: RefCell::default(),
+    };
+
+    match get_rule_set_revision_data(data, rule_set_revision)? {
+        (LibVersion::V1, serialized_rule_set) => {
+            let rule_set = rmp_serde::from_slice::<RuleSetV1>(serialized_rule_set)
+                .map_err(|_| RuleSetError::MessagePackDeserializationError)?;
+            evaluate_rule_set(&rule_set, &context, payload, accounts)
+        }
+        (LibVersion::V2, serialized_rule_set) => {
+            let rule_set = RuleSetV2::from_bytes(serialized_rule_set)?;
+            evaluate_rule_set(&rule_set, &context, payload, accounts)
+        }
+    }
+}
+
+/// Validation context of a `RuleSet` that is not stored yet, referenced from the PDA derived
+/// from its owner and name.
+fn pre_serialized_context(
+    rule_set: &dyn RuleSet,
+    mint: &Pubkey,
+    operation: &str,
+) -> ValidationContext {
+    let (rule_set_address, _) = find_rule_set_address(*rule_set.owner(), rule_set.name());
+
+    ValidationContext {
+        mint: *mint,
+        operation: operation.to_string(),
+        rule_set_revision: None,
+        rule_sets: vec![rule_set_address],
+        frequency_updates: RefCell::default(),
+    }
+}
+
+/// Evaluate the rule of an operation against `AccountInfo`s built from the snapshots.
+fn evaluate_rule_set<'a>(
+    rule_set: &dyn RuleSet<'a>,
+    context: &ValidationContext,
+    payload: &Payload,
+    accounts: &[AccountSnapshot],
+) -> ProgramResult {
+    let rule = rule_set.get_rule(context.operation.clone())?;
+
+    let mut lamports = vec![0u64; accounts.len()];
+    let mut data = accounts
+        .iter()
+        .map(|account| account.data.clone())
+        .collect::<Vec<Vec<u8>>>();
+
+    let account_infos = accounts
+        .iter()
+        .zip(lamports.iter_mut())
+        .zip(data.iter_mut())
+        .map(|((account, lamports), data)| {
+            AccountInfo::new(
+                &account.key,
+                account.is_signer,
+                false,
+                lamports,
+                data,
+                &account.owner,
+                false,
+                0,
+            )
+        })
+        .collect::<Vec<AccountInfo>>();
+
+    let accounts_map = account_infos
+        .iter()
+        .map(|account| (*account.key, account))
+        .collect::<HashMap<Pubkey, &AccountInfo>>();
+
+    rule.validate(&accounts_map, payload, false, &None, &None, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{evaluate, evaluate_rule_set_account, AccountSnapshot};
+    use crate::{
+        error::RuleSetError,
+        payload::{Payload, PayloadType},
+        state::{
+            AdditionalSigner, Any, Pass, ProgramOwned, RoyaltyPayment, Rule, RuleSetHeader,
+            RuleSetReference, RuleSetRevisionMapV2, RuleSetV1, RuleSetV2,
+            RULE_SET_REV_MAP_V2_VERSION, RULE_SET_SERIALIZED_HEADER_LEN, TOKEN_2022_PROGRAM_ID,
+            U64_BYTES,
+        },
+        types::{LibVersion, RuleSet},
+    };
+    use rmp_serde::Serializer;
+    use serde::Serialize;
+    use solana_program::{
+        program_pack::Pack,
+        pubkey::Pubkey,
+        sysvar::{
+            self,
+            instructions::{construct_instructions_data, BorrowedAccountMeta, BorrowedInstruction},
+        },
+    };
+    use spl_token::{
+        instruction::TokenInstruction,
+        state::{Account, AccountState},
+    };
+
+    #[test]
+    fn test_evaluate_v1() {
+        let mint = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+
+        let mut rule_set = RuleSetV1::new("test rule_set".to_string(), Pubkey::new_unique());
+        rule_set
+            .add(
+                "transfer".to_string(),
+                Rule::AdditionalSigner { account: signer },
+            )
+            .unwrap();
+        rule_set
+            .add("transfer:owner".to_string(), Rule::Namespace)
+            .unwrap();
+
+        let mut serialized_rule_set = Vec::new();
+        rule_set
+            .serialize(&mut Serializer::new(&mut serialized_rule_set))
+            .unwrap();
+
+        let mut accounts = vec![AccountSnapshot {
+            key: signer,
+            owner: solana_program::system_program::ID,
+            is_signer: true,
+            data: vec![],
+        }];
+
+        // Namespace falls back to the "transfer" operation.
+        evaluate(
+            &serialized_rule_set,
+            &mint,
+            "transfer:owner",
+            &Payload::default(),
+            &accounts,
+        )
+        .unwrap();
+
+        accounts[0].is_signer = false;
+        let err = evaluate(
+            &serialized_rule_set,
+            &mint,
+            "transfer:owner",
+            &Payload::default(),
+            &accounts,
+        )
+        .unwrap_err();
+        assert_eq!(err, RuleSetError::AdditionalSignerCheckFailed.into());
+
+        let err = evaluate(
+            &serialized_rule_set,
+            &mint,
+            "burn",
+            &Payload::default(),
+            &accounts,
+        )
+        .unwrap_err();
+        assert_eq!(err, RuleSetError::OperationNotFound.into());
+    }
+
+    #[test]
+    fn test_evaluate_v2() {
+        let mint = Pubkey::new_unique();
+        let program = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+
+        let program_owned = ProgramOwned::serialize("Destination".to_string(), program).unwrap();
+        let adtl_signer = AdditionalSigner::serialize(Pubkey::new_unique()).unwrap();
+        let any = Any::serialize(&[&adtl_signer, &program_owned]).unwrap();
+
+        let serialized_rule_set = RuleSetV2::serialize(
+            Pubkey::new_unique(),
+            "test rule_set",
+            &["transfer".to_string()],
+            &[&any],
+        )
+        .unwrap();
+
+        let payload =
+            Payload::from([("Destination".to_string(), PayloadType::Pubkey(destination))]);
+
+        let mut accounts = vec![AccountSnapshot {
+            key: destination,
+            owner: program,
+            is_signer: false,
+            data: vec![1, 2, 3],
+        }];
+
+        evaluate(&serialized_rule_set, &mint, "transfer", &payload, &accounts).unwrap();
+
+        accounts[0].owner = Pubkey::new_unique();
+        let err =
+            evaluate(&serialized_rule_set, &mint, "transfer", &payload, &accounts).unwrap_err();
+        assert_eq!(err, RuleSetError::ProgramOwnedCheckFailed.into());
+    }
+
+    #[test]
+    fn test_evaluate_rule_set_account() {
+        let mint = Pubkey::new_unique();
+        let mut first_rule_set = RuleSetV1::new("test rule_set".to_string(), Pubkey::new_unique());
+        first_rule_set
+            .add("transfer".to_string(), Rule::Pass)
+            .unwrap();
+
+        let mut second_rule_set =
+            RuleSetV1::new("test rule_set".to_string(), *first_rule_set.owner());
+        second_rule_set
+            .add(
+                "transfer".to_string(),
+                Rule::AdditionalSigner {
+                    account: Pubkey::new_unique(),
+                },
+            )
+            .unwrap();
+
+        // Lay out the `RuleSet` PDA data: header, revisions and revision map.
+        let mut data = vec![0u8; RULE_SET_SERIALIZED_HEADER_LEN];
+        let mut revision_map = RuleSetRevisionMapV2::default();
+
+        for rule_set in [&first_rule_set, &second_rule_set] {
+            revision_map.rule_set_revisions.push(data.len());
+            data.push(LibVersion::V1 as u8);
+            rule_set.serialize(&mut Serializer::new(&mut data)).unwrap();
+        }
+
+        let header = RuleSetHeader::new(data.len());
+        borsh::BorshSerialize::serialize(&header, &mut data.as_mut_slice()).unwrap();
+
+        data.push(RULE_SET_REV_MAP_V2_VERSION);
+        borsh::BorshSerialize::serialize(&revision_map, &mut data).unwrap();
+
+        let rule_set_addr = Pubkey::new_unique();
+
+        // The latest revision requires an additional signer.
+        let err = evaluate_rule_set_account(
+            &rule_set_addr,
+            &data,
+            None,
+            &mint,
+            "transfer",
+            &Payload::default(),
+            &[],
+        )
+        .unwrap_err();
+        assert_eq!(err, RuleSetError::MissingAccount.into());
+
+        // The first revision passes.
+        evaluate_rule_set_account(
+            &rule_set_addr,
+            &data,
+            Some(0),
+            &mint,
+            "transfer",
+            &Payload::default(),
+            &[],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_evaluate_rule_set_reference_depth() {
+        let mint = Pubkey::new_unique();
+
+        // A chain of RuleSets, each referencing the next one, the last one passing.
+        let addresses = (0..5)
+            .map(|_| Pubkey::new_unique())
+            .collect::<Vec<Pubkey>>();
+
+        let accounts = addresses
+            .iter()
+            .enumerate()
+            .map(|(index, address)| {
+                let rule = match addresses.get(index + 1) {
+                    Some(next) => RuleSetReference::serialize(*next, None, None).unwrap(),
+                    None => Pass::serialize().unwrap(),
+                };
+
+                let serialized_rule_set = RuleSetV2::serialize(
+                    Pubkey::new_unique(),
+                    "test rule_set",
+                    &["transfer".to_string()],
+                    &[&rule],
+                )
+                .unwrap();
+
+                // Lay out the `RuleSet` PDA data: header, padding to align the `RuleSetV2`
+                // revision and revision map.
+                let location =
+                    std::alloc::Layout::from_size_align(RULE_SET_SERIALIZED_HEADER_LEN, U64_BYTES)
+                        .unwrap()
+                        .pad_to_align()
+                        .size();
+                let mut data = vec![0u8; location];
+                data.extend_from_slice(&serialized_rule_set);
+
+                let header = RuleSetHeader::new(data.len());
+                borsh::BorshSerialize::serialize(&header, &mut data.as_mut_slice()).unwrap();
+
+                let revision_map = RuleSetRevisionMapV2 {
+                    rule_set_revisions: vec![location],
+                    ..Default::default()
+                };
+                data.push(RULE_SET_REV_MAP_V2_VERSION);
+                borsh::BorshSerialize::serialize(&revision_map, &mut data).unwrap();
+
+                AccountSnapshot {
+                    key: *address,
+                    owner: crate::ID,
+                    is_signer: false,
+                    data,
+                }
+            })
+            .collect::<Vec<AccountSnapshot>>();
+
+        // Five chained RuleSets exceed the maximum depth.
+        let err = evaluate_rule_set_account(
+            &addresses[0],
+            &accounts[0].data,
+            None,
+            &mint,
+            "transfer",
+            &Payload::default(),
+            &accounts[1..],
+        )
+        .unwrap_err();
+        assert_eq!(err, RuleSetError::RuleSetReferenceTooDeep.into());
+
+        // Four chained RuleSets are allowed.
+        evaluate_rule_set_account(
+            &addresses[1],
+            &accounts[1].data,
+            None,
+            &mint,
+            "transfer",
+            &Payload::default(),
+            &accounts[2..],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_evaluate_royalty_payment_transfer_fee() {
+        let mint = Pubkey::new_unique();
+        let payment_mint = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let source = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        // The creator must be paid at least 10% of the sale price.
+        let royalty_payment =
+            RoyaltyPayment::serialize("Amount".to_string(), Some(payment_mint), 1_000, &[creator])
+                .unwrap();
+
+        let serialized_rule_set = RuleSetV2::serialize(
+            Pubkey::new_unique(),
+            "test rule_set",
+            &["transfer".to_string()],
+            &[&royalty_payment],
+        )
+        .unwrap();
+
+        let payload = Payload::from([("Amount".to_string(), PayloadType::Number(100))]);
+
+        // A Token-2022 transfer of 10 tokens to the creator.
+        let transfer_data = TokenInstruction::TransferChecked {
+            amount: 10,
+            decimals: 0,
+        }
+        .pack();
+        let instructions_data = construct_instructions_data(&[BorrowedInstruction {
+            program_id: &TOKEN_2022_PROGRAM_ID,
+            accounts: [&source, &payment_mint, &destination, &authority]
+                .into_iter()
+                .map(|pubkey| BorrowedAccountMeta {
+                    pubkey,
+                    is_signer: pubkey == &authority,
+                    is_writable: pubkey == &source || pubkey == &destination,
+                })
+                .collect(),
+            data: &transfer_data,
+        }]);
+
+        // Token-2022 account with the account type and an empty `ImmutableOwner` extension.
+        let mut token_account_data = vec![0u8; Account::LEN];
+        Account::pack(
+            Account {
+                mint: payment_mint,
+                owner: creator,
+                state: AccountState::Initialized,
+                ..Account::default()
+            },
+            &mut token_account_data,
+        )
+        .unwrap();
+        token_account_data.extend_from_slice(&[2, 7, 0, 0, 0]);
+
+        let mut accounts = vec![
+            AccountSnapshot {
+                key: sysvar::instructions::ID,
+                owner: sysvar::ID,
+                is_signer: false,
+                data: instructions_data,
+            },
+            AccountSnapshot {
+                key: destination,
+                owner: TOKEN_2022_PROGRAM_ID,
+                is_signer: false,
+                data: token_account_data,
+            },
+        ];
+
+        evaluate(&serialized_rule_set, &mint, "transfer", &payload, &accounts).unwrap();
+
+        // Add a `TransferFeeAmount` extension, the fee withheld from the payment is unknown.
+        accounts[1].data.extend_from_slice(&[2, 0, 8, 0]);
+        accounts[1].data.extend_from_slice(&[0; 8]);
+
+        let err =
+            evaluate(&serialized_rule_set, &mint, "transfer", &payload, &accounts).unwrap_err();
+        assert_eq!(err, RuleSetError::RoyaltyPaymentCheckFailed.into());
+    }
+}
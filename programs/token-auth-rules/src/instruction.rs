@@ -5,6 +5,7 @@ use shank::ShankInstruction;
 use solana_program::{
     account_info::AccountInfo,
     instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
 };
 
 #[repr(C)]
@@ -17,6 +18,14 @@ pub enum CreateOrUpdateArgs {
         /// are supported.
         serialized_rule_set: Vec<u8>,
     },
+    /// V2 implementation of the `create` instruction arguments.
+    V2 {
+        /// RuleSet pre-serialized by caller. Both MessagePack and Bytemuck formats
+        /// are supported.
+        serialized_rule_set: Vec<u8>,
+        /// Reject the `RuleSet` if the lint pass finds any problem with it.
+        lint: bool,
+    },
 }
 
 #[repr(C)]
@@ -34,6 +43,20 @@ pub enum ValidateArgs {
         /// Optional revision of the `RuleSet` to use.  If `None`, the latest revision is used.
         rule_set_revision: Option<usize>,
     },
+    /// V2 implementation of the `validate` instruction arguments.
+    V2 {
+        /// `Operation` to validate.
+        operation: String,
+        /// `Payload` data used for rule validation.
+        payload: Payload,
+        /// Update any relevant state stored in Rule, such as the Frequency `last_update` time value.
+        update_rule_state: bool,
+        /// Optional revision of the `RuleSet` to use.  If `None`, the latest revision is used.
+        rule_set_revision: Option<usize>,
+        /// Expected SHA-256 hash of the user-pre-serialized `RuleSet` revision.  Validation fails
+        /// if the hash stored for the revision does not match, or if no hash was stored for it.
+        rule_set_hash: [u8; 32],
+    },
 }
 
 #[repr(C)]
@@ -60,12 +83,72 @@ pub enum PuffRuleSetArgs {
     },
 }
 
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+/// Args for `close_rule_set` instruction.
+pub enum CloseRuleSetArgs {
+    /// V1 implementation of the `close_rule_set` instruction arguments.
+    V1 {
+        /// RuleSet name.  Only used when the `RuleSet` PDA is being closed.
+        rule_set_name: String,
+    },
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+/// Args for `transfer_update_authority` instruction.
+pub enum TransferUpdateAuthorityArgs {
+    /// V1 implementation of the `transfer_update_authority` instruction arguments.
+    V1 {
+        /// RuleSet name.  Only used when the `RuleSet` owner is the update authority.
+        rule_set_name: String,
+        /// The proposed new update authority.
+        new_update_authority: Pubkey,
+    },
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+/// Args for `accept_update_authority` instruction.
+pub enum AcceptUpdateAuthorityArgs {
+    /// V1 implementation of the `accept_update_authority` instruction arguments.
+    V1,
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+/// Args for `freeze_rule_set` instruction.
+pub enum FreezeRuleSetArgs {
+    /// V1 implementation of the `freeze_rule_set` instruction arguments.
+    V1 {
+        /// RuleSet name.  Only used when the `RuleSet` owner is the update authority.
+        rule_set_name: String,
+    },
+}
+
+#[repr(C)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+/// Args for `simulate` instruction.
+pub enum SimulateArgs {
+    /// V1 implementation of the `simulate` instruction arguments.
+    V1 {
+        /// `Operation` to simulate.
+        operation: String,
+        /// `Payload` data used for rule validation.
+        payload: Payload,
+        /// Optional revision of the `RuleSet` to use.  If `None`, the latest revision is used.
+        rule_set_revision: Option<usize>,
+    },
+}
+
 #[derive(Debug, Clone, ShankInstruction, AccountContext, BorshSerialize, BorshDeserialize)]
 #[rustfmt::skip]
 /// Instructions available in this program.
 pub enum RuleSetInstruction {
     /// This instruction stores a caller-pre-serialized `RuleSet` into the rule_set PDA account.
-    #[account(0, signer, writable, name="payer", desc="Payer and creator of the RuleSet")]
+    /// The payer must be the `RuleSet` update authority, which is the `RuleSet` owner (creator)
+    /// unless it has been transferred.
+    #[account(0, signer, writable, name="payer", desc="Payer and update authority of the RuleSet")]
     #[account(1, writable, name="rule_set_pda", desc = "The PDA account where the RuleSet is stored")]
     #[account(2, name = "system_program", desc = "System program")]
     #[account(3, optional, name="buffer_pda", desc = "The buffer to copy a complete ruleset from")]
@@ -99,6 +182,51 @@ pub enum RuleSetInstruction {
     #[account(1, writable, name="rule_set_pda", desc = "The PDA account where the RuleSet is stored")]
     #[account(2, name = "system_program", desc = "System program")]
     PuffRuleSet(PuffRuleSetArgs),
+
+    /// Close the rule_set PDA account and/or the buffer PDA account created by the payer, sending
+    /// their lamports to the destination account.  The account data is zeroed so that the
+    /// rule_set PDA can be reinitialized by `CreateOrUpdate`.  Closing the rule_set PDA requires
+    /// the payer to be the `RuleSet` update authority.
+    #[account(0, signer, writable, name="payer", desc="Payer and update authority of the RuleSet")]
+    #[account(1, writable, name="destination", desc="The account receiving the reclaimed lamports")]
+    #[account(2, optional, writable, name="rule_set_pda", desc = "The PDA account where the RuleSet is stored")]
+    #[account(3, optional, writable, name="buffer_pda", desc = "The PDA account where the RuleSet buffer is stored")]
+    CloseRuleSet(CloseRuleSetArgs),
+
+    /// Propose a new update authority for the rule_set PDA account.  The proposed authority
+    /// becomes the update authority once it signs an `AcceptUpdateAuthority` instruction.  The
+    /// address of the rule_set PDA does not change.
+    #[account(0, signer, writable, name="payer", desc="Payer and current update authority of the RuleSet")]
+    #[account(1, writable, name="rule_set_pda", desc = "The PDA account where the RuleSet is stored")]
+    #[account(2, name = "system_program", desc = "System program")]
+    TransferUpdateAuthority(TransferUpdateAuthorityArgs),
+
+    /// Accept the update authority of the rule_set PDA account proposed by the current update
+    /// authority.
+    #[account(0, signer, writable, name="payer", desc="Payer and pending update authority of the RuleSet")]
+    #[account(1, writable, name="rule_set_pda", desc = "The PDA account where the RuleSet is stored")]
+    #[account(2, name = "system_program", desc = "System program")]
+    AcceptUpdateAuthority(AcceptUpdateAuthorityArgs),
+
+    /// Permanently freeze the rule_set PDA account.  A frozen `RuleSet` can never be updated,
+    /// closed or have its update authority transferred.  The frozen flag is stored in the revision
+    /// map, which can be found using the `RuleSetHeader`.
+    #[account(0, signer, writable, name="payer", desc="Payer and update authority of the RuleSet")]
+    #[account(1, writable, name="rule_set_pda", desc = "The PDA account where the RuleSet is stored")]
+    #[account(2, name = "system_program", desc = "System program")]
+    FreezeRuleSet(FreezeRuleSetArgs),
+
+    /// This instruction is a dry-run of `Validate` for a `RuleSetV2`.  It evaluates every
+    /// constraint of the `Rule` tree for the operation without updating any `Rule` state, and
+    /// returns the Borsh serialized `RuleTrace` as the instruction return data.  The instruction
+    /// succeeds whether or not the `Rule` passes.  A `RuleSetV1` revision cannot be traced and
+    /// fails with `UnsupportedRuleSetVersion`.
+    #[account(0, name="rule_set_pda", desc = "The PDA account where the RuleSet is stored")]
+    #[account(1, name="mint", desc="Mint of token asset")]
+    #[account(2, optional, signer, name="rule_authority", desc="Signing authority for any Rule state updates")]
+    #[account(3, optional, name="rule_set_state_pda", desc = "The PDA account where any RuleSet state is stored")]
+    #[args(additional_rule_accounts: Vec<AccountMeta>)]
+    Simulate(SimulateArgs),
 }
 
 /// Builds a `CreateOrUpdate` instruction.
@@ -206,6 +334,129 @@ impl InstructionBuilder for builders::PuffRuleSet {
     }
 }
 
+/// Builds a `CloseRuleSet` instruction.
+impl InstructionBuilder for builders::CloseRuleSet {
+    fn instruction(&self) -> solana_program::instruction::Instruction {
+        let mut accounts = vec![
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new(self.destination, false),
+        ];
+
+        // Add optional account or `crate::ID`.
+        if let Some(rule_set_pda) = self.rule_set_pda {
+            accounts.push(AccountMeta::new(rule_set_pda, false));
+        } else {
+            accounts.push(AccountMeta::new_readonly(crate::ID, false));
+        }
+
+        // Add optional account or `crate::ID`.
+        if let Some(buffer_pda) = self.buffer_pda {
+            accounts.push(AccountMeta::new(buffer_pda, false));
+        } else {
+            accounts.push(AccountMeta::new_readonly(crate::ID, false));
+        }
+
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: RuleSetInstruction::CloseRuleSet(self.args.clone())
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+}
+
+/// Builds a `TransferUpdateAuthority` instruction.
+impl InstructionBuilder for builders::TransferUpdateAuthority {
+    fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = vec![
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new(self.rule_set_pda, false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ];
+
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: RuleSetInstruction::TransferUpdateAuthority(self.args.clone())
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+}
+
+/// Builds a `AcceptUpdateAuthority` instruction.
+impl InstructionBuilder for builders::AcceptUpdateAuthority {
+    fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = vec![
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new(self.rule_set_pda, false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ];
+
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: RuleSetInstruction::AcceptUpdateAuthority(self.args.clone())
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+}
+
+/// Builds a `FreezeRuleSet` instruction.
+impl InstructionBuilder for builders::FreezeRuleSet {
+    fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = vec![
+            AccountMeta::new(self.payer, true),
+            AccountMeta::new(self.rule_set_pda, false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        ];
+
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: RuleSetInstruction::FreezeRuleSet(self.args.clone())
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+}
+
+/// Builds a `Simulate` instruction.
+impl InstructionBuilder for builders::Simulate {
+    fn instruction(&self) -> solana_program::instruction::Instruction {
+        let mut accounts = vec![
+            AccountMeta::new_readonly(self.rule_set_pda, false),
+            AccountMeta::new_readonly(self.mint, false),
+        ];
+
+        // Add optional account or `crate::ID`.
+        if let Some(rule_authority) = self.rule_authority {
+            accounts.push(AccountMeta::new_readonly(rule_authority, true));
+        } else {
+            accounts.push(AccountMeta::new_readonly(crate::ID, false));
+        }
+
+        // Add optional account or `crate::ID`.
+        if let Some(rule_set_state_pda) = self.rule_set_state_pda {
+            accounts.push(AccountMeta::new_readonly(rule_set_state_pda, false));
+        } else {
+            accounts.push(AccountMeta::new_readonly(crate::ID, false));
+        }
+
+        accounts.extend(self.additional_rule_accounts.clone());
+
+        Instruction {
+            program_id: crate::ID,
+            accounts,
+            data: RuleSetInstruction::Simulate(self.args.clone())
+                .try_to_vec()
+                .unwrap(),
+        }
+    }
+}
+
 /// Account context holding the accounts used by various instructions.
 pub struct Context<'a, T> {
     /// The struct holding the named accounts used by an instruction.
@@ -3,8 +3,12 @@
 pub mod entrypoint;
 #[deny(missing_docs)]
 pub mod error;
+#[deny(missing_docs)]
+pub mod evaluator;
 pub mod instruction;
 #[deny(missing_docs)]
+pub mod lint;
+#[deny(missing_docs)]
 pub mod payload;
 #[deny(missing_docs)]
 pub mod pda;
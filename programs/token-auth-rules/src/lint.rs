@@ -0,0 +1,587 @@
+//! Structural checks for `RuleSet`s.
+//!
+//! The functions in this module look for `RuleSet`s that can be stored by `CreateOrUpdate` but
+//! that do not behave as intended: `Namespace` rules without an operation to fall back to, empty
+//! `All`, `Any` and `Threshold` groups, invalid thresholds, amount ranges and time windows,
+//! payload fields that are empty or do not fit in a `Str32` and operations that can never be
+//! selected.  They can be used off-chain before uploading a `RuleSet`, and are run by
+//! `CreateOrUpdate` when its `lint` argument is set.
+use crate::{
+    error::RuleSetError,
+    state::{OwnedRuleSetV2, OwnedRuleV2, Rule, RuleSetV1, RuleSetV2, Str32, TokenAccountCheck},
+    types::{RuleSet, MAX_NAME_LENGTH},
+};
+
+/// A problem found in a `RuleSet`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+    /// Operation the problem was found in, empty for problems affecting the whole `RuleSet`.
+    pub operation: String,
+    /// Error describing the problem.
+    pub error: RuleSetError,
+}
+
+/// Lint a user-pre-serialized `RuleSet` (MessagePack `RuleSetV1` or `RuleSetV2` bytes, as passed
+/// to `CreateOrUpdate`).
+pub fn lint(serialized_rule_set: &[u8]) -> Result<Vec<LintIssue>, RuleSetError> {
+    if let Ok(rule_set) = rmp_serde::from_slice::<RuleSetV1>(serialized_rule_set) {
+        Ok(lint_rule_set_v1(&rule_set))
+    } else if let Ok(rule_set) = RuleSetV2::from_bytes(serialized_rule_set) {
+        // Lookups return the first matching operation, so a repeated operation is never selected.
+        let mut issues = Vec::new();
+        for (index, operation) in rule_set.operations.iter().enumerate() {
+            if rule_set.operations[..index]
+                .iter()
+                .any(|other| other.value == operation.value)
+            {
+                issues.push(issue(
+                    &operation.to_string(),
+                    RuleSetError::UnreachableOperation,
+                ));
+            }
+        }
+
+        // An `OwnedRuleSetV2` cannot hold repeated operations, so the rules are only checked once
+        // the operations are unique.
+        if issues.is_empty() {
+            issues = lint_owned_rule_set_v2(&OwnedRuleSetV2::from_bytes(serialized_rule_set)?);
+        }
+
+        Ok(issues)
+    } else {
+        Err(RuleSetError::RuleSetReadFailed)
+    }
+}
+
+/// Lint a `RuleSetV1`.  Operations are checked in lexicographic order.
+pub fn lint_rule_set_v1(rule_set: &RuleSetV1) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    lint_name(&rule_set.name(), &mut issues);
+
+    let mut operations = rule_set
+        .operations
+        .iter()
+        .collect::<Vec<(&String, &Rule)>>();
+    operations.sort_by(|a, b| a.0.cmp(b.0));
+
+    let namespaces = operations
+        .iter()
+        .map(|(operation, rule)| (operation.as_str(), matches!(rule, Rule::Namespace)))
+        .collect::<Vec<(&str, bool)>>();
+
+    for (index, (operation, rule)) in operations.iter().enumerate() {
+        lint_operation(index, &namespaces, &mut issues);
+        lint_rule_v1(operation, rule, &mut issues);
+    }
+
+    issues
+}
+
+/// Lint an `OwnedRuleSetV2`, including the lengths of the fields that are serialized as `Str32`.
+/// Operations are checked in the order they are stored.
+pub fn lint_owned_rule_set_v2(rule_set: &OwnedRuleSetV2) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    lint_name(&rule_set.rule_set_name, &mut issues);
+
+    let namespaces = rule_set
+        .operations
+        .iter()
+        .map(|(operation, rule)| (operation.as_str(), matches!(rule, OwnedRuleV2::Namespace)))
+        .collect::<Vec<(&str, bool)>>();
+
+    for (index, (operation, rule)) in rule_set.operations.iter().enumerate() {
+        if operation.len() > Str32::SIZE {
+            issues.push(issue(operation, RuleSetError::FieldTooLong));
+        }
+        lint_operation(index, &namespaces, &mut issues);
+        lint_owned_rule_v2(operation, rule, &mut issues);
+    }
+
+    issues
+}
+
+fn issue(operation: &str, error: RuleSetError) -> LintIssue {
+    LintIssue {
+        operation: operation.to_string(),
+        error,
+    }
+}
+
+fn lint_name(name: &str, issues: &mut Vec<LintIssue>) {
+    if name.len() > MAX_NAME_LENGTH {
+        issues.push(issue("", RuleSetError::NameTooLong));
+    }
+}
+
+/// Check the operation at `index` against the other operations of the `RuleSet`, given in the
+/// order they are looked up along with whether their rule is a `Namespace`.
+fn lint_operation(index: usize, operations: &[(&str, bool)], issues: &mut Vec<LintIssue>) {
+    let (operation, is_namespace) = operations[index];
+
+    // A `Namespace` falls back to the operation before the first ':', which must exist and must
+    // not itself be a `Namespace`.
+    if is_namespace {
+        let fallback = match operation.split_once(':') {
+            Some((prefix, _)) => operations.iter().find(|(other, _)| *other == prefix),
+            None => None,
+        };
+
+        if !matches!(fallback, Some((_, false))) {
+            issues.push(issue(operation, RuleSetError::MissingNamespaceFallback));
+        }
+    }
+}
+
+fn lint_rule_v1(operation: &str, rule: &Rule, issues: &mut Vec<LintIssue>) {
+    match rule {
+        Rule::All { rules } | Rule::Any { rules } => {
+            if rules.is_empty() {
+                issues.push(issue(operation, RuleSetError::EmptyRuleGroup));
+            }
+            for rule in rules {
+                lint_rule_v1(operation, rule, issues);
+            }
+        }
+        Rule::Threshold { k, rules } => {
+            if rules.is_empty() {
+                issues.push(issue(operation, RuleSetError::EmptyRuleGroup));
+            } else {
+                lint_threshold(operation, *k, rules.len(), issues);
+            }
+            for rule in rules {
+                lint_rule_v1(operation, rule, issues);
+            }
+        }
+        Rule::AdditionalSignerList {
+            accounts,
+            threshold,
+        } => lint_threshold(operation, *threshold, accounts.len(), issues),
+        Rule::Not { rule } => lint_rule_v1(operation, rule, issues),
+        Rule::AmountRange { min, max, .. } => lint_amount_range(operation, *min, *max, issues),
+        Rule::TimeWindow { start, end, .. } => lint_time_window(operation, *start, *end, issues),
+        _ => (),
+    }
+}
+
+/// Check the threshold of a `Threshold` group or `AdditionalSignerList` against its number of
+/// rules or signers.
+fn lint_threshold(operation: &str, k: u64, size: usize, issues: &mut Vec<LintIssue>) {
+    if k == 0 || k > size as u64 {
+        issues.push(issue(operation, RuleSetError::InvalidThreshold));
+    }
+}
+
+/// Check that the bounds of an `AmountRange` do not leave it empty.
+fn lint_amount_range(operation: &str, min: u64, max: u64, issues: &mut Vec<LintIssue>) {
+    if min > max {
+        issues.push(issue(operation, RuleSetError::InvalidAmountRange));
+    }
+}
+
+/// Check that the bounds of a `TimeWindow` do not leave it empty.
+fn lint_time_window(operation: &str, start: i64, end: i64, issues: &mut Vec<LintIssue>) {
+    if start >= end {
+        issues.push(issue(operation, RuleSetError::InvalidTimeWindow));
+    }
+}
+
+fn lint_owned_rule_v2(operation: &str, rule: &OwnedRuleV2, issues: &mut Vec<LintIssue>) {
+    let fields = match rule {
+        OwnedRuleV2::All { rules } | OwnedRuleV2::Any { rules } => {
+            if rules.is_empty() {
+                issues.push(issue(operation, RuleSetError::EmptyRuleGroup));
+            }
+            for rule in rules {
+                lint_owned_rule_v2(operation, rule, issues);
+            }
+            vec![]
+        }
+        OwnedRuleV2::Threshold { k, rules } => {
+            if rules.is_empty() {
+                issues.push(issue(operation, RuleSetError::EmptyRuleGroup));
+            } else {
+                lint_threshold(operation, *k, rules.len(), issues);
+            }
+            for rule in rules {
+                lint_owned_rule_v2(operation, rule, issues);
+            }
+            vec![]
+        }
+        OwnedRuleV2::AdditionalSignerList {
+            accounts,
+            threshold,
+        } => {
+            lint_threshold(operation, *threshold, accounts.len(), issues);
+            vec![]
+        }
+        OwnedRuleV2::Not { rule } => {
+            lint_owned_rule_v2(operation, rule, issues);
+            vec![]
+        }
+        OwnedRuleV2::AmountRange { field, min, max } => {
+            lint_amount_range(operation, *min, *max, issues);
+            vec![field]
+        }
+        OwnedRuleV2::TimeWindow { start, end, .. } => {
+            lint_time_window(operation, *start, *end, issues);
+            vec![]
+        }
+        OwnedRuleV2::AccountData { field, .. }
+        | OwnedRuleV2::Amount { field, .. }
+        | OwnedRuleV2::Ed25519Attestation { field, .. }
+        | OwnedRuleV2::IsWallet { field }
+        | OwnedRuleV2::ProgramOwned { field, .. }
+        | OwnedRuleV2::ProgramOwnedList { field, .. }
+        | OwnedRuleV2::ProgramOwnedSet { field, .. }
+        | OwnedRuleV2::PubkeyListMatch { field, .. }
+        | OwnedRuleV2::PubkeyMatch { field, .. }
+        | OwnedRuleV2::RoyaltyPayment { field, .. } => vec![field],
+        OwnedRuleV2::PDAMatch {
+            pda_field,
+            seeds_field,
+            ..
+        } => vec![pda_field, seeds_field],
+        OwnedRuleV2::ProgramOwnedTree {
+            pubkey_field,
+            proof_field,
+            ..
+        }
+        | OwnedRuleV2::PubkeyTreeMatch {
+            pubkey_field,
+            proof_field,
+            ..
+        } => vec![pubkey_field, proof_field],
+        OwnedRuleV2::MintTreeMatch { proof_field, .. } => vec![proof_field],
+        OwnedRuleV2::TokenAccount { field, checks } => {
+            let mut fields = vec![field];
+            fields.extend(checks.iter().filter_map(|check| match check {
+                TokenAccountCheck::OwnerField { field }
+                | TokenAccountCheck::MintField { field }
+                | TokenAccountCheck::DelegateField { field } => Some(field),
+                _ => None,
+            }));
+            fields
+        }
+        OwnedRuleV2::FieldCompare {
+            field, other_field, ..
+        } => vec![field, other_field],
+        OwnedRuleV2::TokenGate {
+            field, owner_field, ..
+        } => vec![field, owner_field],
+        OwnedRuleV2::InstructionMatch { account_field, .. } => account_field.iter().collect(),
+        OwnedRuleV2::RuleSetReference { operation, .. } => operation.iter().collect(),
+        OwnedRuleV2::AdditionalSigner { .. }
+        | OwnedRuleV2::Frequency { .. }
+        | OwnedRuleV2::MintListMatch { .. }
+        | OwnedRuleV2::Namespace
+        | OwnedRuleV2::Pass => vec![],
+    };
+
+    if fields.iter().any(|field| field.len() > Str32::SIZE) {
+        issues.push(issue(operation, RuleSetError::FieldTooLong));
+    }
+
+    // A rule reading a field without a name never finds its value in the `Payload`.
+    if !matches!(rule, OwnedRuleV2::RuleSetReference { .. })
+        && fields.iter().any(|field| field.is_empty())
+    {
+        issues.push(issue(operation, RuleSetError::MissingPayloadValue));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lint, lint_owned_rule_set_v2, lint_rule_set_v1, LintIssue};
+    use crate::{
+        error::RuleSetError,
+        state::{
+            AdditionalSignerList, All, AmountRange, Any, IsWallet, Namespace, OwnedRuleSetV2,
+            OwnedRuleV2, Pass, ProgramOwned, Rule, RuleSetV1, RuleSetV2, Threshold, TimeSource,
+            TimeWindow, HEADER_SECTION,
+        },
+    };
+    use solana_program::pubkey::Pubkey;
+
+    fn issue(operation: &str, error: RuleSetError) -> LintIssue {
+        LintIssue {
+            operation: operation.to_string(),
+            error,
+        }
+    }
+
+    #[test]
+    fn test_lint_namespace_fallback_v1() {
+        let mut rule_set = RuleSetV1::new("test rule_set".to_string(), Pubkey::new_unique());
+        rule_set.add("transfer".to_string(), Rule::Pass).unwrap();
+        rule_set
+            .add("transfer:owner".to_string(), Rule::Namespace)
+            .unwrap();
+        assert!(lint_rule_set_v1(&rule_set).is_empty());
+
+        // No ':' separator, missing fallback operation and a fallback to another `Namespace`.
+        rule_set.add("burn".to_string(), Rule::Namespace).unwrap();
+        rule_set
+            .add("delegate:owner".to_string(), Rule::Namespace)
+            .unwrap();
+        rule_set
+            .add("burn:owner".to_string(), Rule::Namespace)
+            .unwrap();
+
+        assert_eq!(
+            lint_rule_set_v1(&rule_set),
+            vec![
+                issue("burn", RuleSetError::MissingNamespaceFallback),
+                issue("burn:owner", RuleSetError::MissingNamespaceFallback),
+                issue("delegate:owner", RuleSetError::MissingNamespaceFallback),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_empty_rule_group_v1() {
+        let mut rule_set = RuleSetV1::new("test rule_set".to_string(), Pubkey::new_unique());
+        rule_set
+            .add(
+                "transfer".to_string(),
+                Rule::Not {
+                    rule: Box::new(Rule::All {
+                        rules: vec![Rule::Pass, Rule::Any { rules: vec![] }],
+                    }),
+                },
+            )
+            .unwrap();
+        rule_set
+            .add(
+                "delegate".to_string(),
+                Rule::Threshold {
+                    k: 4,
+                    rules: vec![
+                        Rule::Pass,
+                        Rule::Threshold {
+                            k: 1,
+                            rules: vec![],
+                        },
+                        Rule::AdditionalSignerList {
+                            accounts: vec![Pubkey::new_unique()],
+                            threshold: 0,
+                        },
+                    ],
+                },
+            )
+            .unwrap();
+        rule_set
+            .add(
+                "update".to_string(),
+                Rule::AmountRange {
+                    min: 10,
+                    max: 1,
+                    field: "Amount".to_string(),
+                },
+            )
+            .unwrap();
+        rule_set
+            .add(
+                "withdraw".to_string(),
+                Rule::TimeWindow {
+                    source: TimeSource::Slot,
+                    start: 10,
+                    end: 10,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            lint_rule_set_v1(&rule_set),
+            vec![
+                issue("delegate", RuleSetError::InvalidThreshold),
+                issue("delegate", RuleSetError::EmptyRuleGroup),
+                issue("delegate", RuleSetError::InvalidThreshold),
+                issue("transfer", RuleSetError::EmptyRuleGroup),
+                issue("update", RuleSetError::InvalidAmountRange),
+                issue("withdraw", RuleSetError::InvalidTimeWindow),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_serialized_v2() {
+        let pass = Pass::serialize().unwrap();
+        let namespace = Namespace::serialize().unwrap();
+        let program_owned =
+            ProgramOwned::serialize("Destination".to_string(), Pubkey::new_unique()).unwrap();
+        let any = Any::serialize(&[&program_owned, &pass]).unwrap();
+
+        let rule_set = RuleSetV2::serialize(
+            Pubkey::new_unique(),
+            "test rule_set",
+            &["transfer".to_string(), "transfer:owner".to_string()],
+            &[&any, &namespace],
+        )
+        .unwrap();
+        assert!(lint(&rule_set).unwrap().is_empty());
+
+        let rule_set = RuleSetV2::serialize(
+            Pubkey::new_unique(),
+            "test rule_set",
+            &["transfer:owner".to_string()],
+            &[&namespace],
+        )
+        .unwrap();
+        assert_eq!(
+            lint(&rule_set).unwrap(),
+            vec![issue(
+                "transfer:owner",
+                RuleSetError::MissingNamespaceFallback
+            )]
+        );
+
+        assert_eq!(lint(&[1, 2, 3]), Err(RuleSetError::RuleSetReadFailed));
+    }
+
+    #[test]
+    fn test_lint_serialized_v2_rules() {
+        // The serializers reject these values, so patch them into the bytes.
+        let patch = |mut rule: Vec<u8>, offset: usize, value: u64| {
+            rule[HEADER_SECTION + offset..HEADER_SECTION + offset + 8]
+                .copy_from_slice(&value.to_le_bytes());
+            rule
+        };
+
+        let pass = Pass::serialize().unwrap();
+        let threshold = patch(Threshold::serialize(1, &[&pass]).unwrap(), 0, 2);
+        let signer_list = patch(
+            AdditionalSignerList::serialize(1, &[Pubkey::new_unique()]).unwrap(),
+            0,
+            0,
+        );
+        let amount_range = patch(
+            AmountRange::serialize("Amount".to_string(), 1, 10).unwrap(),
+            0,
+            11,
+        );
+        let time_window = patch(
+            TimeWindow::serialize(TimeSource::UnixTimestamp, 1, 10).unwrap(),
+            16,
+            1,
+        );
+        let is_wallet = IsWallet::serialize(String::new()).unwrap();
+
+        let rule_set = RuleSetV2::serialize(
+            Pubkey::new_unique(),
+            "test rule_set",
+            &[
+                "burn".to_string(),
+                "delegate".to_string(),
+                "transfer".to_string(),
+                "update".to_string(),
+                "withdraw".to_string(),
+            ],
+            &[
+                &is_wallet,
+                &threshold,
+                &signer_list,
+                &amount_range,
+                &time_window,
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            lint(&rule_set).unwrap(),
+            vec![
+                issue("burn", RuleSetError::MissingPayloadValue),
+                issue("delegate", RuleSetError::InvalidThreshold),
+                issue("transfer", RuleSetError::InvalidThreshold),
+                issue("update", RuleSetError::InvalidAmountRange),
+                issue("withdraw", RuleSetError::InvalidTimeWindow),
+            ]
+        );
+
+        // `RuleSetV2::serialize` rejects repeated operations, so rename the second one.
+        let mut rule_set = RuleSetV2::serialize(
+            Pubkey::new_unique(),
+            "test rule_set",
+            &["transfer".to_string(), "transfer2".to_string()],
+            &[&pass, &pass],
+        )
+        .unwrap();
+        let index = rule_set
+            .windows(9)
+            .position(|window| window == b"transfer2")
+            .unwrap();
+        rule_set[index + 8] = 0;
+
+        assert_eq!(
+            lint(&rule_set).unwrap(),
+            vec![issue("transfer", RuleSetError::UnreachableOperation)]
+        );
+    }
+
+    #[test]
+    fn test_lint_owned_rule_set_v2() {
+        let long_field = "a".repeat(33);
+
+        let mut rule_set = OwnedRuleSetV2::new("test rule_set".to_string(), Pubkey::new_unique());
+        rule_set
+            .add(
+                "transfer".to_string(),
+                OwnedRuleV2::All {
+                    rules: vec![
+                        OwnedRuleV2::Any { rules: vec![] },
+                        OwnedRuleV2::IsWallet {
+                            field: long_field.clone(),
+                        },
+                    ],
+                },
+            )
+            .unwrap();
+        rule_set.add(long_field.clone(), OwnedRuleV2::Pass).unwrap();
+        rule_set
+            .add(
+                "update".to_string(),
+                OwnedRuleV2::AmountRange {
+                    field: "Amount".to_string(),
+                    min: 10,
+                    max: 1,
+                },
+            )
+            .unwrap();
+        rule_set
+            .add(
+                "withdraw".to_string(),
+                OwnedRuleV2::TimeWindow {
+                    source: TimeSource::Slot,
+                    start: 20,
+                    end: 10,
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            lint_owned_rule_set_v2(&rule_set),
+            vec![
+                issue("transfer", RuleSetError::EmptyRuleGroup),
+                issue("transfer", RuleSetError::FieldTooLong),
+                issue(&long_field, RuleSetError::FieldTooLong),
+                issue("update", RuleSetError::InvalidAmountRange),
+                issue("withdraw", RuleSetError::InvalidTimeWindow),
+            ]
+        );
+
+        // Serializing reports the problems instead of panicking.
+        assert_eq!(rule_set.serialize(), Err(RuleSetError::EmptyRuleGroup));
+        assert_eq!(All::serialize(&[]), Err(RuleSetError::EmptyRuleGroup));
+        assert_eq!(
+            ProgramOwned::serialize(long_field, Pubkey::new_unique()),
+            Err(RuleSetError::FieldTooLong)
+        );
+        assert_eq!(
+            AmountRange::serialize("Amount".to_string(), 10, 1),
+            Err(RuleSetError::InvalidAmountRange)
+        );
+        assert_eq!(
+            TimeWindow::serialize(TimeSource::Slot, 20, 10),
+            Err(RuleSetError::InvalidTimeWindow)
+        );
+    }
+}
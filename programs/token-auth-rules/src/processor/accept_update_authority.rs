@@ -0,0 +1,61 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::{
+    error::RuleSetError,
+    instruction::{AcceptUpdateAuthority, AcceptUpdateAuthorityArgs, Context},
+    utils::{assert_owned_by, get_existing_revision_map, write_revision_map},
+};
+
+// Function to match on `AcceptUpdateAuthorityArgs` version and call correct implementation.
+pub(crate) fn accept_update_authority<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: AcceptUpdateAuthorityArgs,
+) -> ProgramResult {
+    let context = AcceptUpdateAuthority::to_context(accounts)?;
+
+    match args {
+        AcceptUpdateAuthorityArgs::V1 => accept_update_authority_v1(program_id, context),
+    }
+}
+
+/// V1 implementation of the `accept_update_authority` instruction.
+fn accept_update_authority_v1(
+    program_id: &Pubkey,
+    ctx: Context<AcceptUpdateAuthority>,
+) -> ProgramResult {
+    if !ctx.accounts.payer_info.is_signer {
+        return Err(RuleSetError::PayerIsNotSigner.into());
+    }
+
+    // `RuleSet` must be owned by this program.
+    assert_owned_by(ctx.accounts.rule_set_pda_info, program_id)?;
+
+    // `RuleSet` must not be empty.
+    if ctx.accounts.rule_set_pda_info.data_is_empty() {
+        return Err(RuleSetError::DataIsEmpty.into());
+    }
+
+    let (mut revision_map, rev_map_location) =
+        get_existing_revision_map(ctx.accounts.rule_set_pda_info)?;
+
+    // The update authority of a frozen `RuleSet` cannot be transferred.
+    if revision_map.frozen {
+        return Err(RuleSetError::RuleSetFrozen.into());
+    }
+
+    // The payer/signer must be the pending `RuleSet` update authority.
+    if revision_map.pending_update_authority != Some(*ctx.accounts.payer_info.key) {
+        return Err(RuleSetError::PendingUpdateAuthorityMismatch.into());
+    }
+
+    revision_map.update_authority = revision_map.pending_update_authority.take();
+
+    write_revision_map(
+        ctx.accounts.rule_set_pda_info,
+        ctx.accounts.payer_info,
+        ctx.accounts.system_program_info,
+        &revision_map,
+        rev_map_location,
+    )
+}
@@ -0,0 +1,83 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    error::RuleSetError,
+    instruction::{CloseRuleSet, CloseRuleSetArgs, Context},
+    pda::PREFIX,
+    utils::{
+        assert_derivation, assert_owned_by, assert_update_authority, close_account_raw,
+        get_existing_revision_map, is_zeroed,
+    },
+};
+
+// Function to match on `CloseRuleSetArgs` version and call correct implementation.
+pub(crate) fn close_rule_set<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: CloseRuleSetArgs,
+) -> ProgramResult {
+    let context = CloseRuleSet::to_context(accounts)?;
+
+    match args {
+        CloseRuleSetArgs::V1 { .. } => close_rule_set_v1(program_id, context, args),
+    }
+}
+
+/// V1 implementation of the `close_rule_set` instruction.
+fn close_rule_set_v1(
+    program_id: &Pubkey,
+    ctx: Context<CloseRuleSet>,
+    args: CloseRuleSetArgs,
+) -> ProgramResult {
+    let CloseRuleSetArgs::V1 { rule_set_name } = args;
+
+    if !ctx.accounts.payer_info.is_signer {
+        return Err(RuleSetError::PayerIsNotSigner.into());
+    }
+
+    // At least one of the PDAs must be provided.
+    if ctx.accounts.rule_set_pda_info.is_none() && ctx.accounts.buffer_pda_info.is_none() {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    if let Some(rule_set_pda_info) = ctx.accounts.rule_set_pda_info {
+        // `RuleSet` must be owned by this program.
+        assert_owned_by(rule_set_pda_info, program_id)?;
+
+        // The payer/signer must be the `RuleSet` update authority.
+        assert_update_authority(
+            program_id,
+            rule_set_pda_info,
+            ctx.accounts.payer_info.key,
+            &rule_set_name,
+        )?;
+
+        // A frozen `RuleSet` cannot be closed.
+        if !is_zeroed(&rule_set_pda_info.data.borrow())
+            && get_existing_revision_map(rule_set_pda_info)?.0.frozen
+        {
+            return Err(RuleSetError::RuleSetFrozen.into());
+        }
+
+        close_account_raw(rule_set_pda_info, ctx.accounts.destination_info)?;
+    }
+
+    if let Some(buffer_pda_info) = ctx.accounts.buffer_pda_info {
+        // Check buffer account info derivation.
+        assert_derivation(
+            program_id,
+            buffer_pda_info.key,
+            &[PREFIX.as_bytes(), ctx.accounts.payer_info.key.as_ref()],
+        )?;
+
+        // Buffer must be owned by this program.
+        assert_owned_by(buffer_pda_info, program_id)?;
+
+        close_account_raw(buffer_pda_info, ctx.accounts.destination_info)?;
+    }
+
+    Ok(())
+}
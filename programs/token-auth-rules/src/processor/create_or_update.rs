@@ -1,21 +1,22 @@
 use borsh::BorshSerialize;
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     program_memory::sol_memcpy, pubkey::Pubkey,
 };
 
 use crate::{
     error::RuleSetError,
     instruction::{Context, CreateOrUpdate, CreateOrUpdateArgs},
+    lint,
     pda::PREFIX,
     state::{
-        RuleSetHeader, RuleSetRevisionMapV1, RuleSetV1, RuleSetV2, RULE_SET_REV_MAP_VERSION,
+        RuleSetHeader, RuleSetRevisionMapV2, RuleSetV1, RuleSetV2, RULE_SET_REV_MAP_V2_VERSION,
         RULE_SET_SERIALIZED_HEADER_LEN, U64_BYTES,
     },
     types::{LibVersion, RuleSet, MAX_NAME_LENGTH},
     utils::{
-        assert_derivation, create_or_allocate_account_raw, get_existing_revision_map, is_zeroed,
-        resize_or_reallocate_account_raw,
+        assert_derivation, compute_rule_set_hash, create_or_allocate_account_raw,
+        get_existing_revision_map, is_zeroed, resize_or_reallocate_account_raw,
     },
 };
 
@@ -30,7 +31,11 @@ pub(crate) fn create_or_update<'a>(
     match args {
         CreateOrUpdateArgs::V1 {
             serialized_rule_set,
-        } => create_or_update_v1(program_id, context, serialized_rule_set),
+        } => create_or_update_v1(program_id, context, serialized_rule_set, false),
+        CreateOrUpdateArgs::V2 {
+            serialized_rule_set,
+            lint,
+        } => create_or_update_v1(program_id, context, serialized_rule_set, lint),
     }
 }
 
@@ -39,6 +44,7 @@ fn create_or_update_v1(
     program_id: &Pubkey,
     ctx: Context<CreateOrUpdate>,
     serialized_rule_set: Vec<u8>,
+    lint: bool,
 ) -> ProgramResult {
     if !ctx.accounts.payer_info.is_signer {
         return Err(RuleSetError::PayerIsNotSigner.into());
@@ -55,34 +61,48 @@ fn create_or_update_v1(
         return Err(RuleSetError::NameTooLong.into());
     }
 
-    // The payer/signer must be the `RuleSet` owner.
-    if *ctx.accounts.payer_info.key != owner {
+    // Reject the `RuleSet` if the lint pass finds any problem with it.
+    if lint {
+        let issues = match ctx.accounts.buffer_pda_info {
+            Some(account_info) => lint::lint(&(*account_info.data).borrow())?,
+            None => lint::lint(&serialized_rule_set)?,
+        };
+
+        for issue in &issues {
+            msg!("Operation '{}': {}", issue.operation, issue.error);
+        }
+
+        if let Some(issue) = issues.into_iter().next() {
+            return Err(issue.error.into());
+        }
+    }
+
+    let is_new_rule_set = ctx.accounts.rule_set_pda_info.data_is_empty()
+        || is_zeroed(&ctx.accounts.rule_set_pda_info.data.borrow());
+
+    // When creating a `RuleSet`, the payer/signer must be the `RuleSet` owner.
+    if is_new_rule_set && *ctx.accounts.payer_info.key != owner {
         return Err(RuleSetError::RuleSetOwnerMismatch.into());
     }
 
-    // Check `RuleSet` account info derivation.
+    // Check `RuleSet` account info derivation.  The `RuleSet` PDA is always derived from the
+    // `RuleSet` owner (creator), even when the update authority has been transferred.
     let bump = assert_derivation(
         program_id,
         ctx.accounts.rule_set_pda_info.key,
-        &[
-            PREFIX.as_bytes(),
-            ctx.accounts.payer_info.key.as_ref(),
-            rule_set_name.as_bytes(),
-        ],
+        &[PREFIX.as_bytes(), owner.as_ref(), rule_set_name.as_bytes()],
     )?;
 
     let rule_set_seeds = &[
         PREFIX.as_ref(),
-        ctx.accounts.payer_info.key.as_ref(),
+        owner.as_ref(),
         rule_set_name.as_ref(),
         &[bump],
     ];
 
     // Get new or existing revision map.
-    let revision_map = if ctx.accounts.rule_set_pda_info.data_is_empty()
-        || is_zeroed(&ctx.accounts.rule_set_pda_info.data.borrow())
-    {
-        let mut revision_map = RuleSetRevisionMapV1::default();
+    let mut revision_map = if is_new_rule_set {
+        let mut revision_map = RuleSetRevisionMapV2::default();
 
         // Initially set the latest revision location to a the value right after the header.
         revision_map
@@ -102,6 +122,26 @@ fn create_or_update_v1(
         let (mut revision_map, existing_rev_map_loc) =
             get_existing_revision_map(ctx.accounts.rule_set_pda_info)?;
 
+        // A frozen `RuleSet` cannot be updated.
+        if revision_map.frozen {
+            return Err(RuleSetError::RuleSetFrozen.into());
+        }
+
+        // The payer/signer must be the `RuleSet` update authority, which is the `RuleSet` owner
+        // unless it has been transferred.
+        match revision_map.update_authority {
+            Some(update_authority) => {
+                if *ctx.accounts.payer_info.key != update_authority {
+                    return Err(RuleSetError::UpdateAuthorityMismatch.into());
+                }
+            }
+            None => {
+                if *ctx.accounts.payer_info.key != owner {
+                    return Err(RuleSetError::RuleSetOwnerMismatch.into());
+                }
+            }
+        }
+
         // The next `RuleSet` revision will start where the existing revision map was + any
         // alignment required (V2 only)
         revision_map
@@ -118,6 +158,15 @@ fn create_or_update_v1(
         revision_map
     };
 
+    // Store the hash of the new user-pre-serialized `RuleSet` so that it can be pinned by content
+    // during validation.
+    revision_map
+        .revision_hashes
+        .push(match ctx.accounts.buffer_pda_info {
+            Some(account_info) => compute_rule_set_hash(&account_info.data.borrow()),
+            None => compute_rule_set_hash(&serialized_rule_set),
+        });
+
     // Borsh serialize (or re-serialize) the revision map.
     let mut serialized_rev_map = Vec::new();
     revision_map
@@ -270,7 +319,7 @@ fn write_data_to_pda(
         .checked_add(1)
         .ok_or(RuleSetError::NumericalOverflow)?;
     if end <= data.len() {
-        sol_memcpy(&mut data[start..end], &[RULE_SET_REV_MAP_VERSION], 1);
+        sol_memcpy(&mut data[start..end], &[RULE_SET_REV_MAP_V2_VERSION], 1);
     } else {
         return Err(RuleSetError::DataSliceUnexpectedIndexError.into());
     }
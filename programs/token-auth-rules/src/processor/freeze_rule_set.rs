@@ -0,0 +1,71 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::{
+    error::RuleSetError,
+    instruction::{Context, FreezeRuleSet, FreezeRuleSetArgs},
+    utils::{
+        assert_owned_by, assert_update_authority, get_existing_revision_map, write_revision_map,
+    },
+};
+
+// Function to match on `FreezeRuleSetArgs` version and call correct implementation.
+pub(crate) fn freeze_rule_set<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: FreezeRuleSetArgs,
+) -> ProgramResult {
+    let context = FreezeRuleSet::to_context(accounts)?;
+
+    match args {
+        FreezeRuleSetArgs::V1 { .. } => freeze_rule_set_v1(program_id, context, args),
+    }
+}
+
+/// V1 implementation of the `freeze_rule_set` instruction.
+fn freeze_rule_set_v1(
+    program_id: &Pubkey,
+    ctx: Context<FreezeRuleSet>,
+    args: FreezeRuleSetArgs,
+) -> ProgramResult {
+    let FreezeRuleSetArgs::V1 { rule_set_name } = args;
+
+    if !ctx.accounts.payer_info.is_signer {
+        return Err(RuleSetError::PayerIsNotSigner.into());
+    }
+
+    // `RuleSet` must be owned by this program.
+    assert_owned_by(ctx.accounts.rule_set_pda_info, program_id)?;
+
+    // `RuleSet` must not be empty.
+    if ctx.accounts.rule_set_pda_info.data_is_empty() {
+        return Err(RuleSetError::DataIsEmpty.into());
+    }
+
+    // The payer/signer must be the `RuleSet` update authority.
+    assert_update_authority(
+        program_id,
+        ctx.accounts.rule_set_pda_info,
+        ctx.accounts.payer_info.key,
+        &rule_set_name,
+    )?;
+
+    let (mut revision_map, rev_map_location) =
+        get_existing_revision_map(ctx.accounts.rule_set_pda_info)?;
+
+    if revision_map.frozen {
+        return Err(RuleSetError::RuleSetFrozen.into());
+    }
+
+    revision_map.frozen = true;
+
+    // The pending update authority can no longer accept once the `RuleSet` is frozen.
+    revision_map.pending_update_authority = None;
+
+    write_revision_map(
+        ctx.accounts.rule_set_pda_info,
+        ctx.accounts.payer_info,
+        ctx.accounts.system_program_info,
+        &revision_map,
+        rev_map_location,
+    )
+}
@@ -2,8 +2,13 @@
 //!
 //! See state module for description of PDA memory layout.
 
+mod accept_update_authority;
+mod close_rule_set;
 mod create_or_update;
+mod freeze_rule_set;
 mod puff_rule_set;
+mod simulate;
+mod transfer_update_authority;
 mod validate;
 mod write_to_buffer;
 
@@ -16,7 +21,10 @@ use solana_program::{
 use crate::{
     instruction::RuleSetInstruction,
     processor::{
-        create_or_update::create_or_update, puff_rule_set::puff_rule_set, validate::validate,
+        accept_update_authority::accept_update_authority, close_rule_set::close_rule_set,
+        create_or_update::create_or_update, freeze_rule_set::freeze_rule_set,
+        puff_rule_set::puff_rule_set, simulate::simulate,
+        transfer_update_authority::transfer_update_authority, validate::validate,
         write_to_buffer::write_to_buffer,
     },
 };
@@ -50,6 +58,26 @@ impl Processor {
                 msg!("Instruction: PuffRuleSet");
                 puff_rule_set(program_id, accounts, args)
             }
+            RuleSetInstruction::CloseRuleSet(args) => {
+                msg!("Instruction: CloseRuleSet");
+                close_rule_set(program_id, accounts, args)
+            }
+            RuleSetInstruction::TransferUpdateAuthority(args) => {
+                msg!("Instruction: TransferUpdateAuthority");
+                transfer_update_authority(program_id, accounts, args)
+            }
+            RuleSetInstruction::AcceptUpdateAuthority(args) => {
+                msg!("Instruction: AcceptUpdateAuthority");
+                accept_update_authority(program_id, accounts, args)
+            }
+            RuleSetInstruction::FreezeRuleSet(args) => {
+                msg!("Instruction: FreezeRuleSet");
+                freeze_rule_set(program_id, accounts, args)
+            }
+            RuleSetInstruction::Simulate(args) => {
+                msg!("Instruction: Simulate");
+                simulate(program_id, accounts, args)
+            }
         }
     }
 }
@@ -0,0 +1,137 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data,
+    program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::{
+    error::RuleSetError,
+    instruction::{Context, Simulate, SimulateArgs},
+    pda::STATE_PDA,
+    state::{RuleSetV2, RuleTrace},
+    types::{LibVersion, RuleSet, ValidationContext},
+    utils::{assert_derivation, get_existing_revision_map, get_revision_location},
+};
+
+// Function to match on `SimulateArgs` version and call correct implementation.
+pub(crate) fn simulate<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: SimulateArgs,
+) -> ProgramResult {
+    let context = Simulate::to_context(accounts)?;
+
+    match args {
+        SimulateArgs::V1 { .. } => simulate_v1(program_id, context, args),
+    }
+}
+
+/// V1 implementation of the `simulate` instruction.
+fn simulate_v1(program_id: &Pubkey, ctx: Context<Simulate>, args: SimulateArgs) -> ProgramResult {
+    // Get the V1 arguments for the instruction.
+    let SimulateArgs::V1 {
+        operation,
+        payload,
+        rule_set_revision,
+    } = args;
+
+    // `RuleSet` must be owned by this program.
+    if *ctx.accounts.rule_set_pda_info.owner != crate::ID {
+        return Err(RuleSetError::IncorrectOwner.into());
+    }
+
+    // `RuleSet` must not be empty.
+    if ctx.accounts.rule_set_pda_info.data_is_empty() {
+        return Err(RuleSetError::DataIsEmpty.into());
+    }
+
+    // Get existing revision map and its serialized length.
+    let (revision_map, rev_map_location) =
+        get_existing_revision_map(ctx.accounts.rule_set_pda_info)?;
+
+    // Resolve the revision being simulated, the latest one if not provided.
+    let revision = match rule_set_revision {
+        Some(revision) => revision,
+        None => revision_map
+            .rule_set_revisions
+            .len()
+            .checked_sub(1)
+            .ok_or(RuleSetError::RuleSetRevisionNotAvailable)?,
+    };
+
+    // Look up the `RuleSet` revision location in the PDA.
+    let (start, end) = get_revision_location(&revision_map, rev_map_location, Some(revision))?;
+
+    let data = ctx
+        .accounts
+        .rule_set_pda_info
+        .data
+        .try_borrow()
+        .map_err(|_| ProgramError::AccountBorrowFailed)?;
+
+    // Only `RuleSetV2` rule trees can be traced.
+    match data.get(start) {
+        Some(lib_version) => {
+            if !matches!(LibVersion::try_from(*lib_version)?, LibVersion::V2) {
+                return Err(RuleSetError::UnsupportedRuleSetVersion.into());
+            }
+        }
+        None => return Err(RuleSetError::DataTypeMismatch.into()),
+    }
+
+    let rule_set = if end < ctx.accounts.rule_set_pda_info.data_len() {
+        RuleSetV2::from_bytes(&data[start..end])?
+    } else {
+        return Err(RuleSetError::DataTypeMismatch.into());
+    };
+
+    // Check `RuleSet` state PDA account info derivation whenever it is provided, since `Rule`s
+    // read their state from it.
+    if let Some(rule_set_state_pda_info) = ctx.accounts.rule_set_state_pda_info {
+        assert_derivation(
+            program_id,
+            rule_set_state_pda_info.key,
+            &[
+                STATE_PDA.as_bytes(),
+                rule_set.owner().as_ref(),
+                rule_set.name().as_bytes(),
+                ctx.accounts.mint_info.key.as_ref(),
+            ],
+        )?;
+    }
+
+    // Convert remaining `Rule` accounts into a map of `Pubkey`s to the corresponding
+    // `AccountInfo`s.
+    let accounts_map = ctx
+        .remaining_accounts
+        .iter()
+        .map(|account| (*account.key, *account))
+        .collect::<HashMap<Pubkey, &AccountInfo>>();
+
+    let context = ValidationContext {
+        mint: *ctx.accounts.mint_info.key,
+        operation: operation.clone(),
+        rule_set_revision: Some(revision),
+        rule_sets: vec![*ctx.accounts.rule_set_pda_info.key],
+        frequency_updates: RefCell::default(),
+    };
+
+    // Evaluate the `Rule` tree without updating any state.
+    let trace = RuleTrace::evaluate(
+        rule_set.resolve_rule(operation)?,
+        &accounts_map,
+        &payload,
+        &ctx.accounts.rule_set_state_pda_info,
+        &ctx.accounts.rule_authority_info,
+        &context,
+    );
+
+    let serialized_trace = trace
+        .try_to_vec()
+        .map_err(|_| RuleSetError::BorshSerializationError)?;
+    set_return_data(&serialized_trace);
+
+    Ok(())
+}
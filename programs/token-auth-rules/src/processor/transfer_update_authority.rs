@@ -0,0 +1,75 @@
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::{
+    error::RuleSetError,
+    instruction::{Context, TransferUpdateAuthority, TransferUpdateAuthorityArgs},
+    utils::{
+        assert_owned_by, assert_update_authority, get_existing_revision_map, write_revision_map,
+    },
+};
+
+// Function to match on `TransferUpdateAuthorityArgs` version and call correct implementation.
+pub(crate) fn transfer_update_authority<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    args: TransferUpdateAuthorityArgs,
+) -> ProgramResult {
+    let context = TransferUpdateAuthority::to_context(accounts)?;
+
+    match args {
+        TransferUpdateAuthorityArgs::V1 { .. } => {
+            transfer_update_authority_v1(program_id, context, args)
+        }
+    }
+}
+
+/// V1 implementation of the `transfer_update_authority` instruction.
+fn transfer_update_authority_v1(
+    program_id: &Pubkey,
+    ctx: Context<TransferUpdateAuthority>,
+    args: TransferUpdateAuthorityArgs,
+) -> ProgramResult {
+    let TransferUpdateAuthorityArgs::V1 {
+        rule_set_name,
+        new_update_authority,
+    } = args;
+
+    if !ctx.accounts.payer_info.is_signer {
+        return Err(RuleSetError::PayerIsNotSigner.into());
+    }
+
+    // `RuleSet` must be owned by this program.
+    assert_owned_by(ctx.accounts.rule_set_pda_info, program_id)?;
+
+    // `RuleSet` must not be empty.
+    if ctx.accounts.rule_set_pda_info.data_is_empty() {
+        return Err(RuleSetError::DataIsEmpty.into());
+    }
+
+    // The payer/signer must be the current `RuleSet` update authority.
+    assert_update_authority(
+        program_id,
+        ctx.accounts.rule_set_pda_info,
+        ctx.accounts.payer_info.key,
+        &rule_set_name,
+    )?;
+
+    // Store the proposed update authority in the revision map.
+    let (mut revision_map, rev_map_location) =
+        get_existing_revision_map(ctx.accounts.rule_set_pda_info)?;
+
+    // The update authority of a frozen `RuleSet` cannot be transferred.
+    if revision_map.frozen {
+        return Err(RuleSetError::RuleSetFrozen.into());
+    }
+
+    revision_map.pending_update_authority = Some(new_update_authority);
+
+    write_revision_map(
+        ctx.accounts.rule_set_pda_info,
+        ctx.accounts.payer_info,
+        ctx.accounts.system_program_info,
+        &revision_map,
+        rev_map_location,
+    )
+}
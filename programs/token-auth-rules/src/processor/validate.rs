@@ -1,17 +1,23 @@
-use std::collections::HashMap;
+use std::{cell::RefCell, collections::HashMap};
+
+use borsh::BorshSerialize;
 
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey,
 };
 
 use crate::{
     error::RuleSetError,
     instruction::{Context, Validate, ValidateArgs},
+    payload::Payload,
     pda::STATE_PDA,
-    state::{RuleSetV1, RuleSetV2},
-    types::{LibVersion, RuleSet},
-    utils::{assert_derivation, get_existing_revision_map},
+    state::{FrequencyAccount, RuleSetV1, RuleSetV2, SolanaAccount},
+    types::{LibVersion, RuleSet, ValidationContext},
+    utils::{
+        assert_derivation, create_or_allocate_account_raw, get_existing_revision_map,
+        get_revision_location, resize_or_reallocate_account_raw,
+    },
 };
 
 // Function to match on `ValidateArgs` version and call correct implementation.
@@ -23,20 +29,49 @@ pub(crate) fn validate<'a>(
     let context = Validate::to_context(accounts)?;
 
     match args {
-        ValidateArgs::V1 { .. } => validate_v1(program_id, context, args),
+        ValidateArgs::V1 {
+            operation,
+            payload,
+            update_rule_state,
+            rule_set_revision,
+        } => validate_v1(
+            program_id,
+            context,
+            operation,
+            payload,
+            update_rule_state,
+            rule_set_revision,
+            None,
+        ),
+        ValidateArgs::V2 {
+            operation,
+            payload,
+            update_rule_state,
+            rule_set_revision,
+            rule_set_hash,
+        } => validate_v1(
+            program_id,
+            context,
+            operation,
+            payload,
+            update_rule_state,
+            rule_set_revision,
+            Some(rule_set_hash),
+        ),
     }
 }
 
-/// V1 implementation of the `validate` instruction.
-fn validate_v1(program_id: &Pubkey, ctx: Context<Validate>, args: ValidateArgs) -> ProgramResult {
-    // Get the V1 arguments for the instruction.
-    let ValidateArgs::V1 {
-        operation,
-        payload,
-        update_rule_state,
-        rule_set_revision,
-    } = args;
-
+/// V1 implementation of the `validate` instruction.  `ValidateArgs::V2` additionally provides
+/// the expected hash of the `RuleSet` revision.
+fn validate_v1(
+    program_id: &Pubkey,
+    ctx: Context<Validate>,
+    operation: String,
+    payload: Payload,
+    update_rule_state: bool,
+    rule_set_revision: Option<usize>,
+    rule_set_hash: Option<[u8; 32]>,
+) -> ProgramResult {
     // If state is being updated for any `Rule`s, the payer must be present and must be a signer so
     // that the `RuleSet` state PDA can be created or reallocated.
     if update_rule_state {
@@ -63,32 +98,37 @@ fn validate_v1(program_id: &Pubkey, ctx: Context<Validate>, args: ValidateArgs)
     let (revision_map, rev_map_location) =
         get_existing_revision_map(ctx.accounts.rule_set_pda_info)?;
 
-    // Use the user-provided revision number to look up the `RuleSet` revision location in the PDA.
-    let (start, end) = match rule_set_revision {
-        Some(revision) => {
-            let start = revision_map
-                .rule_set_revisions
-                .get(revision)
-                .ok_or(RuleSetError::RuleSetRevisionNotAvailable)?;
+    // Resolve the revision being validated, the latest one if not provided.
+    let revision = match rule_set_revision {
+        Some(revision) => revision,
+        None => revision_map
+            .rule_set_revisions
+            .len()
+            .checked_sub(1)
+            .ok_or(RuleSetError::RuleSetRevisionNotAvailable)?,
+    };
 
-            let end_index = revision
-                .checked_add(1)
-                .ok_or(RuleSetError::NumericalOverflow)?;
+    // If provided, the expected hash must match the hash stored for the `RuleSet` revision.
+    if let Some(rule_set_hash) = rule_set_hash {
+        let stored_hash = revision_map
+            .revision_hashes
+            .get(revision)
+            .ok_or(RuleSetError::RuleSetRevisionNotAvailable)?;
 
-            let end = revision_map
-                .rule_set_revisions
-                .get(end_index)
-                .unwrap_or(&rev_map_location);
-            (*start, *end)
+        // Revisions written before hashes were stored have an all-zero hash.
+        if *stored_hash == [0; 32] {
+            msg!("Revision {} hash is not available", revision);
+            return Err(RuleSetError::RuleSetHashUnavailable.into());
         }
-        None => {
-            let start = revision_map
-                .rule_set_revisions
-                .last()
-                .ok_or(RuleSetError::RuleSetRevisionNotAvailable)?;
-            (*start, rev_map_location)
+
+        if *stored_hash != rule_set_hash {
+            msg!("Revision {} hash mismatch", revision);
+            return Err(RuleSetError::RuleSetHashMismatch.into());
         }
-    };
+    }
+
+    // Look up the `RuleSet` revision location in the PDA.
+    let (start, end) = get_revision_location(&revision_map, rev_map_location, Some(revision))?;
 
     // Mutably borrow the existing `RuleSet` PDA data.
     let data = ctx
@@ -130,24 +170,28 @@ fn validate_v1(program_id: &Pubkey, ctx: Context<Validate>, args: ValidateArgs)
         }
     };
 
-    // If `RuleSet` state is to be updated, check account info derivation.
-    if update_rule_state {
-        if let Some(rule_set_state_pda_info) = ctx.accounts.rule_set_state_pda_info {
-            let _bump = assert_derivation(
-                program_id,
-                rule_set_state_pda_info.key,
-                &[
-                    STATE_PDA.as_bytes(),
-                    rule_set.owner().as_ref(),
-                    rule_set.name().as_bytes(),
-                    ctx.accounts.mint_info.key.as_ref(),
-                ],
-            )?;
-        } else {
-            return Err(ProgramError::NotEnoughAccountKeys);
-        }
+    // If `RuleSet` state is to be updated, the `RuleSet` state PDA must be provided.
+    if update_rule_state && ctx.accounts.rule_set_state_pda_info.is_none() {
+        return Err(ProgramError::NotEnoughAccountKeys);
     }
 
+    // Check `RuleSet` state PDA account info derivation whenever it is provided, since `Rule`s
+    // read their state from it.
+    let rule_set_name = rule_set.name();
+    let rule_set_state_bump = match ctx.accounts.rule_set_state_pda_info {
+        Some(rule_set_state_pda_info) => Some(assert_derivation(
+            program_id,
+            rule_set_state_pda_info.key,
+            &[
+                STATE_PDA.as_bytes(),
+                rule_set.owner().as_ref(),
+                rule_set_name.as_bytes(),
+                ctx.accounts.mint_info.key.as_ref(),
+            ],
+        )?),
+        None => None,
+    };
+
     // Convert remaining `Rule` accounts into a map of `Pubkey`s to the corresponding
     // `AccountInfo`s.  This makes it easy to pass the account infos into validation functions
     // since they store the `Pubkey`s.
@@ -157,6 +201,14 @@ fn validate_v1(program_id: &Pubkey, ctx: Context<Validate>, args: ValidateArgs)
         .map(|account| (*account.key, *account))
         .collect::<HashMap<Pubkey, &AccountInfo>>();
 
+    let context = ValidationContext {
+        mint: *ctx.accounts.mint_info.key,
+        operation: operation.clone(),
+        rule_set_revision: Some(revision),
+        rule_sets: vec![*ctx.accounts.rule_set_pda_info.key],
+        frequency_updates: RefCell::default(),
+    };
+
     // Validate the `Rule`.
     rule_set.get_rule(operation)?.validate(
         &accounts_map,
@@ -164,5 +216,63 @@ fn validate_v1(program_id: &Pubkey, ctx: Context<Validate>, args: ValidateArgs)
         update_rule_state,
         &ctx.accounts.rule_set_state_pda_info,
         &ctx.accounts.rule_authority_info,
-    )
+        &context,
+    )?;
+
+    // Store the state recorded by the `Rule`s, which only happens when `update_rule_state` is
+    // set, creating the `RuleSet` state PDA if it does not exist yet.
+    let frequency_updates = context.frequency_updates.take();
+
+    if let (Some(rule_set_state_pda_info), Some(bump), false) = (
+        ctx.accounts.rule_set_state_pda_info,
+        rule_set_state_bump,
+        frequency_updates.is_empty(),
+    ) {
+        let payer_info = ctx
+            .accounts
+            .payer_info
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+        let mut frequency_account = if rule_set_state_pda_info.data_is_empty() {
+            FrequencyAccount::new(vec![])
+        } else {
+            FrequencyAccount::from_account_info(rule_set_state_pda_info)?
+        };
+
+        for state in frequency_updates {
+            frequency_account.update(state);
+        }
+
+        let size = frequency_account.try_to_vec()?.len();
+
+        if rule_set_state_pda_info.data_is_empty() {
+            let rule_set_state_seeds = &[
+                STATE_PDA.as_bytes(),
+                rule_set.owner().as_ref(),
+                rule_set_name.as_bytes(),
+                ctx.accounts.mint_info.key.as_ref(),
+                &[bump],
+            ];
+
+            create_or_allocate_account_raw(
+                *program_id,
+                rule_set_state_pda_info,
+                ctx.accounts.system_program_info,
+                payer_info,
+                size,
+                rule_set_state_seeds,
+            )?;
+        } else if size > rule_set_state_pda_info.data_len() {
+            resize_or_reallocate_account_raw(
+                rule_set_state_pda_info,
+                payer_info,
+                ctx.accounts.system_program_info,
+                size,
+            )?;
+        }
+
+        frequency_account.to_account_data(rule_set_state_pda_info)?;
+    }
+
+    Ok(())
 }
@@ -0,0 +1,41 @@
+use solana_program::{account_info::AccountInfo, msg, pubkey::Pubkey};
+use std::collections::HashMap;
+
+use super::RuleResult;
+use crate::error::RuleSetError;
+
+/// Check that at least `threshold` of the `signers` signed the transaction.
+///
+/// Signers missing from `accounts` count as not signed and repeated signers are only counted
+/// once.  Evaluation stops as soon as `threshold` signatures are found.
+pub(crate) fn validate_additional_signer_list(
+    accounts: &HashMap<Pubkey, &AccountInfo>,
+    signers: &[Pubkey],
+    threshold: u64,
+) -> RuleResult {
+    if threshold == 0 || threshold > signers.len() as u64 {
+        return RuleResult::Error(RuleSetError::InvalidThreshold.into());
+    }
+
+    let mut found = 0;
+
+    for (index, signer) in signers.iter().enumerate() {
+        if signers[..index].contains(signer) {
+            continue;
+        }
+
+        if accounts
+            .get(signer)
+            .is_some_and(|account| account.is_signer)
+        {
+            found += 1;
+
+            if found >= threshold {
+                return RuleResult::Success(RuleSetError::AdditionalSignerCheckFailed.into());
+            }
+        }
+    }
+
+    msg!("Found {} of {} required signatures", found, threshold);
+    RuleResult::Failure(RuleSetError::AdditionalSignerCheckFailed.into())
+}
@@ -0,0 +1,43 @@
+use solana_program::msg;
+
+use super::{Operator, RuleResult};
+use crate::{
+    error::RuleSetError,
+    payload::{Payload, PayloadType},
+};
+
+/// Check that the value of `field` compares to the value of `other_field` using `operator`.
+///
+/// Both fields must hold values of the same `PayloadType`: `Pubkey` values support `Operator::Eq`
+/// and `Operator::Ne`, while `Number` values support every operator.
+pub(crate) fn validate_field_compare(
+    payload: &Payload,
+    field: &String,
+    operator: &Operator,
+    other_field: &String,
+) -> RuleResult {
+    let result = match (payload.get(field), payload.get(other_field)) {
+        (Some(PayloadType::Pubkey(value)), Some(PayloadType::Pubkey(other))) => match operator {
+            Operator::Eq => value == other,
+            Operator::Ne => value != other,
+            _ => {
+                msg!("Pubkey values can only be compared for equality");
+                return RuleResult::Error(RuleSetError::InvalidCompareOp.into());
+            }
+        },
+        (Some(PayloadType::Number(value)), Some(PayloadType::Number(other))) => {
+            operator.compare(*value, *other)
+        }
+        (Some(_), Some(_)) => {
+            msg!("Payload values must be Pubkey or Number values of the same type");
+            return RuleResult::Error(RuleSetError::DataTypeMismatch.into());
+        }
+        _ => return RuleResult::Error(RuleSetError::MissingPayloadValue.into()),
+    };
+
+    if result {
+        RuleResult::Success(RuleSetError::FieldCompareCheckFailed.into())
+    } else {
+        RuleResult::Failure(RuleSetError::FieldCompareCheckFailed.into())
+    }
+}
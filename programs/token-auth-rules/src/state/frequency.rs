@@ -1,26 +1,57 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, msg, program_error::ProgramError, sysvar::Sysvar,
+};
 
-use super::{Key, SolanaAccount};
+use super::{Key, RuleResult, SolanaAccount};
+use crate::{error::RuleSetError, types::ValidationContext, utils::is_zeroed};
 
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, ShankAccount)]
-/// An account containing frequency state.
+/// An account containing frequency state.  The `RuleSet` state PDA of a mint holds the state of
+/// every `Frequency` rule evaluated for it, so that rules of different operations or with
+/// different periods do not share a cooldown.
 pub struct FrequencyAccount {
     /// The `Key` for this account which identifies it as a Frequency account.
     pub key: Key,
-    /// The last time the frequency counter was updated.
-    pub last_update: i64,
+    /// The state of each `Frequency` rule, keyed by operation and period.
+    pub frequencies: Vec<FrequencyState>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+/// The state of a `Frequency` rule.
+pub struct FrequencyState {
+    /// The operation validated by the rule.
+    pub operation: String,
     /// The period which must transpire before the rule will succeed again.
     pub period: i64,
+    /// The last time the frequency counter was updated.
+    pub last_update: i64,
 }
 
 impl FrequencyAccount {
     /// Create a new `FrequencyAccount`.
-    pub fn new(last_update: i64, period: i64) -> Self {
+    pub fn new(frequencies: Vec<FrequencyState>) -> Self {
         Self {
             key: Key::Frequency,
-            last_update,
-            period,
+            frequencies,
+        }
+    }
+
+    /// Retrieve the state of the `Frequency` rule with the given operation and period.
+    pub fn get(&self, operation: &str, period: i64) -> Option<&FrequencyState> {
+        self.frequencies
+            .iter()
+            .find(|state| state.operation == operation && state.period == period)
+    }
+
+    /// Store the state of a `Frequency` rule, replacing its previous state if any.
+    pub fn update(&mut self, state: FrequencyState) {
+        match self.frequencies.iter_mut().find(|existing| {
+            existing.operation == state.operation && existing.period == state.period
+        }) {
+            Some(existing) => existing.last_update = state.last_update,
+            None => self.frequencies.push(state),
         }
     }
 }
@@ -30,3 +61,58 @@ impl SolanaAccount for FrequencyAccount {
         Key::Frequency
     }
 }
+
+/// Check that `period` seconds have passed since the last update of the `Frequency` rule of the
+/// operation being validated, as stored in the `RuleSet` state PDA.  A rule without stored state
+/// always passes.  When `update_rule_state` is `true`, the current time is recorded in the
+/// `context` and stored as the last update once the validation succeeds.
+pub(crate) fn validate_frequency(
+    rule_set_state_pda: &Option<&AccountInfo>,
+    period: i64,
+    update_rule_state: bool,
+    context: &ValidationContext,
+) -> RuleResult {
+    let rule_set_state_pda = match rule_set_state_pda {
+        Some(rule_set_state_pda) => rule_set_state_pda,
+        None => return RuleResult::Error(RuleSetError::MissingAccount.into()),
+    };
+
+    let now = match Clock::get() {
+        Ok(clock) => clock.unix_timestamp,
+        Err(err) => return RuleResult::Error(err),
+    };
+
+    let is_initialized = match rule_set_state_pda.data.try_borrow() {
+        Ok(data) => !is_zeroed(&data),
+        Err(_) => return RuleResult::Error(ProgramError::AccountBorrowFailed),
+    };
+
+    if is_initialized {
+        let frequency_account = match FrequencyAccount::from_account_info(rule_set_state_pda) {
+            Ok(frequency_account) => frequency_account,
+            Err(err) => return RuleResult::Error(err),
+        };
+
+        if let Some(state) = frequency_account.get(&context.operation, period) {
+            let elapsed = match now.checked_sub(state.last_update) {
+                Some(elapsed) => elapsed,
+                None => return RuleResult::Error(RuleSetError::NumericalOverflow.into()),
+            };
+
+            if elapsed < period {
+                msg!("Frequency period has not elapsed");
+                return RuleResult::Failure(RuleSetError::FrequencyCheckFailed.into());
+            }
+        }
+    }
+
+    if update_rule_state {
+        context.frequency_updates.borrow_mut().push(FrequencyState {
+            operation: context.operation.clone(),
+            period,
+            last_update: now,
+        });
+    }
+
+    RuleResult::Success(RuleSetError::FrequencyCheckFailed.into())
+}
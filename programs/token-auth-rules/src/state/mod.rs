@@ -1,12 +1,16 @@
 //! All structures and related functions representing a Rule Set on-chain.
 //!
 //! Key types include the main `RuleSetV1` type which keeps the the map of operations to `Rules`,
-//! `RuleSetV2` type which keep a list of `RuleV2`, as well as `RuleSetHeader` and `RuleSetRevisionMapV1`
+//! `RuleSetV2` type which keep a list of `RuleV2`, as well as `RuleSetHeader` and `RuleSetRevisionMapV2`
 //! types used to manage data within the `RuleSet` PDA.
 //!
 //! Each time a `RuleSet` is updated, a new revision is added to the PDA, and previous revisions
 //! never deleted. The revision map is needed so that during `RuleSet` validation the desired
-//! revision can be selected by the user.
+//! revision can be selected by the user.  The revision map also stores the update authority of the
+//! `RuleSet`, which defaults to the `RuleSet` owner (creator) but can be transferred without
+//! changing the PDA address, and whether the `RuleSet` is frozen, in which case no new revisions
+//! can be added.  PDAs written before the update authority was introduced hold a
+//! `RuleSetRevisionMapV1`, which is upgraded to a `RuleSetRevisionMapV2` on the next update.
 //!
 //! Because the `RuleSet`s and the revision map are variable size, a fixed size header is stored
 //! at the beginning of the `RuleSet` PDA that allows new `RuleSets` and updated revision maps
@@ -25,6 +29,16 @@
 //! When `RuleSetV2` revisions are added, there migh be the need to add padding bytes to align the data
 //! to 8 bytes.
 //! ```
+//!
+//! The header is never versioned, so the frozen flag lives in the `RuleSetRevisionMapV2` rather
+//! than in the header.  To read it, load the header, skip the 1-byte revision map version at
+//! `rev_map_version_location` and Borsh deserialize the `RuleSetRevisionMapV2` that follows:
+//! ```text
+//! | rule_set_revisions      | update_authority | pending_update_authority | frozen | revision_hashes          |
+//! |-------------------------|------------------|--------------------------|--------|--------------------------|
+//! | 4 + 8 * revisions bytes | 1 or 33 bytes    | 1 or 33 bytes            | 1 byte | 4 + 32 * revisions bytes |
+//! ```
+//! A PDA holding a `RuleSetRevisionMapV1` (version 1) is never frozen.
 use borsh::{BorshDeserialize, BorshSerialize};
 use num_derive::FromPrimitive;
 use num_traits::FromPrimitive;
@@ -32,14 +46,22 @@ use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
 };
 
+mod additional_signer_list;
+mod field_compare;
 mod frequency;
 mod rule_set;
 mod rules;
+mod threshold;
+mod time_window;
 mod v2;
 
+pub(crate) use additional_signer_list::*;
+pub(crate) use field_compare::*;
 pub use frequency::*;
 pub use rule_set::*;
 pub use rules::*;
+pub(crate) use threshold::*;
+pub use time_window::*;
 pub use v2::*;
 
 use crate::{error::RuleSetError, utils::assert_owned_by};
@@ -88,7 +110,8 @@ pub trait SolanaAccount: BorshSerialize + BorshDeserialize {
     /// BorshSerialize the Rust data structure into the `Account` data.
     fn to_account_data(&self, account: &AccountInfo) -> ProgramResult {
         let mut data = account.try_borrow_mut_data()?;
-        self.serialize(&mut *data).map_err(Into::into)
+        // Serialize through a temporary slice so the account's data slice is not advanced.
+        self.serialize(&mut &mut data[..]).map_err(Into::into)
     }
 }
 
@@ -14,6 +14,9 @@ use std::collections::HashMap;
 /// Version of the `RuleSetRevisionMapV1` struct.
 pub const RULE_SET_REV_MAP_VERSION: u8 = 1;
 
+/// Version of the `RuleSetRevisionMapV2` struct.
+pub const RULE_SET_REV_MAP_V2_VERSION: u8 = 2;
+
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
 /// Header used to keep track of where RuleSets are stored in the PDA.  This header is meant
 /// to be stored at the beginning of the PDA and never be versioned so that it always
@@ -47,6 +50,41 @@ pub struct RuleSetRevisionMapV1 {
     pub rule_set_revisions: Vec<usize>,
 }
 
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Default)]
+/// Revision map used to keep track of where individual `RuleSet` revisions are stored in the PDA,
+/// as well as the authority allowed to update the `RuleSet` and whether it is frozen.  See
+/// top-level module for description of PDA memory layout.
+pub struct RuleSetRevisionMapV2 {
+    /// `Vec` used to map a `RuleSet` revision number to its location in the PDA.
+    pub rule_set_revisions: Vec<usize>,
+    /// The authority allowed to update the `RuleSet`.  If `None`, the `RuleSet` owner (creator)
+    /// is the update authority.
+    pub update_authority: Option<Pubkey>,
+    /// The new update authority proposed by the current update authority, which becomes the
+    /// update authority once it accepts.
+    pub pending_update_authority: Option<Pubkey>,
+    /// Whether the `RuleSet` is frozen.  A frozen `RuleSet` can never be updated or closed.
+    /// The flag follows the two variable-size authority options, so clients read it by
+    /// deserializing the revision map (see the `isRuleSetFrozen` helpers in the JS clients).
+    pub frozen: bool,
+    /// `Vec` used to map a `RuleSet` revision number to the SHA-256 hash of its
+    /// user-pre-serialized data.  Revisions written before hashes were stored have an all-zero
+    /// hash and cannot be validated against an expected hash.
+    pub revision_hashes: Vec<[u8; 32]>,
+}
+
+impl From<RuleSetRevisionMapV1> for RuleSetRevisionMapV2 {
+    fn from(revision_map: RuleSetRevisionMapV1) -> Self {
+        Self {
+            revision_hashes: vec![[0; 32]; revision_map.rule_set_revisions.len()],
+            rule_set_revisions: revision_map.rule_set_revisions,
+            update_authority: None,
+            pending_update_authority: None,
+            frozen: false,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 /// The struct containing all Rule Set data, most importantly the map of operations to `Rules`.
@@ -91,6 +129,28 @@ impl RuleSetV1 {
     pub fn get(&self, operation: String) -> Option<&Rule> {
         self.operations.get(&operation)
     }
+
+    /// Retrieve the `Rule` tree for a given `Operation` by recursively searching through
+    /// namespace fallbacks.
+    pub fn resolve_rule(&self, operation: String) -> Result<&Rule, ProgramError> {
+        let rule = self.get(operation.to_string());
+
+        match rule {
+            Some(Rule::Namespace) => {
+                // Check for a ':' namespace separator. If it exists try to operation namespace to see if
+                // a fallback exists. E.g. 'transfer:owner' will check for a fallback for 'transfer'.
+                // If it doesn't exist then fail.
+                let split = operation.split(':').collect::<Vec<&str>>();
+                if split.len() > 1 {
+                    self.resolve_rule(split[0].to_owned())
+                } else {
+                    Err(RuleSetError::OperationNotFound.into())
+                }
+            }
+            Some(r) => Ok(r),
+            None => Err(RuleSetError::OperationNotFound.into()),
+        }
+    }
 }
 
 impl<'a> RuleSet<'a> for RuleSetV1 {
@@ -109,22 +169,6 @@ impl<'a> RuleSet<'a> for RuleSetV1 {
 
     /// This function returns the rule for an operation by recursively searching through fallbacks
     fn get_rule(&self, operation: String) -> Result<&dyn Assertable<'a>, ProgramError> {
-        let rule = self.get(operation.to_string());
-
-        match rule {
-            Some(Rule::Namespace) => {
-                // Check for a ':' namespace separator. If it exists try to operation namespace to see if
-                // a fallback exists. E.g. 'transfer:owner' will check for a fallback for 'transfer'.
-                // If it doesn't exist then fail.
-                let split = operation.split(':').collect::<Vec<&str>>();
-                if split.len() > 1 {
-                    self.get_rule(split[0].to_owned())
-                } else {
-                    Err(RuleSetError::OperationNotFound.into())
-                }
-            }
-            Some(r) => Ok(r),
-            None => Err(RuleSetError::OperationNotFound.into()),
-        }
+        Ok(self.resolve_rule(operation)?)
     }
 }
@@ -1,10 +1,12 @@
 use crate::{
     error::RuleSetError,
     payload::Payload,
-    types::Assertable,
-    // TODO: Uncomment this after on-curve sycall available.
-    // utils::is_on_curve,
-    utils::{assert_derivation, compute_merkle_root, is_zeroed},
+    state::{
+        validate_additional_signer_list, validate_field_compare, validate_frequency,
+        validate_threshold, validate_time_window, Operator, TimeSource,
+    },
+    types::{Assertable, ValidationContext},
+    utils::{assert_derivation, compute_merkle_root, is_on_curve, is_zeroed},
 };
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "serde-with-feature")]
@@ -28,6 +30,10 @@ pub enum CompareOp {
     GtEq,
     /// Greater Than
     Gt,
+    /// Not Equal To
+    Ne,
+    /// Multiple Of
+    MultipleOf,
 }
 
 /// Enum representation of Rule failure conditions
@@ -174,14 +180,18 @@ pub enum Rule {
         /// The field the amount is stored in.
         field: String,
     },
-    /// Comparison based on time between operations.  Currently not implemented.  This rule
-    /// is planned check to ensure a certain amount of time has passed.  This rule will make use
-    /// of the `rule_set_state_pda` optional account passed into `Validate`, and will require
-    /// the optional `rule_authority` account to sign.
+    /// Comparison based on time between operations.  This rule checks that at least `period`
+    /// seconds have passed since the last update stored in the `rule_set_state_pda` optional
+    /// account passed into `Validate`, and requires the optional `rule_authority` account to
+    /// sign.  When `update_rule_state` is `true`, the current time is stored in the
+    /// `rule_set_state_pda`.
     Frequency {
         /// The authority of the frequency account.
         #[cfg_attr(feature = "serde-with-feature", serde(with = "As::<DisplayFromStr>"))]
         authority: Pubkey,
+        /// The period (in seconds) which must transpire before the rule will succeed again.
+        #[serde(default)]
+        period: i64,
     },
     /// The true test if a pubkey can be signed from a client and therefore is a true wallet account.
     /// The details of this rule are as follows: a wallet is defined as being both owned by the
@@ -210,6 +220,77 @@ pub enum Rule {
     },
     /// A rule that tells the operation finder to use the default namespace rule.
     Namespace,
+    /// Comparison based on when the operation happens.  This rule checks that the `Clock` value
+    /// selected by `source` is inside the `[start, end)` window.  `start` must be before `end`.
+    TimeWindow {
+        /// The `Clock` value to compare.
+        source: TimeSource,
+        /// Start of the window (inclusive).
+        start: i64,
+        /// End of the window (exclusive).
+        end: i64,
+    },
+    /// Comparison against a range of amounts.  When the `Validate` instruction is called, this
+    /// rule requires a `PayloadType` value of `PayloadType::Amount`.  The `field` value in the Rule
+    /// is used to locate the numerical amount in the payload, which must be inside the inclusive
+    /// `[min, max]` range stored in the rule.  `min` cannot be greater than `max`.
+    AmountRange {
+        /// The minimum amount (inclusive).
+        min: u64,
+        /// The maximum amount (inclusive).
+        max: u64,
+        /// The field the amount is stored in.
+        field: String,
+    },
+    /// Comparison between two values in the `Payload`.  The `field` and `other_field` values in
+    /// the rule are used to locate values of the same `PayloadType` in the payload, which are
+    /// compared using the operator stored in the rule.  `Pubkey` values can only be compared with
+    /// `CompareOp::Eq` and `CompareOp::Ne`, while `Number` values support every operator.
+    FieldCompare {
+        /// The field in the `Payload` holding the value to compare.
+        field: String,
+        /// The operator to be used in the comparison.
+        operator: CompareOp,
+        /// The field in the `Payload` holding the value compared against.
+        other_field: String,
+    },
+    /// At least `threshold` of the additional signers must be present.  When the `Validate`
+    /// instruction is called, this rule does not require any `Payload` values, but the accounts of
+    /// the additional signers that signed must be provided to `Validate` via the
+    /// `additional_rule_accounts` argument so that whether they are signers can be retrieved from
+    /// their `AccountInfo` structs.
+    AdditionalSignerList {
+        /// The public keys that may have also signed the transaction.
+        accounts: Vec<Pubkey>,
+        /// The number of public keys that must have also signed the transaction.
+        threshold: u64,
+    },
+    /// Group threshold, where at least `k` of the rules contained must pass.  Rules are evaluated
+    /// in order and evaluation stops as soon as `k` rules passed or `k` can no longer be reached.
+    Threshold {
+        /// The number of rules that must pass.
+        k: u64,
+        /// The vector of Rules contained under Threshold.
+        rules: Vec<Rule>,
+    },
+    /// The mint being validated must be in the list of `Pubkey`s.  This rule does not require
+    /// any `Payload` values, the mint passed to the `Validate` instruction is compared.
+    MintListMatch {
+        /// The list of mints to be compared against.
+        mints: Vec<Pubkey>,
+    },
+    /// The mint being validated must be a member of the Merkle tree in the rule.  When the
+    /// `Validate` instruction is called, this rule requires a `PayloadType` value of
+    /// `PayloadType::MerkleProof`.  The `proof_field` value in the Rule is used to locate it in
+    /// the `Payload`.  The mint passed to the `Validate` instruction and the proof are used to
+    /// calculate a Merkle root which is compared against the root stored in the rule.
+    MintTreeMatch {
+        /// The root of the Merkle tree.
+        root: [u8; 32],
+        /// The field in the `Payload` to be compared
+        /// when looking for the Merkle proof.
+        proof_field: String,
+    },
 }
 
 impl<'a> Assertable<'a> for Rule {
@@ -221,6 +302,7 @@ impl<'a> Assertable<'a> for Rule {
         update_rule_state: bool,
         rule_set_state_pda: &Option<&AccountInfo>,
         rule_authority: &Option<&AccountInfo>,
+        context: &ValidationContext,
     ) -> ProgramResult {
         let result = self.low_level_validate(
             accounts,
@@ -228,6 +310,7 @@ impl<'a> Assertable<'a> for Rule {
             update_rule_state,
             rule_set_state_pda,
             rule_authority,
+            context,
         );
 
         match result {
@@ -244,9 +327,10 @@ impl Rule {
         &self,
         accounts: &HashMap<Pubkey, &AccountInfo>,
         payload: &Payload,
-        _update_rule_state: bool,
-        _rule_set_state_pda: &Option<&AccountInfo>,
+        update_rule_state: bool,
+        rule_set_state_pda: &Option<&AccountInfo>,
         rule_authority: &Option<&AccountInfo>,
+        context: &ValidationContext,
     ) -> RuleResult {
         match self {
             Rule::All { rules } => {
@@ -256,9 +340,10 @@ impl Rule {
                     let result = rule.low_level_validate(
                         accounts,
                         payload,
-                        _update_rule_state,
-                        _rule_set_state_pda,
+                        update_rule_state,
+                        rule_set_state_pda,
                         rule_authority,
+                        context,
                     );
                     // Return failure on the first failing rule.
                     match result {
@@ -278,9 +363,10 @@ impl Rule {
                     let result = rule.low_level_validate(
                         accounts,
                         payload,
-                        _update_rule_state,
-                        _rule_set_state_pda,
+                        update_rule_state,
+                        rule_set_state_pda,
                         rule_authority,
+                        context,
                     );
                     match result {
                         Success(_) => return result,
@@ -305,9 +391,10 @@ impl Rule {
                 let result = rule.low_level_validate(
                     accounts,
                     payload,
-                    _update_rule_state,
-                    _rule_set_state_pda,
+                    update_rule_state,
+                    rule_set_state_pda,
                     rule_authority,
+                    context,
                 );
 
                 // Negate the result.
@@ -362,9 +449,10 @@ impl Rule {
                     return new_rule.low_level_validate(
                         accounts,
                         payload,
-                        _update_rule_state,
-                        _rule_set_state_pda,
+                        update_rule_state,
+                        rule_set_state_pda,
                         rule_authority,
+                        context,
                     );
                 } else {
                     let key = match payload.get_pubkey(&field.to_owned()) {
@@ -503,9 +591,10 @@ impl Rule {
                     return new_rule.low_level_validate(
                         accounts,
                         payload,
-                        _update_rule_state,
-                        _rule_set_state_pda,
+                        update_rule_state,
+                        rule_set_state_pda,
                         rule_authority,
+                        context,
                     );
                 } else {
                     let key = match payload.get_pubkey(&field.to_string()) {
@@ -600,15 +689,7 @@ impl Rule {
             } => {
                 msg!("Validating Amount");
                 if let Some(payload_amount) = &payload.get_amount(field) {
-                    let operator_fn = match operator {
-                        CompareOp::Lt => PartialOrd::lt,
-                        CompareOp::LtEq => PartialOrd::le,
-                        CompareOp::Eq => PartialEq::eq,
-                        CompareOp::Gt => PartialOrd::gt,
-                        CompareOp::GtEq => PartialOrd::ge,
-                    };
-
-                    if operator_fn(payload_amount, rule_amount) {
+                    if Operator::from(operator.clone()).compare(*payload_amount, *rule_amount) {
                         Success(self.to_error())
                     } else {
                         Failure(self.to_error())
@@ -617,7 +698,7 @@ impl Rule {
                     Error(RuleSetError::MissingPayloadValue.into())
                 }
             }
-            Rule::Frequency { authority } => {
+            Rule::Frequency { authority, period } => {
                 msg!("Validating Frequency");
 
                 if let Some(rule_authority) = rule_authority {
@@ -630,7 +711,7 @@ impl Rule {
                     return Error(RuleSetError::MissingAccount.into());
                 }
 
-                Error(RuleSetError::NotImplemented.into())
+                validate_frequency(rule_set_state_pda, *period, update_rule_state, context)
             }
             Rule::Pass => {
                 msg!("Validating Pass");
@@ -649,18 +730,18 @@ impl Rule {
                 // its owner is the System Program.
                 if let Some(account) = accounts.get(key) {
                     if *account.owner != system_program::ID {
-                        // TODO: Change error return to commented line after on-curve syscall
-                        // available.
-                        return Error(RuleSetError::NotImplemented.into());
-                        //return (false, self.to_error());
+                        return Failure(self.to_error());
                     }
                 } else {
                     return Error(RuleSetError::MissingAccount.into());
                 }
 
-                // TODO: Uncomment call to `is_on_curve()` after on-curve sycall available.
-                Error(RuleSetError::NotImplemented.into())
-                //(is_on_curve(key), self.to_error())
+                // A wallet address must be on the ed25519 curve, which rules out PDAs.
+                if is_on_curve(key) {
+                    Success(self.to_error())
+                } else {
+                    Failure(self.to_error())
+                }
             }
             Rule::ProgramOwnedSet { programs, field } => {
                 msg!("Validating ProgramOwnedSet");
@@ -681,9 +762,10 @@ impl Rule {
                     return new_rule.low_level_validate(
                         accounts,
                         payload,
-                        _update_rule_state,
-                        _rule_set_state_pda,
+                        update_rule_state,
+                        rule_set_state_pda,
                         rule_authority,
+                        context,
                     );
                 } else {
                     let key = match payload.get_pubkey(&field.to_string()) {
@@ -722,6 +804,85 @@ impl Rule {
                 msg!("Validating Namespace");
                 Failure(self.to_error())
             }
+            Rule::TimeWindow { source, start, end } => {
+                msg!("Validating TimeWindow");
+                validate_time_window(*source, *start, *end)
+            }
+            Rule::AmountRange { min, max, field } => {
+                msg!("Validating AmountRange");
+                if let Some(payload_amount) = &payload.get_amount(field) {
+                    if min <= payload_amount && payload_amount <= max {
+                        Success(self.to_error())
+                    } else {
+                        Failure(self.to_error())
+                    }
+                } else {
+                    Error(RuleSetError::MissingPayloadValue.into())
+                }
+            }
+            Rule::FieldCompare {
+                field,
+                operator,
+                other_field,
+            } => {
+                msg!("Validating FieldCompare");
+                validate_field_compare(
+                    payload,
+                    field,
+                    &Operator::from(operator.clone()),
+                    other_field,
+                )
+            }
+            Rule::AdditionalSignerList {
+                accounts: signers,
+                threshold,
+            } => {
+                msg!("Validating AdditionalSignerList");
+                validate_additional_signer_list(accounts, signers, *threshold)
+            }
+            Rule::Threshold { k, rules } => {
+                msg!("Validating Threshold");
+                validate_threshold(
+                    *k,
+                    rules.len(),
+                    rules.iter().map(|rule| {
+                        rule.low_level_validate(
+                            accounts,
+                            payload,
+                            update_rule_state,
+                            rule_set_state_pda,
+                            rule_authority,
+                            context,
+                        )
+                    }),
+                )
+            }
+            Rule::MintListMatch { mints } => {
+                msg!("Validating MintListMatch");
+
+                if mints.contains(&context.mint) {
+                    Success(self.to_error())
+                } else {
+                    Failure(self.to_error())
+                }
+            }
+            Rule::MintTreeMatch { root, proof_field } => {
+                msg!("Validating MintTreeMatch");
+
+                // Get the Merkle proof from the payload.
+                let merkle_proof = match payload.get_merkle_proof(proof_field) {
+                    Some(merkle_proof) => merkle_proof,
+                    _ => return Error(RuleSetError::MissingPayloadValue.into()),
+                };
+
+                // Check if the computed hash (root) is equal to the root in the rule.
+                let computed_root = compute_merkle_root(&context.mint, merkle_proof);
+                if computed_root == *root {
+                    Success(self.to_error())
+                } else {
+                    Failure(self.to_error())
+                }
+            }
         }
     }
 
@@ -733,7 +894,9 @@ impl Rule {
             | Rule::Not { .. }
             | Rule::Pass
             | Rule::Namespace => RuleSetError::UnexpectedRuleSetFailure.into(),
-            Rule::AdditionalSigner { .. } => RuleSetError::AdditionalSignerCheckFailed.into(),
+            Rule::AdditionalSigner { .. } | Rule::AdditionalSignerList { .. } => {
+                RuleSetError::AdditionalSignerCheckFailed.into()
+            }
             Rule::PubkeyMatch { .. } => RuleSetError::PubkeyMatchCheckFailed.into(),
             Rule::PubkeyListMatch { .. } => RuleSetError::PubkeyListMatchCheckFailed.into(),
             Rule::PubkeyTreeMatch { .. } => RuleSetError::PubkeyTreeMatchCheckFailed.into(),
@@ -741,10 +904,17 @@ impl Rule {
             Rule::ProgramOwned { .. } => RuleSetError::ProgramOwnedCheckFailed.into(),
             Rule::ProgramOwnedList { .. } => RuleSetError::ProgramOwnedListCheckFailed.into(),
             Rule::ProgramOwnedTree { .. } => RuleSetError::ProgramOwnedTreeCheckFailed.into(),
-            Rule::Amount { .. } => RuleSetError::AmountCheckFailed.into(),
+            Rule::Amount { .. } | Rule::AmountRange { .. } => {
+                RuleSetError::AmountCheckFailed.into()
+            }
             Rule::Frequency { .. } => RuleSetError::FrequencyCheckFailed.into(),
             Rule::IsWallet { .. } => RuleSetError::IsWalletCheckFailed.into(),
             Rule::ProgramOwnedSet { .. } => RuleSetError::ProgramOwnedSetCheckFailed.into(),
+            Rule::TimeWindow { .. } => RuleSetError::TimeWindowCheckFailed.into(),
+            Rule::FieldCompare { .. } => RuleSetError::FieldCompareCheckFailed.into(),
+            Rule::Threshold { .. } => RuleSetError::ThresholdCheckFailed.into(),
+            Rule::MintListMatch { .. } => RuleSetError::MintListMatchCheckFailed.into(),
+            Rule::MintTreeMatch { .. } => RuleSetError::MintTreeMatchCheckFailed.into(),
         }
     }
 }
@@ -0,0 +1,49 @@
+use solana_program::program_error::ProgramError;
+
+use super::RuleResult;
+use crate::error::RuleSetError;
+
+/// Check that at least `k` of the `results` of the `size` rules of a `Threshold` group are
+/// successes.
+///
+/// `results` is evaluated lazily and in order, so evaluation stops as soon as `k` rules passed or
+/// `k` can no longer be reached.  When the threshold is not reached, a failure is returned if at
+/// least one rule failed, otherwise the last error.
+pub(crate) fn validate_threshold(
+    k: u64,
+    size: usize,
+    results: impl Iterator<Item = RuleResult>,
+) -> RuleResult {
+    let size = size as u64;
+    if k == 0 || k > size {
+        return RuleResult::Error(RuleSetError::InvalidThreshold.into());
+    }
+
+    let mut passed = 0;
+    let mut last_failure: Option<ProgramError> = None;
+    let mut last_error: Option<ProgramError> = None;
+
+    for (index, result) in results.enumerate() {
+        match result {
+            RuleResult::Success(_) => passed += 1,
+            RuleResult::Failure(err) => last_failure = Some(err),
+            RuleResult::Error(err) => last_error = Some(err),
+        }
+
+        // Stop as soon as the threshold is reached or can no longer be reached.
+        if passed >= k {
+            return RuleResult::Success(RuleSetError::ThresholdCheckFailed.into());
+        } else if passed + (size - index as u64 - 1) < k {
+            break;
+        }
+    }
+
+    // Return a failure if at least one rule failed, otherwise return the last error.
+    if last_failure.is_some() {
+        RuleResult::Failure(RuleSetError::ThresholdCheckFailed.into())
+    } else if let Some(err) = last_error {
+        RuleResult::Error(err)
+    } else {
+        RuleResult::Error(RuleSetError::UnexpectedRuleSetFailure.into())
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use solana_program::{clock::Clock, msg, sysvar::Sysvar};
+
+use super::RuleResult;
+use crate::error::RuleSetError;
+
+#[repr(u64)]
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, Copy)]
+/// `Clock` value compared against the bounds of a time window.
+pub enum TimeSource {
+    /// `Clock::unix_timestamp`, in seconds.
+    UnixTimestamp,
+    /// `Clock::slot`.
+    Slot,
+}
+
+impl TryFrom<u64> for TimeSource {
+    // Type of the error generated.
+    type Error = RuleSetError;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TimeSource::UnixTimestamp),
+            1 => Ok(TimeSource::Slot),
+            _ => Err(RuleSetError::InvalidTimeSource),
+        }
+    }
+}
+
+/// Check that the current `Clock` value selected by `source` is inside the `[start, end)` window.
+/// A window whose `start` is not before its `end` is an error, since it can never pass.
+pub(crate) fn validate_time_window(source: TimeSource, start: i64, end: i64) -> RuleResult {
+    if start >= end {
+        return RuleResult::Error(RuleSetError::InvalidTimeWindow.into());
+    }
+
+    let clock = match Clock::get() {
+        Ok(clock) => clock,
+        Err(err) => return RuleResult::Error(err),
+    };
+
+    let now = match source {
+        TimeSource::UnixTimestamp => clock.unix_timestamp,
+        TimeSource::Slot => match i64::try_from(clock.slot) {
+            Ok(slot) => slot,
+            Err(_) => return RuleResult::Error(RuleSetError::NumericalOverflow.into()),
+        },
+    };
+
+    if start <= now && now < end {
+        RuleResult::Success(RuleSetError::TimeWindowCheckFailed.into())
+    } else {
+        msg!("{} is outside of the [{}, {}) window", now, start, end);
+        RuleResult::Failure(RuleSetError::TimeWindowCheckFailed.into())
+    }
+}
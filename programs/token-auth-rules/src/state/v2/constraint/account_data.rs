@@ -0,0 +1,214 @@
+use solana_program::{msg, program_error::ProgramError};
+
+use crate::{
+    error::RuleSetError,
+    state::{try_from_bytes, RuleResult},
+    state::{
+        v2::{padded_len, Constraint, ConstraintType, Operator, Str32, HEADER_SECTION, U64_BYTES},
+        Header,
+    },
+};
+
+/// Constraint representing a comparison against a slice of an account's data.
+///
+/// This constraint requires a `PayloadType` value of `PayloadType::Pubkey`.  The `field` value in
+/// the rule is used to locate the `Pubkey` in the payload, and the `data[offset..offset + len]`
+/// slice of its account is compared to the `expected` bytes stored in the rule, after applying
+/// the optional `mask` to both.  `Operator::Eq` compares the bytes; the other operators read up
+/// to 8 bytes as a little-endian unsigned integer.  Note this same `Pubkey` account must also be
+/// provided to `Validate` via the `additional_rule_accounts` argument.
+pub struct AccountData<'a> {
+    /// The field in the `Payload` to be compared.
+    pub field: &'a Str32,
+    /// Offset of the slice in the account data.
+    pub offset: &'a u64,
+    /// The operator to be used in the comparison.
+    pub operator: &'a u64,
+    /// The expected bytes.
+    pub expected: &'a [u8],
+    /// The mask applied to the account data and the expected bytes, if any.
+    pub mask: Option<&'a [u8]>,
+}
+
+impl<'a> AccountData<'a> {
+    /// Deserialize a constraint from a byte array.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        // field
+        let field = try_from_bytes::<Str32>(0, Str32::SIZE, bytes)?;
+        let mut cursor = Str32::SIZE;
+
+        // offset
+        let offset = try_from_bytes::<u64>(cursor, U64_BYTES, bytes)?;
+        cursor += U64_BYTES;
+
+        // operator
+        let operator = try_from_bytes::<u64>(cursor, U64_BYTES, bytes)?;
+        cursor += U64_BYTES;
+
+        // len
+        let len = *try_from_bytes::<u64>(cursor, U64_BYTES, bytes)? as usize;
+        cursor += U64_BYTES;
+
+        // has_mask
+        let has_mask = *try_from_bytes::<u64>(cursor, U64_BYTES, bytes)? != 0;
+        cursor += U64_BYTES;
+
+        // expected
+        let expected = bytes
+            .get(cursor..cursor.saturating_add(len))
+            .ok_or(RuleSetError::RuleSetReadFailed)?;
+        cursor += padded_len(len);
+
+        // mask
+        let mask = if has_mask {
+            Some(
+                bytes
+                    .get(cursor..cursor.saturating_add(len))
+                    .ok_or(RuleSetError::RuleSetReadFailed)?,
+            )
+        } else {
+            None
+        };
+
+        Ok(Self {
+            field,
+            offset,
+            operator,
+            expected,
+            mask,
+        })
+    }
+
+    /// Serialize a constraint into a byte array.
+    pub fn serialize(
+        field: String,
+        offset: u64,
+        operator: Operator,
+        expected: &[u8],
+        mask: Option<&[u8]>,
+    ) -> Result<Vec<u8>, RuleSetError> {
+        // sanity check: the mask must cover the expected bytes and numeric comparisons are
+        // limited to u64 values
+        if expected.is_empty()
+            || mask.is_some_and(|mask| mask.len() != expected.len())
+            || (operator != Operator::Eq && expected.len() > U64_BYTES)
+        {
+            return Err(RuleSetError::DataTypeMismatch);
+        }
+
+        // length of the assert
+        let length = (Str32::SIZE
+            + (U64_BYTES * 4)
+            + padded_len(expected.len()) * if mask.is_some() { 2 } else { 1 })
+            as u32;
+        let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
+
+        // Header
+        Header::serialize(ConstraintType::AccountData, length, &mut data);
+
+        // Constraint
+        // - field
+        data.extend(Str32::serialize(&field)?);
+        // - offset
+        data.extend(u64::to_le_bytes(offset));
+        // - operator
+        data.extend(u64::to_le_bytes(operator as u64));
+        // - len
+        data.extend(u64::to_le_bytes(expected.len() as u64));
+        // - has_mask
+        data.extend(u64::to_le_bytes(mask.is_some() as u64));
+        // - expected (padded to keep the following rules aligned)
+        data.extend(expected);
+        data.resize(data.len() + padded_len(expected.len()) - expected.len(), 0);
+        // - mask
+        if let Some(mask) = mask {
+            data.extend(mask);
+            data.resize(data.len() + padded_len(mask.len()) - mask.len(), 0);
+        }
+
+        Ok(data)
+    }
+
+    /// Value of the byte at `index` after applying the mask.
+    fn masked(&self, bytes: &[u8], index: usize) -> u8 {
+        match self.mask {
+            Some(mask) => bytes[index] & mask[index],
+            None => bytes[index],
+        }
+    }
+
+    /// Little-endian unsigned integer value of the bytes after applying the mask.
+    fn masked_value(&self, bytes: &[u8]) -> u64 {
+        (0..bytes.len()).rev().fold(0, |value, index| {
+            (value << 8) | self.masked(bytes, index) as u64
+        })
+    }
+}
+
+impl<'a> Constraint<'a> for AccountData<'a> {
+    fn constraint_type(&self) -> ConstraintType {
+        ConstraintType::AccountData
+    }
+
+    fn validate(
+        &self,
+        accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        payload: &crate::payload::Payload,
+        _update_rule_state: bool,
+        _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating AccountData");
+
+        let key = match payload.get_pubkey(&self.field.to_string()) {
+            Some(pubkey) => pubkey,
+            _ => return RuleResult::Error(RuleSetError::MissingPayloadValue.into()),
+        };
+
+        let account = match accounts.get(key) {
+            Some(account) => account,
+            _ => return RuleResult::Error(RuleSetError::MissingAccount.into()),
+        };
+
+        let data = match account.data.try_borrow() {
+            Ok(data) => data,
+            Err(_) => return RuleResult::Error(ProgramError::AccountBorrowFailed),
+        };
+
+        if data.is_empty() {
+            msg!("Account data is empty");
+            return RuleResult::Error(RuleSetError::DataIsEmpty.into());
+        }
+
+        let actual = match (*self.offset as usize)
+            .checked_add(self.expected.len())
+            .and_then(|end| data.get(*self.offset as usize..end))
+        {
+            Some(actual) => actual,
+            None => {
+                msg!("Account data is too short");
+                return RuleResult::Error(RuleSetError::DataSliceUnexpectedIndexError.into());
+            }
+        };
+
+        let result = match Operator::try_from(*self.operator) {
+            Ok(Operator::Eq) => (0..actual.len())
+                .all(|index| self.masked(actual, index) == self.masked(self.expected, index)),
+            Ok(operator) => {
+                operator.compare(self.masked_value(actual), self.masked_value(self.expected))
+            }
+            // sanity check: the value is checked at creation
+            Err(_) => return RuleResult::Failure(self.constraint_type().to_error()),
+        };
+
+        if result {
+            RuleResult::Success(self.constraint_type().to_error())
+        } else {
+            RuleResult::Failure(self.constraint_type().to_error())
+        }
+    }
+}
@@ -63,6 +63,7 @@ impl<'a> Constraint<'a> for AdditionalSigner<'a> {
         _update_rule_state: bool,
         _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
         _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
     ) -> RuleResult {
         msg!("Validating AdditionalSigner");
 
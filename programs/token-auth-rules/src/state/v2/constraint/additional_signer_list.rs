@@ -0,0 +1,86 @@
+use solana_program::{
+    msg,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+
+use crate::{
+    error::RuleSetError,
+    state::{
+        try_cast_slice,
+        v2::{Constraint, ConstraintType, HEADER_SECTION, U64_BYTES},
+        validate_additional_signer_list, Header,
+    },
+    state::{try_from_bytes, RuleResult},
+};
+
+/// Constraint representing the requirement that at least `threshold` of the additional signers
+/// must be present.
+///
+/// This constraint does not require any `Payload` values, but the accounts of the additional
+/// signers that signed must be provided to `Validate` via the `additional_rule_accounts` argument
+/// so that whether they are signers can be retrieved from their `AccountInfo` structs.
+pub struct AdditionalSignerList<'a> {
+    /// The number of public keys that must have also signed the transaction.
+    pub threshold: &'a u64,
+    /// The public keys that may have also signed the transaction.
+    pub accounts: &'a [Pubkey],
+}
+
+impl<'a> AdditionalSignerList<'a> {
+    /// Deserialize a constraint from a byte array.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        let threshold = try_from_bytes::<u64>(0, U64_BYTES, bytes)?;
+        let accounts = try_cast_slice(&bytes[U64_BYTES..])?;
+
+        Ok(Self {
+            threshold,
+            accounts,
+        })
+    }
+
+    /// Serialize a constraint into a byte array.
+    pub fn serialize(threshold: u64, accounts: &[Pubkey]) -> Result<Vec<u8>, RuleSetError> {
+        if threshold == 0 || threshold > accounts.len() as u64 {
+            return Err(RuleSetError::InvalidThreshold);
+        }
+
+        let length = (U64_BYTES + (accounts.len() * PUBKEY_BYTES)) as u32;
+        let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
+
+        // Header
+        Header::serialize(ConstraintType::AdditionalSignerList, length, &mut data);
+
+        // Constraint
+        // - threshold
+        data.extend(u64::to_le_bytes(threshold));
+        // - accounts
+        accounts.iter().for_each(|p| {
+            data.extend(p.as_ref());
+        });
+
+        Ok(data)
+    }
+}
+
+impl<'a> Constraint<'a> for AdditionalSignerList<'a> {
+    fn constraint_type(&self) -> ConstraintType {
+        ConstraintType::AdditionalSignerList
+    }
+
+    fn validate(
+        &self,
+        accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        _payload: &crate::payload::Payload,
+        _update_rule_state: bool,
+        _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating AdditionalSignerList");
+
+        validate_additional_signer_list(accounts, self.accounts, *self.threshold)
+    }
+}
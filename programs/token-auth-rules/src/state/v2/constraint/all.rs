@@ -42,8 +42,7 @@ impl<'a> All<'a> {
                 .iter()
                 .map(|v| v.len())
                 .reduce(|accum, item| accum + item)
-                .ok_or(RuleSetError::DataIsEmpty)
-                .unwrap()) as u32;
+                .ok_or(RuleSetError::EmptyRuleGroup)?) as u32;
 
         let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
 
@@ -66,6 +65,25 @@ impl<'a> Constraint<'a> for All<'a> {
         ConstraintType::All
     }
 
+    fn rules(&self) -> &[RuleV2<'a>] {
+        &self.rules
+    }
+
+    fn combine(&self, results: &mut dyn Iterator<Item = RuleResult>) -> RuleResult {
+        let mut last: Option<ProgramError> = None;
+
+        for result in results {
+            // Return failure on the first failing rule.
+            match result {
+                RuleResult::Success(err) => last = Some(err),
+                _ => return result,
+            }
+        }
+
+        // Return pass if and only if all rules passed.
+        RuleResult::Success(last.unwrap_or_else(|| RuleSetError::UnexpectedRuleSetFailure.into()))
+    }
+
     fn validate(
         &self,
         accounts: &std::collections::HashMap<
@@ -76,27 +94,19 @@ impl<'a> Constraint<'a> for All<'a> {
         update_rule_state: bool,
         rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
         rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        context: &crate::types::ValidationContext,
     ) -> RuleResult {
         msg!("Validating All");
 
-        let mut last: Option<ProgramError> = None;
-
-        for rule in &self.rules {
-            let result = rule.validate(
+        self.combine(&mut self.rules.iter().map(|rule| {
+            rule.validate(
                 accounts,
                 payload,
                 update_rule_state,
                 rule_set_state_pda,
                 rule_authority,
-            );
-            // Return failure on the first failing rule.
-            match result {
-                RuleResult::Success(err) => last = Some(err),
-                _ => return result,
-            }
-        }
-
-        // Return pass if and only if all rules passed.
-        RuleResult::Success(last.unwrap_or_else(|| RuleSetError::UnexpectedRuleSetFailure.into()))
+                context,
+            )
+        }))
     }
 }
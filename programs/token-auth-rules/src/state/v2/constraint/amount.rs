@@ -63,9 +63,7 @@ impl<'a> Amount<'a> {
         // - operator
         data.extend(u64::to_le_bytes(operator as u64));
         // - field
-        let mut field_bytes = [0u8; Str32::SIZE];
-        field_bytes[..field.len()].copy_from_slice(field.as_bytes());
-        data.extend(field_bytes);
+        data.extend(Str32::serialize(&field)?);
 
         Ok(data)
     }
@@ -86,22 +84,19 @@ impl<'a> Constraint<'a> for Amount<'a> {
         _update_rule_state: bool,
         _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
         _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
     ) -> RuleResult {
         msg!("Validating Amount");
         let condition_type = self.constraint_type();
 
         if let Some(payload_amount) = &payload.get_amount(&self.field.to_string()) {
-            let operator_fn = match Operator::try_from(*self.operator) {
-                Ok(Operator::Lt) => PartialOrd::lt,
-                Ok(Operator::LtEq) => PartialOrd::le,
-                Ok(Operator::Eq) => PartialEq::eq,
-                Ok(Operator::Gt) => PartialOrd::gt,
-                Ok(Operator::GtEq) => PartialOrd::ge,
+            let operator = match Operator::try_from(*self.operator) {
+                Ok(operator) => operator,
                 // sanity check: the value is checked at creation
                 Err(_) => return RuleResult::Failure(condition_type.to_error()),
             };
 
-            if operator_fn(payload_amount, self.amount) {
+            if operator.compare(*payload_amount, *self.amount) {
                 RuleResult::Success(condition_type.to_error())
             } else {
                 RuleResult::Failure(condition_type.to_error())
@@ -0,0 +1,99 @@
+use solana_program::msg;
+
+use crate::{
+    error::RuleSetError,
+    state::{try_from_bytes, RuleResult},
+    state::{
+        v2::{Constraint, ConstraintType, Str32, HEADER_SECTION, U64_BYTES},
+        Header,
+    },
+};
+
+/// Constraint representing a comparison against a range of amounts of tokens being transferred.
+///
+/// This constraint requires a `PayloadType` value of `PayloadType::Amount`. The `field`
+/// value in the Rule is used to locate the numerical amount in the payload, which must be
+/// inside the inclusive `[min, max]` range stored in the rule.  `min` cannot be greater than
+/// `max`.
+pub struct AmountRange<'a> {
+    /// The minimum amount (inclusive).
+    pub min: &'a u64,
+    /// The maximum amount (inclusive).
+    pub max: &'a u64,
+    /// The field the amount is stored in.
+    pub field: &'a Str32,
+}
+
+impl<'a> AmountRange<'a> {
+    /// Deserialize a constraint from a byte array.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        // min
+        let min = try_from_bytes::<u64>(0, U64_BYTES, bytes)?;
+        let mut cursor = U64_BYTES;
+
+        // max
+        let max = try_from_bytes::<u64>(cursor, U64_BYTES, bytes)?;
+        cursor += U64_BYTES;
+
+        // field
+        let field = try_from_bytes::<Str32>(cursor, Str32::SIZE, bytes)?;
+
+        Ok(Self { min, max, field })
+    }
+
+    /// Serialize a constraint into a byte array.
+    pub fn serialize(field: String, min: u64, max: u64) -> Result<Vec<u8>, RuleSetError> {
+        if min > max {
+            return Err(RuleSetError::InvalidAmountRange);
+        }
+
+        // length of the assert
+        let length = (U64_BYTES + U64_BYTES + Str32::SIZE) as u32;
+        let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
+
+        // Header
+        Header::serialize(ConstraintType::AmountRange, length, &mut data);
+
+        // Constraint
+        // - min
+        data.extend(u64::to_le_bytes(min));
+        // - max
+        data.extend(u64::to_le_bytes(max));
+        // - field
+        data.extend(Str32::serialize(&field)?);
+
+        Ok(data)
+    }
+}
+
+impl<'a> Constraint<'a> for AmountRange<'a> {
+    fn constraint_type(&self) -> ConstraintType {
+        ConstraintType::AmountRange
+    }
+
+    fn validate(
+        &self,
+        _accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        payload: &crate::payload::Payload,
+        _update_rule_state: bool,
+        _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating AmountRange");
+        let condition_type = self.constraint_type();
+
+        if let Some(payload_amount) = &payload.get_amount(&self.field.to_string()) {
+            if self.min <= payload_amount && payload_amount <= self.max {
+                RuleResult::Success(condition_type.to_error())
+            } else {
+                RuleResult::Failure(condition_type.to_error())
+            }
+        } else {
+            RuleResult::Error(RuleSetError::MissingPayloadValue.into())
+        }
+    }
+}
@@ -41,8 +41,7 @@ impl<'a> Any<'a> {
                 .iter()
                 .map(|v| v.len())
                 .reduce(|accum, item| accum + item)
-                .ok_or(RuleSetError::DataIsEmpty)
-                .unwrap()) as u32;
+                .ok_or(RuleSetError::EmptyRuleGroup)?) as u32;
 
         let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
 
@@ -64,31 +63,15 @@ impl<'a> Constraint<'a> for Any<'a> {
         ConstraintType::Any
     }
 
-    fn validate(
-        &self,
-        accounts: &std::collections::HashMap<
-            solana_program::pubkey::Pubkey,
-            &solana_program::account_info::AccountInfo,
-        >,
-        payload: &crate::payload::Payload,
-        update_rule_state: bool,
-        rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
-        rule_authority: &Option<&solana_program::account_info::AccountInfo>,
-    ) -> RuleResult {
-        msg!("Validating Any");
+    fn rules(&self) -> &[RuleV2<'a>] {
+        &self.rules
+    }
 
+    fn combine(&self, results: &mut dyn Iterator<Item = RuleResult>) -> RuleResult {
         let mut last_failure: Option<ProgramError> = None;
         let mut last_error: Option<ProgramError> = None;
 
-        for rule in &self.rules {
-            let result = rule.validate(
-                accounts,
-                payload,
-                update_rule_state,
-                rule_set_state_pda,
-                rule_authority,
-            );
-
+        for result in results {
             match result {
                 RuleResult::Success(_) => return result,
                 RuleResult::Failure(err) => last_failure = Some(err),
@@ -107,4 +90,30 @@ impl<'a> Constraint<'a> for Any<'a> {
             RuleResult::Error(RuleSetError::UnexpectedRuleSetFailure.into())
         }
     }
+
+    fn validate(
+        &self,
+        accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        payload: &crate::payload::Payload,
+        update_rule_state: bool,
+        rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating Any");
+
+        self.combine(&mut self.rules.iter().map(|rule| {
+            rule.validate(
+                accounts,
+                payload,
+                update_rule_state,
+                rule_set_state_pda,
+                rule_authority,
+                context,
+            )
+        }))
+    }
 }
@@ -0,0 +1,200 @@
+use solana_program::{
+    clock::Clock,
+    ed25519_program,
+    instruction::Instruction,
+    msg,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+    sysvar::{self, Sysvar},
+};
+
+use crate::{
+    error::RuleSetError,
+    state::{try_from_bytes, RuleResult},
+    state::{
+        v2::{Constraint, ConstraintType, Str32, HEADER_SECTION},
+        Header,
+    },
+    utils::load_instructions,
+};
+
+/// Offset of the first signature offsets of an ed25519 program instruction.
+const SIGNATURE_OFFSETS_START: usize = 2;
+
+/// Size of the signature offsets of an ed25519 program instruction.
+const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
+
+/// Instruction index referencing the data of the ed25519 program instruction itself.
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Size of an attestation message: wallet, mint and expiry.
+const MESSAGE_SIZE: usize = PUBKEY_BYTES + PUBKEY_BYTES + std::mem::size_of::<i64>();
+
+/// Constraint representing a test where an unexpired attestation signed by the authority must be
+/// verified by an ed25519 program instruction of the transaction.
+///
+/// This constraint requires a `PayloadType` value of `PayloadType::Pubkey`.  The `field` value in
+/// the rule is used to locate the wallet in the payload, while the mint is the one being
+/// validated.  The instructions sysvar must be provided to `Validate` via the
+/// `additional_rule_accounts` argument so that the instructions of the transaction can be loaded.
+/// The attestation message is the wallet, the mint and the expiry (a little-endian `i64` Unix
+/// timestamp), as built by `Ed25519Attestation::message`, and must be stored in the data of the
+/// ed25519 program instruction along with the public key of the authority.
+pub struct Ed25519Attestation<'a> {
+    /// The authority that must have signed the attestation.
+    pub authority: &'a Pubkey,
+    /// The field in the `Payload` holding the wallet.
+    pub field: &'a Str32,
+}
+
+impl<'a> Ed25519Attestation<'a> {
+    /// Deserialize a constraint from a byte array.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        let authority = try_from_bytes::<Pubkey>(0, PUBKEY_BYTES, bytes)?;
+        let field = try_from_bytes::<Str32>(PUBKEY_BYTES, Str32::SIZE, bytes)?;
+
+        Ok(Self { authority, field })
+    }
+
+    /// Serialize a constraint into a byte array.
+    pub fn serialize(field: String, authority: Pubkey) -> Result<Vec<u8>, RuleSetError> {
+        // length of the assert
+        let length = (PUBKEY_BYTES + Str32::SIZE) as u32;
+        let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
+
+        // Header
+        Header::serialize(ConstraintType::Ed25519Attestation, length, &mut data);
+
+        // Constraint
+        // - authority
+        data.extend(authority.as_ref());
+        // - field
+        data.extend(Str32::serialize(&field)?);
+
+        Ok(data)
+    }
+
+    /// Build the attestation message to be signed by the authority.
+    pub fn message(wallet: &Pubkey, mint: &Pubkey, expiry: i64) -> Vec<u8> {
+        let mut message = Vec::with_capacity(MESSAGE_SIZE);
+        message.extend(wallet.as_ref());
+        message.extend(mint.as_ref());
+        message.extend(expiry.to_le_bytes());
+        message
+    }
+
+    /// Expiry of every attestation signed by the authority for the wallet and mint in the data of
+    /// an ed25519 program instruction.
+    fn expiries(&self, instruction: &Instruction, wallet: &Pubkey, mint: &Pubkey) -> Vec<i64> {
+        let data = &instruction.data;
+        let read_u16 = |offset: usize| {
+            data.get(offset..offset + 2)
+                .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        };
+
+        let num_signatures = data.first().copied().unwrap_or_default() as usize;
+        let mut expiries = Vec::new();
+
+        for index in 0..num_signatures {
+            let start = SIGNATURE_OFFSETS_START + index * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+
+            // The signature itself was verified by the ed25519 program, but the public key and
+            // the message must be read from this instruction.
+            let (public_key, message) = match (
+                read_u16(start + 4),
+                read_u16(start + 6),
+                read_u16(start + 8),
+                read_u16(start + 10),
+                read_u16(start + 12),
+            ) {
+                (
+                    Some(public_key_offset),
+                    Some(CURRENT_INSTRUCTION),
+                    Some(message_offset),
+                    Some(message_size),
+                    Some(CURRENT_INSTRUCTION),
+                ) => (
+                    data.get(public_key_offset as usize..public_key_offset as usize + PUBKEY_BYTES),
+                    data.get(
+                        message_offset as usize..message_offset as usize + message_size as usize,
+                    ),
+                ),
+                _ => continue,
+            };
+
+            if let (Some(public_key), Some(message)) = (public_key, message) {
+                if public_key == self.authority.as_ref()
+                    && message.len() == MESSAGE_SIZE
+                    && message[..PUBKEY_BYTES] == *wallet.as_ref()
+                    && message[PUBKEY_BYTES..PUBKEY_BYTES * 2] == *mint.as_ref()
+                {
+                    let mut expiry = [0u8; 8];
+                    expiry.copy_from_slice(&message[PUBKEY_BYTES * 2..]);
+                    expiries.push(i64::from_le_bytes(expiry));
+                }
+            }
+        }
+
+        expiries
+    }
+}
+
+impl<'a> Constraint<'a> for Ed25519Attestation<'a> {
+    fn constraint_type(&self) -> ConstraintType {
+        ConstraintType::Ed25519Attestation
+    }
+
+    fn validate(
+        &self,
+        accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        payload: &crate::payload::Payload,
+        _update_rule_state: bool,
+        _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating Ed25519Attestation");
+
+        let wallet = match payload.get_pubkey(&self.field.to_string()) {
+            Some(pubkey) => pubkey,
+            _ => return RuleResult::Error(RuleSetError::MissingPayloadValue.into()),
+        };
+
+        let instructions_sysvar = match accounts.get(&sysvar::instructions::ID) {
+            Some(account) => account,
+            _ => return RuleResult::Error(RuleSetError::MissingAccount.into()),
+        };
+
+        let instructions = match load_instructions(instructions_sysvar) {
+            Ok(instructions) => instructions,
+            Err(error) => return RuleResult::Error(error),
+        };
+
+        let expiry = match instructions
+            .iter()
+            .filter(|instruction| instruction.program_id == ed25519_program::ID)
+            .flat_map(|instruction| self.expiries(instruction, wallet, &context.mint))
+            .max()
+        {
+            Some(expiry) => expiry,
+            None => {
+                msg!("Attestation not found");
+                return RuleResult::Failure(self.constraint_type().to_error());
+            }
+        };
+
+        let clock = match Clock::get() {
+            Ok(clock) => clock,
+            Err(err) => return RuleResult::Error(err),
+        };
+
+        if clock.unix_timestamp < expiry {
+            RuleResult::Success(self.constraint_type().to_error())
+        } else {
+            msg!("Attestation expired at {}", expiry);
+            RuleResult::Failure(self.constraint_type().to_error())
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use solana_program::msg;
+
+use crate::{
+    error::RuleSetError,
+    state::{try_from_bytes, validate_field_compare, RuleResult},
+    state::{
+        v2::{Constraint, ConstraintType, Operator, Str32, HEADER_SECTION, U64_BYTES},
+        Header,
+    },
+};
+
+/// Constraint representing a comparison between two values in the `Payload`.
+///
+/// The `field` and `other_field` values in the rule are used to locate values of the same
+/// `PayloadType` in the payload, which are compared using the comparison operator stored in the
+/// rule.  `Pubkey` values can only be compared with `Operator::Eq` and `Operator::Ne`, while
+/// `Number` values support every operator.
+pub struct FieldCompare<'a> {
+    /// The field in the `Payload` holding the value to compare.
+    pub field: &'a Str32,
+    /// The operator to be used in the comparison.
+    pub operator: &'a u64,
+    /// The field in the `Payload` holding the value compared against.
+    pub other_field: &'a Str32,
+}
+
+impl<'a> FieldCompare<'a> {
+    /// Deserialize a constraint from a byte array.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        // field
+        let field = try_from_bytes::<Str32>(0, Str32::SIZE, bytes)?;
+        let mut cursor = Str32::SIZE;
+
+        // operator
+        let operator = try_from_bytes::<u64>(cursor, U64_BYTES, bytes)?;
+        cursor += U64_BYTES;
+
+        // other_field
+        let other_field = try_from_bytes::<Str32>(cursor, Str32::SIZE, bytes)?;
+
+        Ok(Self {
+            field,
+            operator,
+            other_field,
+        })
+    }
+
+    /// Serialize a constraint into a byte array.
+    pub fn serialize(
+        field: String,
+        operator: Operator,
+        other_field: String,
+    ) -> Result<Vec<u8>, RuleSetError> {
+        // length of the assert
+        let length = (Str32::SIZE + U64_BYTES + Str32::SIZE) as u32;
+        let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
+
+        // Header
+        Header::serialize(ConstraintType::FieldCompare, length, &mut data);
+
+        // Constraint
+        // - field
+        data.extend(Str32::serialize(&field)?);
+        // - operator
+        data.extend(u64::to_le_bytes(operator as u64));
+        // - other_field
+        data.extend(Str32::serialize(&other_field)?);
+
+        Ok(data)
+    }
+}
+
+impl<'a> Constraint<'a> for FieldCompare<'a> {
+    fn constraint_type(&self) -> ConstraintType {
+        ConstraintType::FieldCompare
+    }
+
+    fn validate(
+        &self,
+        _accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        payload: &crate::payload::Payload,
+        _update_rule_state: bool,
+        _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating FieldCompare");
+
+        let operator = match Operator::try_from(*self.operator) {
+            Ok(operator) => operator,
+            // sanity check: the value is checked at creation
+            Err(_) => return RuleResult::Failure(self.constraint_type().to_error()),
+        };
+
+        validate_field_compare(
+            payload,
+            &self.field.to_string(),
+            &operator,
+            &self.other_field.to_string(),
+        )
+    }
+}
@@ -5,34 +5,49 @@ use solana_program::{
 
 use crate::{
     error::RuleSetError,
-    state::{try_from_bytes, RuleResult},
+    state::{try_from_bytes, validate_frequency, RuleResult},
     state::{
-        v2::{Constraint, ConstraintType, HEADER_SECTION},
+        v2::{Constraint, ConstraintType, HEADER_SECTION, U64_BYTES},
         Header,
     },
 };
 
+/// Period of a `Frequency` constraint serialized before the period was stored.
+const LEGACY_PERIOD: i64 = 0;
+
 /// Constraint representing a comparison based on time between operations.
 ///
-/// Currently not implemented. This constraint is planned check to ensure a certain
-/// amount of time has passed.  This rule will make use of the `rule_set_state_pda`
-/// optional account passed into `Validate`, and will require the optional
-/// `rule_authority` account to sign.
+/// This constraint checks that at least `period` seconds have passed since the last
+/// update stored in the `rule_set_state_pda` optional account passed into `Validate`,
+/// and requires the optional `rule_authority` account to sign.  When `update_rule_state`
+/// is `true`, the current time is stored in the `rule_set_state_pda`.
 pub struct Frequency<'a> {
     /// The authority of the frequency account.
     pub authority: &'a Pubkey,
+    /// The period (in seconds) which must transpire before the rule will succeed again.
+    pub period: &'a i64,
 }
 
 impl<'a> Frequency<'a> {
     /// Deserialize a constraint from a byte array.
     pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        // authority
         let authority = try_from_bytes::<Pubkey>(0, PUBKEY_BYTES, bytes)?;
-        Ok(Self { authority })
+        let cursor = PUBKEY_BYTES;
+
+        // period (not present in constraints serialized with only the authority)
+        let period = if bytes.len() > cursor {
+            try_from_bytes::<i64>(cursor, U64_BYTES, bytes)?
+        } else {
+            &LEGACY_PERIOD
+        };
+
+        Ok(Self { authority, period })
     }
 
     /// Serialize a constraint into a byte array.
-    pub fn serialize(authority: Pubkey) -> Result<Vec<u8>, RuleSetError> {
-        let length = PUBKEY_BYTES as u32;
+    pub fn serialize(authority: Pubkey, period: i64) -> Result<Vec<u8>, RuleSetError> {
+        let length = (PUBKEY_BYTES + U64_BYTES) as u32;
         let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
 
         // Header
@@ -41,6 +56,8 @@ impl<'a> Frequency<'a> {
         // Constraint
         // - pubkey
         data.extend(authority.as_ref());
+        // - period
+        data.extend(i64::to_le_bytes(period));
 
         Ok(data)
     }
@@ -58,9 +75,10 @@ impl<'a> Constraint<'a> for Frequency<'a> {
             &solana_program::account_info::AccountInfo,
         >,
         _payload: &crate::payload::Payload,
-        _update_rule_state: bool,
-        _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        update_rule_state: bool,
+        rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
         rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        context: &crate::types::ValidationContext,
     ) -> RuleResult {
         msg!("Validating Frequency");
 
@@ -74,6 +92,6 @@ impl<'a> Constraint<'a> for Frequency<'a> {
             return RuleResult::Error(RuleSetError::MissingAccount.into());
         }
 
-        RuleResult::Error(RuleSetError::NotImplemented.into())
+        validate_frequency(rule_set_state_pda, *self.period, update_rule_state, context)
     }
 }
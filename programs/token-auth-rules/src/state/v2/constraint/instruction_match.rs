@@ -0,0 +1,160 @@
+use solana_program::{
+    msg,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+    sysvar,
+};
+
+use crate::{
+    error::RuleSetError,
+    state::{try_from_bytes, RuleResult},
+    state::{
+        v2::{padded_len, Constraint, ConstraintType, Str32, HEADER_SECTION, U64_BYTES},
+        Header,
+    },
+    utils::load_instructions,
+};
+
+/// Constraint representing a test where an instruction of the transaction must match the program
+/// and data stored in the rule.
+///
+/// The instructions sysvar must be provided to `Validate` via the `additional_rule_accounts`
+/// argument so that the instructions of the transaction can be loaded.  An instruction matches
+/// when it targets the `program` stored in the rule and its data starts with the `discriminator`
+/// bytes.  When an `account_field` is stored in the rule, this constraint also requires a
+/// `PayloadType` value of `PayloadType::Pubkey`: the `field` value is used to locate the `Pubkey`
+/// in the payload, which must be the account at `account_index` of the instruction.  Wrap the
+/// constraint in a `Not` to require that no instruction matches.
+pub struct InstructionMatch<'a> {
+    /// The program the instruction must target.
+    pub program: &'a Pubkey,
+    /// Index of the instruction account to be compared.
+    pub account_index: &'a u64,
+    /// The field in the `Payload` holding the instruction account, empty if any account matches.
+    pub account_field: &'a Str32,
+    /// The leading bytes of the instruction data.
+    pub discriminator: &'a [u8],
+}
+
+impl<'a> InstructionMatch<'a> {
+    /// Deserialize a constraint from a byte array.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        // program
+        let program = try_from_bytes::<Pubkey>(0, PUBKEY_BYTES, bytes)?;
+        let mut cursor = PUBKEY_BYTES;
+
+        // account_index
+        let account_index = try_from_bytes::<u64>(cursor, U64_BYTES, bytes)?;
+        cursor += U64_BYTES;
+
+        // account_field
+        let account_field = try_from_bytes::<Str32>(cursor, Str32::SIZE, bytes)?;
+        cursor += Str32::SIZE;
+
+        // len
+        let len = *try_from_bytes::<u64>(cursor, U64_BYTES, bytes)? as usize;
+        cursor += U64_BYTES;
+
+        // discriminator
+        let discriminator = bytes
+            .get(cursor..cursor.saturating_add(len))
+            .ok_or(RuleSetError::RuleSetReadFailed)?;
+
+        Ok(Self {
+            program,
+            account_index,
+            account_field,
+            discriminator,
+        })
+    }
+
+    /// Serialize a constraint into a byte array.
+    pub fn serialize(
+        program: Pubkey,
+        discriminator: &[u8],
+        account_field: Option<String>,
+        account_index: u64,
+    ) -> Result<Vec<u8>, RuleSetError> {
+        // length of the assert
+        let length =
+            (PUBKEY_BYTES + U64_BYTES + Str32::SIZE + U64_BYTES + padded_len(discriminator.len()))
+                as u32;
+        let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
+
+        // Header
+        Header::serialize(ConstraintType::InstructionMatch, length, &mut data);
+
+        // Constraint
+        // - program
+        data.extend(program.as_ref());
+        // - account_index
+        data.extend(u64::to_le_bytes(account_index));
+        // - account_field
+        data.extend(Str32::serialize(&account_field.unwrap_or_default())?);
+        // - len
+        data.extend(u64::to_le_bytes(discriminator.len() as u64));
+        // - discriminator (padded to keep the following rules aligned)
+        data.extend(discriminator);
+        data.resize(HEADER_SECTION + length as usize, 0);
+
+        Ok(data)
+    }
+}
+
+impl<'a> Constraint<'a> for InstructionMatch<'a> {
+    fn constraint_type(&self) -> ConstraintType {
+        ConstraintType::InstructionMatch
+    }
+
+    fn validate(
+        &self,
+        accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        payload: &crate::payload::Payload,
+        _update_rule_state: bool,
+        _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating InstructionMatch");
+
+        let account = match self.account_field.to_string() {
+            field if field.is_empty() => None,
+            field => match payload.get_pubkey(&field) {
+                Some(pubkey) => Some(pubkey),
+                _ => return RuleResult::Error(RuleSetError::MissingPayloadValue.into()),
+            },
+        };
+
+        let instructions_sysvar = match accounts.get(&sysvar::instructions::ID) {
+            Some(account) => account,
+            _ => return RuleResult::Error(RuleSetError::MissingAccount.into()),
+        };
+
+        let instructions = match load_instructions(instructions_sysvar) {
+            Ok(instructions) => instructions,
+            Err(error) => return RuleResult::Error(error),
+        };
+
+        let matched = instructions.iter().any(|instruction| {
+            let account_matches = match account {
+                Some(account) => instruction
+                    .accounts
+                    .get(*self.account_index as usize)
+                    .is_some_and(|meta| meta.pubkey == *account),
+                None => true,
+            };
+
+            instruction.program_id == *self.program
+                && instruction.data.starts_with(self.discriminator)
+                && account_matches
+        });
+
+        if matched {
+            RuleResult::Success(self.constraint_type().to_error())
+        } else {
+            RuleResult::Failure(self.constraint_type().to_error())
+        }
+    }
+}
@@ -7,6 +7,7 @@ use crate::{
         v2::{Constraint, ConstraintType, Str32, HEADER_SECTION},
         Header,
     },
+    utils::is_on_curve,
 };
 
 /// Constraint that represents a test on whether a pubkey can be signed from a client and therefore
@@ -39,9 +40,7 @@ impl<'a> IsWallet<'a> {
 
         // Constraint
         // - field
-        let mut field_bytes = [0u8; Str32::SIZE];
-        field_bytes[..field.len()].copy_from_slice(field.as_bytes());
-        data.extend(field_bytes);
+        data.extend(Str32::serialize(&field)?);
 
         Ok(data)
     }
@@ -62,6 +61,7 @@ impl<'a> Constraint<'a> for IsWallet<'a> {
         _update_rule_state: bool,
         _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
         _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
     ) -> RuleResult {
         msg!("Validating IsWallet");
 
@@ -75,17 +75,17 @@ impl<'a> Constraint<'a> for IsWallet<'a> {
         // its owner is the System Program.
         if let Some(account) = accounts.get(key) {
             if *account.owner != system_program::ID {
-                // TODO: Change error return to commented line after on-curve syscall
-                // available.
-                return RuleResult::Error(RuleSetError::NotImplemented.into());
-                //return (false, self.to_error());
+                return RuleResult::Failure(self.constraint_type().to_error());
             }
         } else {
             return RuleResult::Error(RuleSetError::MissingAccount.into());
         }
 
-        // TODO: Uncomment call to `is_on_curve()` after on-curve sycall available.
-        RuleResult::Error(RuleSetError::NotImplemented.into())
-        //(is_on_curve(key), self.to_error())
+        // A wallet address must be on the ed25519 curve, which rules out PDAs.
+        if is_on_curve(key) {
+            RuleResult::Success(self.constraint_type().to_error())
+        } else {
+            RuleResult::Failure(self.constraint_type().to_error())
+        }
     }
 }
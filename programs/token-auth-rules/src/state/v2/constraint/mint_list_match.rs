@@ -0,0 +1,77 @@
+use solana_program::{
+    msg,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+
+use crate::{
+    error::RuleSetError,
+    state::RuleResult,
+    state::{
+        try_cast_slice,
+        v2::{Constraint, ConstraintType, HEADER_SECTION},
+        Header,
+    },
+};
+
+/// Constraint representing a test where the mint being validated must be in the list of
+/// `Pubkey`s.
+///
+/// This constraint does not require any `Payload` values.  The mint passed to the `Validate`
+/// instruction is compared to the `Pubkey` list in the rule.
+pub struct MintListMatch<'a> {
+    /// The list of mints to be compared against.
+    pub mints: &'a [Pubkey],
+}
+
+impl<'a> MintListMatch<'a> {
+    /// Deserialize a constraint from a byte array.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        let mints = try_cast_slice(bytes)?;
+        Ok(Self { mints })
+    }
+
+    /// Serialize a constraint into a byte array.
+    pub fn serialize(mints: &[Pubkey]) -> Result<Vec<u8>, RuleSetError> {
+        // length of the assert
+        let length = (mints.len() * PUBKEY_BYTES) as u32;
+        let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
+
+        // Header
+        Header::serialize(ConstraintType::MintListMatch, length, &mut data);
+
+        // Constraint
+        // - mints
+        mints.iter().for_each(|p| {
+            data.extend(p.as_ref());
+        });
+
+        Ok(data)
+    }
+}
+
+impl<'a> Constraint<'a> for MintListMatch<'a> {
+    fn constraint_type(&self) -> ConstraintType {
+        ConstraintType::MintListMatch
+    }
+
+    fn validate(
+        &self,
+        _accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        _payload: &crate::payload::Payload,
+        _update_rule_state: bool,
+        _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating MintListMatch");
+
+        if self.mints.contains(&context.mint) {
+            RuleResult::Success(self.constraint_type().to_error())
+        } else {
+            RuleResult::Failure(self.constraint_type().to_error())
+        }
+    }
+}
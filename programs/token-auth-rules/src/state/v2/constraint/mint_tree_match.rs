@@ -0,0 +1,97 @@
+use solana_program::{msg, pubkey::PUBKEY_BYTES};
+
+use crate::{
+    error::RuleSetError,
+    state::RuleResult,
+    state::{
+        try_from_bytes,
+        v2::{Constraint, ConstraintType, Str32, HEADER_SECTION},
+        Header,
+    },
+    utils::compute_merkle_root,
+};
+
+/// Constraint representing a test where the mint being validated must be a member of the Merkle
+/// tree in the rule.
+///
+/// This constraint requires a `PayloadType` value of `PayloadType::MerkleProof`.  The
+/// `proof_field` value in the rule is used to locate it in the `Payload`.  The mint passed to the
+/// `Validate` instruction and the proof are used to calculate a Merkle root which is compared
+/// against the root stored in the rule.
+pub struct MintTreeMatch<'a> {
+    /// The field in the `Payload` to be compared when looking for the Merkle proof.
+    pub proof_field: &'a Str32,
+    /// The root of the Merkle tree.
+    pub root: &'a [u8; PUBKEY_BYTES],
+}
+
+impl<'a> MintTreeMatch<'a> {
+    /// Deserialize a constraint from a byte array.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        // proof_field
+        let proof_field = try_from_bytes::<Str32>(0, Str32::SIZE, bytes)?;
+        let cursor = Str32::SIZE;
+
+        // root
+        let root = try_from_bytes::<[u8; 32]>(cursor, PUBKEY_BYTES, bytes)?;
+
+        Ok(Self { proof_field, root })
+    }
+
+    /// Serialize a constraint into a byte array.
+    pub fn serialize(
+        proof_field: String,
+        root: &[u8; PUBKEY_BYTES],
+    ) -> Result<Vec<u8>, RuleSetError> {
+        // length of the assert
+        let length = (Str32::SIZE + PUBKEY_BYTES) as u32;
+        let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
+
+        // Header
+        Header::serialize(ConstraintType::MintTreeMatch, length, &mut data);
+
+        // Constraint
+        // - proof_field
+        data.extend(Str32::serialize(&proof_field)?);
+        // - root
+        data.extend_from_slice(root);
+
+        Ok(data)
+    }
+}
+
+impl<'a> Constraint<'a> for MintTreeMatch<'a> {
+    fn constraint_type(&self) -> ConstraintType {
+        ConstraintType::MintTreeMatch
+    }
+
+    fn validate(
+        &self,
+        _accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        payload: &crate::payload::Payload,
+        _update_rule_state: bool,
+        _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating MintTreeMatch");
+
+        // Get the Merkle proof from the payload.
+        let merkle_proof = match payload.get_merkle_proof(&self.proof_field.to_string()) {
+            Some(merkle_proof) => merkle_proof,
+            _ => return RuleResult::Error(RuleSetError::MissingPayloadValue.into()),
+        };
+
+        // Check if the computed hash (root) is equal to the root in the rule.
+        let computed_root = compute_merkle_root(&context.mint, merkle_proof);
+
+        if computed_root == *self.root {
+            RuleResult::Success(self.constraint_type().to_error())
+        } else {
+            RuleResult::Failure(self.constraint_type().to_error())
+        }
+    }
+}
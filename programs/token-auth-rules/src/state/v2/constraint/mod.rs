@@ -3,36 +3,66 @@
 //! A constraint is a test that must be met in order for a rule to be valid. These are
 //! intended to be used in conjunction with the [`RuleV2`](super::RuleV2) type.
 
+mod account_data;
 mod additional_signer;
+mod additional_signer_list;
 mod all;
 mod amount;
+mod amount_range;
 mod any;
+mod ed25519_attestation;
+mod field_compare;
 mod frequency;
+mod instruction_match;
 mod is_wallet;
+mod mint_list_match;
+mod mint_tree_match;
 mod namespace;
 mod not;
 mod pass;
 mod pda_match;
 mod program_owned;
 mod program_owned_list;
+mod program_owned_set;
 mod program_owned_tree;
 mod pubkey_list_match;
 mod pubkey_match;
 mod pubkey_tree_match;
+mod royalty_payment;
+mod rule_set_reference;
+mod threshold;
+mod time_window;
+mod token_account;
+mod token_gate;
 
+pub use account_data::*;
 pub use additional_signer::*;
+pub use additional_signer_list::*;
 pub use all::*;
 pub use amount::*;
+pub use amount_range::*;
 pub use any::*;
+pub use ed25519_attestation::*;
+pub use field_compare::*;
 pub use frequency::*;
+pub use instruction_match::*;
 pub use is_wallet::*;
+pub use mint_list_match::*;
+pub use mint_tree_match::*;
 pub use namespace::*;
 pub use not::*;
 pub use pass::*;
 pub use pda_match::*;
 pub use program_owned::*;
 pub use program_owned_list::*;
+pub use program_owned_set::*;
 pub use program_owned_tree::*;
 pub use pubkey_list_match::*;
 pub use pubkey_match::*;
 pub use pubkey_tree_match::*;
+pub use royalty_payment::*;
+pub use rule_set_reference::*;
+pub use threshold::*;
+pub use time_window::*;
+pub use token_account::*;
+pub use token_gate::*;
@@ -39,6 +39,20 @@ impl<'a> Constraint<'a> for Not<'a> {
         ConstraintType::Not
     }
 
+    fn rules(&self) -> &[RuleV2<'a>] {
+        std::slice::from_ref(&self.rule)
+    }
+
+    fn combine(&self, results: &mut dyn Iterator<Item = RuleResult>) -> RuleResult {
+        // Negate the result.
+        match results.next() {
+            Some(RuleResult::Success(err)) => RuleResult::Failure(err),
+            Some(RuleResult::Failure(err)) => RuleResult::Success(err),
+            Some(RuleResult::Error(err)) => RuleResult::Error(err),
+            None => RuleResult::Error(RuleSetError::UnexpectedRuleSetFailure.into()),
+        }
+    }
+
     fn validate(
         &self,
         accounts: &std::collections::HashMap<
@@ -49,22 +63,17 @@ impl<'a> Constraint<'a> for Not<'a> {
         update_rule_state: bool,
         rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
         rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        context: &crate::types::ValidationContext,
     ) -> RuleResult {
         msg!("Validating Not");
 
-        let result = self.rule.validate(
+        self.combine(&mut std::iter::once(self.rule.validate(
             accounts,
             payload,
             update_rule_state,
             rule_set_state_pda,
             rule_authority,
-        );
-
-        // Negate the result.
-        match result {
-            RuleResult::Success(err) => RuleResult::Failure(err),
-            RuleResult::Failure(err) => RuleResult::Success(err),
-            RuleResult::Error(err) => RuleResult::Error(err),
-        }
+            context,
+        )))
     }
 }
@@ -67,13 +67,9 @@ impl<'a> PDAMatch<'a> {
         let program = program.unwrap_or(DEFAULT_PUBKEY);
         data.extend(program.as_ref());
         // - pda_field
-        let mut field_bytes = [0u8; Str32::SIZE];
-        field_bytes[..pda_field.len()].copy_from_slice(pda_field.as_bytes());
-        data.extend(field_bytes);
+        data.extend(Str32::serialize(&pda_field)?);
         // - seeds_field
-        let mut field_bytes = [0u8; Str32::SIZE];
-        field_bytes[..seeds_field.len()].copy_from_slice(seeds_field.as_bytes());
-        data.extend(field_bytes);
+        data.extend(Str32::serialize(&seeds_field)?);
 
         Ok(data)
     }
@@ -94,6 +90,7 @@ impl<'a> Constraint<'a> for PDAMatch<'a> {
         _update_rule_state: bool,
         _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
         _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
     ) -> RuleResult {
         msg!("Validating PDAMatch");
 
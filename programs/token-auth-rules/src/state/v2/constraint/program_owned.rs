@@ -50,9 +50,7 @@ impl<'a> ProgramOwned<'a> {
         // - program
         data.extend(program.as_ref());
         // - field
-        let mut field_bytes = [0u8; Str32::SIZE];
-        field_bytes[..field.len()].copy_from_slice(field.as_bytes());
-        data.extend(field_bytes);
+        data.extend(Str32::serialize(&field)?);
 
         Ok(data)
     }
@@ -73,6 +71,7 @@ impl<'a> Constraint<'a> for ProgramOwned<'a> {
         _update_rule_state: bool,
         _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
         _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
     ) -> RuleResult {
         msg!("Validating ProgramOwned");
 
@@ -48,9 +48,7 @@ impl<'a> ProgramOwnedList<'a> {
 
         // Constraint
         // - field
-        let mut field_bytes = [0u8; Str32::SIZE];
-        field_bytes[..field.len()].copy_from_slice(field.as_bytes());
-        data.extend(field_bytes);
+        data.extend(Str32::serialize(&field)?);
         // - programs
         programs.iter().for_each(|p| {
             data.extend(p.as_ref());
@@ -75,6 +73,7 @@ impl<'a> Constraint<'a> for ProgramOwnedList<'a> {
         _update_rule_state: bool,
         _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
         _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
     ) -> RuleResult {
         msg!("Validating ProgramOwnedList");
 
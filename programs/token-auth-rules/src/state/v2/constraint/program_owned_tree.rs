@@ -61,13 +61,9 @@ impl<'a> ProgramOwnedTree<'a> {
 
         // Constraint
         // - pubkey_field
-        let mut field_bytes = [0u8; Str32::SIZE];
-        field_bytes[..pubkey_field.len()].copy_from_slice(pubkey_field.as_bytes());
-        data.extend(field_bytes);
+        data.extend(Str32::serialize(&pubkey_field)?);
         // - proof_field
-        let mut field_bytes = [0u8; Str32::SIZE];
-        field_bytes[..proof_field.len()].copy_from_slice(proof_field.as_bytes());
-        data.extend(field_bytes);
+        data.extend(Str32::serialize(&proof_field)?);
         // - root
         data.extend_from_slice(root);
 
@@ -90,6 +86,7 @@ impl<'a> Constraint<'a> for ProgramOwnedTree<'a> {
         _update_rule_state: bool,
         _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
         _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
     ) -> RuleResult {
         msg!("Validating ProgramOwnedTree");
 
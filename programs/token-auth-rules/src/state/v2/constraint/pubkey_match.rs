@@ -45,9 +45,7 @@ impl<'a> PubkeyMatch<'a> {
         // - pubkey
         data.extend(pubkey.as_ref());
         // - field
-        let mut field_bytes = [0u8; Str32::SIZE];
-        field_bytes[..field.len()].copy_from_slice(field.as_bytes());
-        data.extend(field_bytes);
+        data.extend(Str32::serialize(&field)?);
 
         Ok(data)
     }
@@ -68,6 +66,7 @@ impl<'a> Constraint<'a> for PubkeyMatch<'a> {
         _update_rule_state: bool,
         _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
         _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
     ) -> RuleResult {
         msg!("Validating PubkeyMatch");
 
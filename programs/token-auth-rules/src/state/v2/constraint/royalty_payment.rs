@@ -0,0 +1,255 @@
+use solana_program::{
+    account_info::AccountInfo,
+    instruction::Instruction,
+    msg,
+    program_pack::Pack,
+    program_utils::limited_deserialize,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+    system_instruction::SystemInstruction,
+    system_program, sysvar,
+};
+use spl_token::{instruction::TokenInstruction, state::Account};
+use std::collections::HashMap;
+
+use crate::{
+    error::RuleSetError,
+    state::{
+        try_cast_slice,
+        v2::{
+            unpack_token_account, Constraint, ConstraintType, Str32, HEADER_SECTION,
+            TOKEN_2022_PROGRAM_ID, U64_BYTES,
+        },
+        Header,
+    },
+    state::{try_from_bytes, RuleResult},
+    utils::load_instructions,
+};
+
+/// Number of basis points in a whole.
+const MAX_BASIS_POINTS: u128 = 10_000;
+
+/// Size of the type and length prefix of a Token-2022 extension.
+const EXTENSION_HEADER_BYTES: usize = 4;
+
+/// Token-2022 `ExtensionType` value of the `TransferFeeAmount` account extension.
+const TRANSFER_FEE_AMOUNT_EXTENSION: u16 = 2;
+
+/// Constraint representing a test where the royalties paid to the creators must be at least the
+/// basis points of the sale price.
+///
+/// This constraint requires a `PayloadType` value of `PayloadType::Number`.  The `field` value in
+/// the rule is used to locate the sale price in the payload.  The instructions sysvar must be
+/// provided to `Validate` via the `additional_rule_accounts` argument so that the transfers of
+/// the transaction can be loaded.  When no `mint` is stored in the rule, System Program transfers
+/// of lamports to the creators are added up.  Otherwise, SPL Token (and Token-2022) transfers of
+/// tokens of the `mint` to token accounts owned by the creators are added up, and the destination
+/// token accounts must also be provided via the `additional_rule_accounts` argument.  Note only
+/// the top-level instructions of the transaction are inspected, and transfers to Token-2022
+/// accounts with the transfer fee extension are not counted since the fee withheld from the
+/// transferred amount is not known.
+pub struct RoyaltyPayment<'a> {
+    /// The mint of the tokens paid, zeroed (default) if the royalties are paid in lamports.
+    pub mint: &'a Pubkey,
+    /// The royalties to be paid, in basis points of the sale price.
+    pub basis_points: &'a u64,
+    /// The field in the `Payload` holding the sale price.
+    pub field: &'a Str32,
+    /// The creators the royalties must be paid to.
+    pub creators: &'a [Pubkey],
+}
+
+impl<'a> RoyaltyPayment<'a> {
+    /// Deserialize a constraint from a byte array.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        // mint
+        let mint = try_from_bytes::<Pubkey>(0, PUBKEY_BYTES, bytes)?;
+        let mut cursor = PUBKEY_BYTES;
+
+        // basis_points
+        let basis_points = try_from_bytes::<u64>(cursor, U64_BYTES, bytes)?;
+        cursor += U64_BYTES;
+
+        // field
+        let field = try_from_bytes::<Str32>(cursor, Str32::SIZE, bytes)?;
+        cursor += Str32::SIZE;
+
+        // creators
+        let creators = try_cast_slice(&bytes[cursor..])?;
+
+        Ok(Self {
+            mint,
+            basis_points,
+            field,
+            creators,
+        })
+    }
+
+    /// Serialize a constraint into a byte array.
+    pub fn serialize(
+        field: String,
+        mint: Option<Pubkey>,
+        basis_points: u64,
+        creators: &[Pubkey],
+    ) -> Result<Vec<u8>, RuleSetError> {
+        // length of the assert
+        let length =
+            (PUBKEY_BYTES + U64_BYTES + Str32::SIZE + (creators.len() * PUBKEY_BYTES)) as u32;
+        let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
+
+        // Header
+        Header::serialize(ConstraintType::RoyaltyPayment, length, &mut data);
+
+        // Constraint
+        // - mint
+        data.extend(mint.unwrap_or_default().as_ref());
+        // - basis_points
+        data.extend(u64::to_le_bytes(basis_points));
+        // - field
+        data.extend(Str32::serialize(&field)?);
+        // - creators
+        creators.iter().for_each(|p| {
+            data.extend(p.as_ref());
+        });
+
+        Ok(data)
+    }
+
+    /// Amount paid to the creators by a System Program transfer.
+    fn lamports_paid(&self, instruction: &Instruction) -> u64 {
+        let (lamports, recipient) = match limited_deserialize::<SystemInstruction>(
+            &instruction.data,
+            instruction.data.len() as u64,
+        ) {
+            Ok(SystemInstruction::Transfer { lamports }) => (lamports, instruction.accounts.get(1)),
+            Ok(SystemInstruction::TransferWithSeed { lamports, .. }) => {
+                (lamports, instruction.accounts.get(2))
+            }
+            _ => return 0,
+        };
+
+        match recipient {
+            Some(recipient) if self.creators.contains(&recipient.pubkey) => lamports,
+            _ => 0,
+        }
+    }
+
+    /// Amount paid to the creators by an SPL Token transfer.
+    fn tokens_paid(
+        &self,
+        instruction: &Instruction,
+        accounts: &HashMap<Pubkey, &AccountInfo>,
+    ) -> u64 {
+        let (amount, destination) = match TokenInstruction::unpack(&instruction.data) {
+            Ok(TokenInstruction::Transfer { amount }) => (amount, instruction.accounts.get(1)),
+            Ok(TokenInstruction::TransferChecked { amount, .. }) => {
+                (amount, instruction.accounts.get(2))
+            }
+            _ => return 0,
+        };
+
+        let destination = match destination.and_then(|meta| accounts.get(&meta.pubkey)) {
+            Some(destination) => destination,
+            None => {
+                msg!("Destination token account not provided");
+                return 0;
+            }
+        };
+
+        match unpack_token_account(destination) {
+            Ok(Some(account))
+                if account.mint == *self.mint && self.creators.contains(&account.owner) =>
+            {
+                if has_transfer_fee(destination) {
+                    msg!("Transfer fees are not supported");
+                    return 0;
+                }
+                amount
+            }
+            _ => 0,
+        }
+    }
+}
+
+/// Whether the token account carries the Token-2022 `TransferFeeAmount` extension, in which case
+/// transfers to it are charged a fee.
+fn has_transfer_fee(account_info: &AccountInfo) -> bool {
+    let data = match account_info.data.try_borrow() {
+        Ok(data) => data,
+        Err(_) => return false,
+    };
+
+    // Extensions are stored as type-length-value entries after the account type.
+    let mut cursor = Account::LEN + 1;
+    while let Some(header) = data.get(cursor..cursor + EXTENSION_HEADER_BYTES) {
+        if u16::from_le_bytes([header[0], header[1]]) == TRANSFER_FEE_AMOUNT_EXTENSION {
+            return true;
+        }
+        cursor += EXTENSION_HEADER_BYTES + u16::from_le_bytes([header[2], header[3]]) as usize;
+    }
+
+    false
+}
+
+impl<'a> Constraint<'a> for RoyaltyPayment<'a> {
+    fn constraint_type(&self) -> ConstraintType {
+        ConstraintType::RoyaltyPayment
+    }
+
+    fn validate(
+        &self,
+        accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        payload: &crate::payload::Payload,
+        _update_rule_state: bool,
+        _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating RoyaltyPayment");
+
+        let price = match payload.get_amount(&self.field.to_string()) {
+            Some(amount) => amount,
+            _ => return RuleResult::Error(RuleSetError::MissingPayloadValue.into()),
+        };
+
+        let instructions_sysvar = match accounts.get(&sysvar::instructions::ID) {
+            Some(account) => account,
+            _ => return RuleResult::Error(RuleSetError::MissingAccount.into()),
+        };
+
+        let instructions = match load_instructions(instructions_sysvar) {
+            Ok(instructions) => instructions,
+            Err(error) => return RuleResult::Error(error),
+        };
+
+        let paid = instructions
+            .iter()
+            .map(|instruction| {
+                if *self.mint == Pubkey::default() {
+                    if instruction.program_id == system_program::ID {
+                        self.lamports_paid(instruction)
+                    } else {
+                        0
+                    }
+                } else if instruction.program_id == spl_token::ID
+                    || instruction.program_id == TOKEN_2022_PROGRAM_ID
+                {
+                    self.tokens_paid(instruction, accounts)
+                } else {
+                    0
+                }
+            })
+            .fold(0u64, |total, amount| total.saturating_add(amount));
+
+        msg!("Royalties paid: {}, sale price: {}", paid, price);
+
+        // paid / price >= basis_points / 10_000, without rounding
+        if paid as u128 * MAX_BASIS_POINTS >= price as u128 * *self.basis_points as u128 {
+            RuleResult::Success(self.constraint_type().to_error())
+        } else {
+            RuleResult::Failure(self.constraint_type().to_error())
+        }
+    }
+}
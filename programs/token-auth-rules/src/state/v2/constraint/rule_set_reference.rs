@@ -0,0 +1,206 @@
+use solana_program::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+use std::{cell::RefCell, collections::HashMap};
+
+use crate::{
+    error::RuleSetError,
+    payload::Payload,
+    state::{try_from_bytes, RuleResult, RuleSetV1, RuleSetV2},
+    state::{
+        v2::{Constraint, ConstraintType, Str32, HEADER_SECTION, U64_BYTES},
+        Header,
+    },
+    types::{LibVersion, ValidationContext},
+    utils::{get_revision_map_from_data, get_rule_set_revision_data},
+};
+
+/// Maximum number of `RuleSet`s in a chain of references, including the `RuleSet` being
+/// validated.
+pub const MAX_RULE_SET_REFERENCE_DEPTH: usize = 4;
+
+/// Revision value indicating that the latest revision of the referenced `RuleSet` is used.
+const LATEST_REVISION: u64 = u64::MAX;
+
+/// Constraint representing a test where the rule of an operation of another `RuleSet` must pass.
+///
+/// The referenced `RuleSet` PDA must be provided to `Validate` via the `additional_rule_accounts`
+/// argument.  Its rule is evaluated with the same accounts, payload and mint, for the `operation`
+/// stored in the rule (or the operation being validated if none is stored) and for the
+/// `revision` stored in the rule (or the latest revision if none is stored).  Rule state is not
+/// available to the referenced `RuleSet`, so rules that need the `RuleSet` state PDA (such as
+/// `Frequency`) cannot pass.  A `RuleSet` that is already being validated cannot be referenced
+/// again, and at most `MAX_RULE_SET_REFERENCE_DEPTH` `RuleSet`s can be chained.
+pub struct RuleSetReference<'a> {
+    /// The address of the referenced `RuleSet` PDA.
+    pub rule_set: &'a Pubkey,
+    /// The revision of the referenced `RuleSet`, `u64::MAX` if the latest revision is used.
+    pub revision: &'a u64,
+    /// The operation of the referenced `RuleSet`, empty if the operation being validated is used.
+    pub operation: &'a Str32,
+}
+
+impl<'a> RuleSetReference<'a> {
+    /// Deserialize a constraint from a byte array.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        // rule_set
+        let rule_set = try_from_bytes::<Pubkey>(0, PUBKEY_BYTES, bytes)?;
+        let mut cursor = PUBKEY_BYTES;
+
+        // revision
+        let revision = try_from_bytes::<u64>(cursor, U64_BYTES, bytes)?;
+        cursor += U64_BYTES;
+
+        // operation
+        let operation = try_from_bytes::<Str32>(cursor, Str32::SIZE, bytes)?;
+
+        Ok(Self {
+            rule_set,
+            revision,
+            operation,
+        })
+    }
+
+    /// Serialize a constraint into a byte array.
+    pub fn serialize(
+        rule_set: Pubkey,
+        operation: Option<String>,
+        revision: Option<usize>,
+    ) -> Result<Vec<u8>, RuleSetError> {
+        // length of the assert
+        let length = (PUBKEY_BYTES + U64_BYTES + Str32::SIZE) as u32;
+        let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
+
+        // Header
+        Header::serialize(ConstraintType::RuleSetReference, length, &mut data);
+
+        // Constraint
+        // - rule_set
+        data.extend(rule_set.as_ref());
+        // - revision
+        let revision = match revision {
+            Some(revision) => revision as u64,
+            None => LATEST_REVISION,
+        };
+        data.extend(u64::to_le_bytes(revision));
+        // - operation
+        data.extend(Str32::serialize(&operation.unwrap_or_default())?);
+
+        Ok(data)
+    }
+
+    /// Evaluate the rule of the referenced `RuleSet`.
+    fn validate_reference(
+        &self,
+        accounts: &HashMap<Pubkey, &AccountInfo>,
+        payload: &Payload,
+        context: &ValidationContext,
+    ) -> Result<RuleResult, ProgramError> {
+        if context.rule_sets.contains(self.rule_set) {
+            msg!("RuleSet {} is already being validated", self.rule_set);
+            return Err(RuleSetError::RuleSetReferenceCycle.into());
+        }
+
+        if context.rule_sets.len() >= MAX_RULE_SET_REFERENCE_DEPTH {
+            return Err(RuleSetError::RuleSetReferenceTooDeep.into());
+        }
+
+        let rule_set_pda_info = accounts
+            .get(self.rule_set)
+            .ok_or(RuleSetError::MissingAccount)?;
+
+        // `RuleSet` must be owned by this program.
+        if *rule_set_pda_info.owner != crate::ID {
+            return Err(RuleSetError::IncorrectOwner.into());
+        }
+
+        // `RuleSet` must not be empty.
+        if rule_set_pda_info.data_is_empty() {
+            return Err(RuleSetError::DataIsEmpty.into());
+        }
+
+        let data = rule_set_pda_info
+            .data
+            .try_borrow()
+            .map_err(|_| ProgramError::AccountBorrowFailed)?;
+
+        let revision = match *self.revision {
+            LATEST_REVISION => None,
+            revision => Some(revision as usize),
+        };
+
+        let (revision_map, _) = get_revision_map_from_data(&data)?;
+
+        let mut rule_sets = context.rule_sets.clone();
+        rule_sets.push(*self.rule_set);
+
+        let context = ValidationContext {
+            mint: context.mint,
+            operation: match self.operation.to_string() {
+                operation if operation.is_empty() => context.operation.clone(),
+                operation => operation,
+            },
+            rule_set_revision: match revision {
+                Some(revision) => Some(revision),
+                None => revision_map.rule_set_revisions.len().checked_sub(1),
+            },
+            rule_sets,
+            frequency_updates: RefCell::default(),
+        };
+
+        msg!(
+            "Validating operation {} of RuleSet {}",
+            context.operation,
+            self.rule_set
+        );
+
+        match get_rule_set_revision_data(&data, revision)? {
+            (LibVersion::V1, serialized_rule_set) => {
+                let rule_set = rmp_serde::from_slice::<RuleSetV1>(serialized_rule_set)
+                    .map_err(|_| RuleSetError::MessagePackDeserializationError)?;
+                let rule = rule_set.resolve_rule(context.operation.clone())?;
+
+                Ok(rule.low_level_validate(accounts, payload, false, &None, &None, &context))
+            }
+            (LibVersion::V2, serialized_rule_set) => {
+                let rule_set = RuleSetV2::from_bytes(serialized_rule_set)?;
+                let rule = rule_set.resolve_rule(context.operation.clone())?;
+
+                Ok(Constraint::validate(
+                    rule, accounts, payload, false, &None, &None, &context,
+                ))
+            }
+        }
+    }
+}
+
+impl<'a> Constraint<'a> for RuleSetReference<'a> {
+    fn constraint_type(&self) -> ConstraintType {
+        ConstraintType::RuleSetReference
+    }
+
+    fn validate(
+        &self,
+        accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        payload: &crate::payload::Payload,
+        _update_rule_state: bool,
+        _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating RuleSetReference");
+
+        match self.validate_reference(accounts, payload, context) {
+            Ok(RuleResult::Success(_)) => RuleResult::Success(self.constraint_type().to_error()),
+            // Failures and errors of the referenced rule are returned as is.
+            Ok(result) => result,
+            Err(err) => RuleResult::Error(err),
+        }
+    }
+}
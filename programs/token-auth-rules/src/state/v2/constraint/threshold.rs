@@ -0,0 +1,115 @@
+use solana_program::msg;
+
+use crate::{
+    error::RuleSetError,
+    state::{try_from_bytes, validate_threshold, RuleResult},
+    state::{
+        v2::{Constraint, ConstraintType, RuleV2, HEADER_SECTION, U64_BYTES},
+        Header,
+    },
+};
+
+/// Constraint representing a group threshold, where at least `k` of the rules contained must
+/// pass.  Rules are evaluated in order and evaluation stops as soon as `k` rules passed or `k`
+/// can no longer be reached.
+pub struct Threshold<'a> {
+    /// The number of rules that must pass.
+    pub k: &'a u64,
+    /// The number of rules contained under Threshold.
+    pub size: &'a u64,
+    /// The vector of Rules contained under Threshold.
+    pub rules: Vec<RuleV2<'a>>,
+}
+
+impl<'a> Threshold<'a> {
+    /// Deserialize a constraint from a byte array.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        // k
+        let k = try_from_bytes::<u64>(0, U64_BYTES, bytes)?;
+        let mut offset = U64_BYTES;
+
+        // size
+        let size = try_from_bytes::<u64>(offset, U64_BYTES, bytes)?;
+        offset += U64_BYTES;
+
+        let mut rules = Vec::with_capacity(*size as usize);
+
+        for _ in 0..*size {
+            let rule = RuleV2::from_bytes(&bytes[offset..])?;
+            offset += rule.length();
+            rules.push(rule);
+        }
+
+        Ok(Self { k, size, rules })
+    }
+
+    /// Serialize a constraint into a byte array.
+    pub fn serialize(k: u64, rules: &[&[u8]]) -> Result<Vec<u8>, RuleSetError> {
+        let length = (U64_BYTES
+            + U64_BYTES
+            + rules
+                .iter()
+                .map(|v| v.len())
+                .reduce(|accum, item| accum + item)
+                .ok_or(RuleSetError::EmptyRuleGroup)?) as u32;
+
+        if k == 0 || k > rules.len() as u64 {
+            return Err(RuleSetError::InvalidThreshold);
+        }
+
+        let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
+
+        // Header
+        Header::serialize(ConstraintType::Threshold, length, &mut data);
+
+        // Constraint
+        // - k
+        data.extend(u64::to_le_bytes(k));
+        // - size
+        data.extend(u64::to_le_bytes(rules.len() as u64));
+        // - rules
+        rules.iter().for_each(|x| data.extend(x.iter()));
+
+        Ok(data)
+    }
+}
+
+impl<'a> Constraint<'a> for Threshold<'a> {
+    fn constraint_type(&self) -> ConstraintType {
+        ConstraintType::Threshold
+    }
+
+    fn rules(&self) -> &[RuleV2<'a>] {
+        &self.rules
+    }
+
+    fn combine(&self, results: &mut dyn Iterator<Item = RuleResult>) -> RuleResult {
+        validate_threshold(*self.k, self.rules.len(), results)
+    }
+
+    fn validate(
+        &self,
+        accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        payload: &crate::payload::Payload,
+        update_rule_state: bool,
+        rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating Threshold");
+
+        self.combine(&mut self.rules.iter().map(|rule| {
+            rule.validate(
+                accounts,
+                payload,
+                update_rule_state,
+                rule_set_state_pda,
+                rule_authority,
+                context,
+            )
+        }))
+    }
+}
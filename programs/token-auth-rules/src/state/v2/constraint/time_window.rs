@@ -0,0 +1,93 @@
+use solana_program::msg;
+
+use crate::{
+    error::RuleSetError,
+    state::{try_from_bytes, validate_time_window, RuleResult, TimeSource},
+    state::{
+        v2::{Constraint, ConstraintType, HEADER_SECTION, U64_BYTES},
+        Header,
+    },
+};
+
+/// Constraint representing a comparison based on when the operation happens.
+///
+/// This constraint checks that the `Clock` value selected by `source` (the unix timestamp or
+/// the slot) is inside the `[start, end)` window stored in the rule.  `start` must be before
+/// `end`.
+pub struct TimeWindow<'a> {
+    /// The `Clock` value to compare, as the `u64` representation of a `TimeSource`.
+    pub source: &'a u64,
+    /// Start of the window (inclusive).
+    pub start: &'a i64,
+    /// End of the window (exclusive).
+    pub end: &'a i64,
+}
+
+impl<'a> TimeWindow<'a> {
+    /// Deserialize a constraint from a byte array.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        // source
+        let source = try_from_bytes::<u64>(0, U64_BYTES, bytes)?;
+        let mut cursor = U64_BYTES;
+
+        // start
+        let start = try_from_bytes::<i64>(cursor, U64_BYTES, bytes)?;
+        cursor += U64_BYTES;
+
+        // end
+        let end = try_from_bytes::<i64>(cursor, U64_BYTES, bytes)?;
+
+        Ok(Self { source, start, end })
+    }
+
+    /// Serialize a constraint into a byte array.
+    pub fn serialize(source: TimeSource, start: i64, end: i64) -> Result<Vec<u8>, RuleSetError> {
+        if start >= end {
+            return Err(RuleSetError::InvalidTimeWindow);
+        }
+
+        let length = (U64_BYTES * 3) as u32;
+        let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
+
+        // Header
+        Header::serialize(ConstraintType::TimeWindow, length, &mut data);
+
+        // Constraint
+        // - source
+        data.extend(u64::to_le_bytes(source as u64));
+        // - start
+        data.extend(i64::to_le_bytes(start));
+        // - end
+        data.extend(i64::to_le_bytes(end));
+
+        Ok(data)
+    }
+}
+
+impl<'a> Constraint<'a> for TimeWindow<'a> {
+    fn constraint_type(&self) -> ConstraintType {
+        ConstraintType::TimeWindow
+    }
+
+    fn validate(
+        &self,
+        _accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        _payload: &crate::payload::Payload,
+        _update_rule_state: bool,
+        _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating TimeWindow");
+
+        let source = match TimeSource::try_from(*self.source) {
+            Ok(source) => source,
+            Err(err) => return RuleResult::Error(err.into()),
+        };
+
+        validate_time_window(source, *self.start, *self.end)
+    }
+}
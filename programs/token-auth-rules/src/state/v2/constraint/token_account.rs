@@ -0,0 +1,297 @@
+use bytemuck::{Pod, Zeroable};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde-with-feature")]
+use serde_with::{As, DisplayFromStr};
+use solana_program::{
+    account_info::AccountInfo,
+    msg,
+    program_error::ProgramError,
+    program_option::COption,
+    program_pack::Pack,
+    pubkey,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+use spl_token::state::{Account, AccountState};
+
+use crate::{
+    error::RuleSetError,
+    payload::Payload,
+    state::RuleResult,
+    state::{
+        try_cast_slice, try_from_bytes,
+        v2::{Constraint, ConstraintType, Str32, HEADER_SECTION, U64_BYTES},
+        Header,
+    },
+};
+
+/// Program ID of the Token-2022 program, which shares the base account layout of SPL Token.
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Token-2022 `AccountType` value stored after the base layout of token accounts.
+const TOKEN_2022_ACCOUNT_TYPE: u8 = 2;
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+/// Checks that can be asserted against the state of a token account.
+pub enum TokenAccountCheck {
+    /// The owner of the token account must match the `Pubkey`.
+    Owner {
+        /// The expected owner.
+        #[cfg_attr(feature = "serde-with-feature", serde(with = "As::<DisplayFromStr>"))]
+        pubkey: Pubkey,
+    },
+    /// The owner of the token account must match the `Pubkey` stored in the `Payload` field.
+    OwnerField {
+        /// The field in the `Payload` holding the expected owner.
+        field: String,
+    },
+    /// The mint of the token account must match the `Pubkey`.
+    Mint {
+        /// The expected mint.
+        #[cfg_attr(feature = "serde-with-feature", serde(with = "As::<DisplayFromStr>"))]
+        pubkey: Pubkey,
+    },
+    /// The mint of the token account must match the `Pubkey` stored in the `Payload` field.
+    MintField {
+        /// The field in the `Payload` holding the expected mint.
+        field: String,
+    },
+    /// The token account must have a delegate matching the `Pubkey`.
+    Delegate {
+        /// The expected delegate.
+        #[cfg_attr(feature = "serde-with-feature", serde(with = "As::<DisplayFromStr>"))]
+        pubkey: Pubkey,
+    },
+    /// The token account must have a delegate matching the `Pubkey` stored in the `Payload` field.
+    DelegateField {
+        /// The field in the `Payload` holding the expected delegate.
+        field: String,
+    },
+    /// The token account must not have a delegate.
+    NoDelegate,
+    /// The token account must be (or not be) frozen.
+    Frozen {
+        /// Whether the token account is expected to be frozen.
+        frozen: bool,
+    },
+}
+
+impl TokenAccountCheck {
+    /// Serialize a check into its on-chain representation.
+    pub fn serialize(&self) -> Result<TokenAccountCheckData, RuleSetError> {
+        let (check_type, value) = match self {
+            TokenAccountCheck::Owner { pubkey } => (0, pubkey.to_bytes()),
+            TokenAccountCheck::OwnerField { field } => (1, Str32::serialize(field)?),
+            TokenAccountCheck::Mint { pubkey } => (2, pubkey.to_bytes()),
+            TokenAccountCheck::MintField { field } => (3, Str32::serialize(field)?),
+            TokenAccountCheck::Delegate { pubkey } => (4, pubkey.to_bytes()),
+            TokenAccountCheck::DelegateField { field } => (5, Str32::serialize(field)?),
+            TokenAccountCheck::NoDelegate => (6, [0; PUBKEY_BYTES]),
+            TokenAccountCheck::Frozen { frozen } => {
+                let mut value = [0; PUBKEY_BYTES];
+                value[0] = *frozen as u8;
+                (7, value)
+            }
+        };
+
+        Ok(TokenAccountCheckData { check_type, value })
+    }
+}
+
+impl TryFrom<&TokenAccountCheckData> for TokenAccountCheck {
+    // Type of the error generated.
+    type Error = RuleSetError;
+
+    fn try_from(data: &TokenAccountCheckData) -> Result<Self, Self::Error> {
+        let pubkey = Pubkey::new_from_array(data.value);
+        let field = Str32 { value: data.value }.to_string();
+
+        match data.check_type {
+            0 => Ok(TokenAccountCheck::Owner { pubkey }),
+            1 => Ok(TokenAccountCheck::OwnerField { field }),
+            2 => Ok(TokenAccountCheck::Mint { pubkey }),
+            3 => Ok(TokenAccountCheck::MintField { field }),
+            4 => Ok(TokenAccountCheck::Delegate { pubkey }),
+            5 => Ok(TokenAccountCheck::DelegateField { field }),
+            6 => Ok(TokenAccountCheck::NoDelegate),
+            7 => Ok(TokenAccountCheck::Frozen {
+                frozen: data.value[0] != 0,
+            }),
+            _ => Err(RuleSetError::InvalidTokenAccountCheck),
+        }
+    }
+}
+
+/// On-chain representation of a `TokenAccountCheck`.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct TokenAccountCheckData {
+    /// The type of the check.
+    pub check_type: u64,
+    /// The `Pubkey`, `Payload` field name or flag of the check, depending on the type.
+    pub value: [u8; PUBKEY_BYTES],
+}
+
+impl TokenAccountCheckData {
+    /// The size of the struct in bytes.
+    pub const SIZE: usize = U64_BYTES + PUBKEY_BYTES;
+}
+
+/// Constraint representing a test against the state of an SPL Token (or Token-2022) account.
+///
+/// This constraint requires a `PayloadType` value of `PayloadType::Pubkey`.  The `field` value in
+/// the rule is used to locate the `Pubkey` of the token account in the payload, which must be
+/// owned by the SPL Token or Token-2022 program.  Every check in the rule must pass against the
+/// base layout of the token account.  Note this same `Pubkey` account must also be provided to
+/// `Validate` via the `additional_rule_accounts` argument.
+pub struct TokenAccount<'a> {
+    /// The field in the `Payload` to be compared.
+    pub field: &'a Str32,
+    /// The checks to assert against the token account.
+    pub checks: &'a [TokenAccountCheckData],
+}
+
+impl<'a> TokenAccount<'a> {
+    /// Deserialize a constraint from a byte array.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        let field = try_from_bytes::<Str32>(0, Str32::SIZE, bytes)?;
+        let checks = try_cast_slice(&bytes[Str32::SIZE..])?;
+
+        Ok(Self { field, checks })
+    }
+
+    /// Serialize a constraint into a byte array.
+    pub fn serialize(field: String, checks: &[TokenAccountCheck]) -> Result<Vec<u8>, RuleSetError> {
+        // sanity check: a constraint without checks would only test the account owner
+        if checks.is_empty() {
+            return Err(RuleSetError::InvalidTokenAccountCheck);
+        }
+
+        let length = (Str32::SIZE + (checks.len() * TokenAccountCheckData::SIZE)) as u32;
+        let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
+
+        // Header
+        Header::serialize(ConstraintType::TokenAccount, length, &mut data);
+
+        // Constraint
+        // - field
+        data.extend(Str32::serialize(&field)?);
+        // - checks
+        for check in checks {
+            data.extend(bytemuck::bytes_of(&check.serialize()?));
+        }
+
+        Ok(data)
+    }
+}
+
+impl<'a> Constraint<'a> for TokenAccount<'a> {
+    fn constraint_type(&self) -> ConstraintType {
+        ConstraintType::TokenAccount
+    }
+
+    fn validate(
+        &self,
+        accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        payload: &crate::payload::Payload,
+        _update_rule_state: bool,
+        _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating TokenAccount");
+
+        let key = match payload.get_pubkey(&self.field.to_string()) {
+            Some(pubkey) => pubkey,
+            _ => return RuleResult::Error(RuleSetError::MissingPayloadValue.into()),
+        };
+
+        let account_info = match accounts.get(key) {
+            Some(account) => account,
+            _ => return RuleResult::Error(RuleSetError::MissingAccount.into()),
+        };
+
+        let account = match unpack_token_account(account_info) {
+            Ok(Some(account)) => account,
+            Ok(None) => return RuleResult::Failure(self.constraint_type().to_error()),
+            Err(error) => return RuleResult::Error(error),
+        };
+
+        for check in self.checks {
+            let check = match TokenAccountCheck::try_from(check) {
+                Ok(check) => check,
+                Err(error) => return RuleResult::Error(error.into()),
+            };
+
+            match check_token_account(&account, &check, payload) {
+                Ok(true) => (),
+                Ok(false) => {
+                    msg!("Token account check failed: {:?}", check);
+                    return RuleResult::Failure(self.constraint_type().to_error());
+                }
+                Err(error) => return RuleResult::Error(error.into()),
+            }
+        }
+
+        RuleResult::Success(self.constraint_type().to_error())
+    }
+}
+
+/// Unpacks the base layout of an SPL Token or Token-2022 account, returning `None` if the
+/// account is not an initialized token account.
+pub(crate) fn unpack_token_account(
+    account_info: &AccountInfo,
+) -> Result<Option<Account>, ProgramError> {
+    if *account_info.owner != spl_token::ID && *account_info.owner != TOKEN_2022_PROGRAM_ID {
+        msg!("Account is not owned by a token program");
+        return Ok(None);
+    }
+
+    let data = account_info
+        .data
+        .try_borrow()
+        .map_err(|_| ProgramError::AccountBorrowFailed)?;
+
+    // Token-2022 accounts with extensions store the account type after the base layout.
+    if data.len() > Account::LEN && data[Account::LEN] != TOKEN_2022_ACCOUNT_TYPE {
+        msg!("Account is not a token account");
+        return Ok(None);
+    }
+
+    match data.get(..Account::LEN).map(Account::unpack) {
+        Some(Ok(account)) => Ok(Some(account)),
+        _ => {
+            msg!("Account is not an initialized token account");
+            Ok(None)
+        }
+    }
+}
+
+/// Evaluates a single check against the unpacked token account.
+fn check_token_account(
+    account: &Account,
+    check: &TokenAccountCheck,
+    payload: &Payload,
+) -> Result<bool, RuleSetError> {
+    let payload_pubkey = |field: &String| {
+        payload
+            .get_pubkey(field)
+            .copied()
+            .ok_or(RuleSetError::MissingPayloadValue)
+    };
+
+    Ok(match check {
+        TokenAccountCheck::Owner { pubkey } => account.owner == *pubkey,
+        TokenAccountCheck::OwnerField { field } => account.owner == payload_pubkey(field)?,
+        TokenAccountCheck::Mint { pubkey } => account.mint == *pubkey,
+        TokenAccountCheck::MintField { field } => account.mint == payload_pubkey(field)?,
+        TokenAccountCheck::Delegate { pubkey } => account.delegate == COption::Some(*pubkey),
+        TokenAccountCheck::DelegateField { field } => {
+            account.delegate == COption::Some(payload_pubkey(field)?)
+        }
+        TokenAccountCheck::NoDelegate => account.delegate.is_none(),
+        TokenAccountCheck::Frozen { frozen } => (account.state == AccountState::Frozen) == *frozen,
+    })
+}
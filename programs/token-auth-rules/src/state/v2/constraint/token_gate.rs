@@ -0,0 +1,156 @@
+use solana_program::{
+    msg,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+
+use crate::{
+    error::RuleSetError,
+    state::{try_from_bytes, RuleResult},
+    state::{
+        v2::{
+            unpack_token_account, Constraint, ConstraintType, Operator, Str32, HEADER_SECTION,
+            U64_BYTES,
+        },
+        Header,
+    },
+};
+
+/// Constraint representing a test where a wallet must hold an amount of tokens of a mint.
+///
+/// This constraint requires `PayloadType` values of `PayloadType::Pubkey`.  The `field` value in
+/// the rule is used to locate the `Pubkey` of a token account in the payload, which must hold
+/// tokens of the `mint` stored in the rule and be owned by the `Pubkey` located by the
+/// `owner_field` value.  The amount held by the token account is compared to the amount stored in
+/// the rule, using the comparison operator stored in the rule.  Note the token account must also
+/// be provided to `Validate` via the `additional_rule_accounts` argument.
+pub struct TokenGate<'a> {
+    /// The mint of the tokens.
+    pub mint: &'a Pubkey,
+    /// The amount to be compared against.
+    pub amount: &'a u64,
+    /// The operator to be used in the comparison.
+    pub operator: &'a u64,
+    /// The field in the `Payload` holding the token account.
+    pub field: &'a Str32,
+    /// The field in the `Payload` holding the owner of the token account.
+    pub owner_field: &'a Str32,
+}
+
+impl<'a> TokenGate<'a> {
+    /// Deserialize a constraint from a byte array.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RuleSetError> {
+        // mint
+        let mint = try_from_bytes::<Pubkey>(0, PUBKEY_BYTES, bytes)?;
+        let mut cursor = PUBKEY_BYTES;
+
+        // amount
+        let amount = try_from_bytes::<u64>(cursor, U64_BYTES, bytes)?;
+        cursor += U64_BYTES;
+
+        // operator
+        let operator = try_from_bytes::<u64>(cursor, U64_BYTES, bytes)?;
+        cursor += U64_BYTES;
+
+        // field
+        let field = try_from_bytes::<Str32>(cursor, Str32::SIZE, bytes)?;
+        cursor += Str32::SIZE;
+
+        // owner_field
+        let owner_field = try_from_bytes::<Str32>(cursor, Str32::SIZE, bytes)?;
+
+        Ok(Self {
+            mint,
+            amount,
+            operator,
+            field,
+            owner_field,
+        })
+    }
+
+    /// Serialize a constraint into a byte array.
+    pub fn serialize(
+        field: String,
+        owner_field: String,
+        mint: Pubkey,
+        operator: Operator,
+        amount: u64,
+    ) -> Result<Vec<u8>, RuleSetError> {
+        // length of the assert
+        let length = (PUBKEY_BYTES + U64_BYTES + U64_BYTES + Str32::SIZE + Str32::SIZE) as u32;
+        let mut data = Vec::with_capacity(HEADER_SECTION + length as usize);
+
+        // Header
+        Header::serialize(ConstraintType::TokenGate, length, &mut data);
+
+        // Constraint
+        // - mint
+        data.extend(mint.as_ref());
+        // - amount
+        data.extend(u64::to_le_bytes(amount));
+        // - operator
+        data.extend(u64::to_le_bytes(operator as u64));
+        // - field
+        data.extend(Str32::serialize(&field)?);
+        // - owner_field
+        data.extend(Str32::serialize(&owner_field)?);
+
+        Ok(data)
+    }
+}
+
+impl<'a> Constraint<'a> for TokenGate<'a> {
+    fn constraint_type(&self) -> ConstraintType {
+        ConstraintType::TokenGate
+    }
+
+    fn validate(
+        &self,
+        accounts: &std::collections::HashMap<
+            solana_program::pubkey::Pubkey,
+            &solana_program::account_info::AccountInfo,
+        >,
+        payload: &crate::payload::Payload,
+        _update_rule_state: bool,
+        _rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
+        _rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        _context: &crate::types::ValidationContext,
+    ) -> RuleResult {
+        msg!("Validating TokenGate");
+
+        let key = match payload.get_pubkey(&self.field.to_string()) {
+            Some(pubkey) => pubkey,
+            _ => return RuleResult::Error(RuleSetError::MissingPayloadValue.into()),
+        };
+
+        let owner = match payload.get_pubkey(&self.owner_field.to_string()) {
+            Some(pubkey) => pubkey,
+            _ => return RuleResult::Error(RuleSetError::MissingPayloadValue.into()),
+        };
+
+        let account_info = match accounts.get(key) {
+            Some(account) => account,
+            _ => return RuleResult::Error(RuleSetError::MissingAccount.into()),
+        };
+
+        let account = match unpack_token_account(account_info) {
+            Ok(Some(account)) => account,
+            Ok(None) => return RuleResult::Failure(self.constraint_type().to_error()),
+            Err(error) => return RuleResult::Error(error),
+        };
+
+        let operator = match Operator::try_from(*self.operator) {
+            Ok(operator) => operator,
+            // sanity check: the value is checked at creation
+            Err(_) => return RuleResult::Failure(self.constraint_type().to_error()),
+        };
+
+        if account.mint == *self.mint
+            && account.owner == *owner
+            && operator.compare(account.amount, *self.amount)
+        {
+            RuleResult::Success(self.constraint_type().to_error())
+        } else {
+            RuleResult::Failure(self.constraint_type().to_error())
+        }
+    }
+}
@@ -1,16 +1,26 @@
 pub mod constraint;
+mod owned;
 mod rule_set_v2;
 mod rule_v2;
+mod trace;
 
 pub use constraint::*;
+pub use owned::*;
 pub use rule_set_v2::*;
 pub use rule_v2::*;
+pub use trace::*;
 
 use bytemuck::{AnyBitPattern, NoUninit, Pod, Zeroable};
+use serde::{Deserialize, Serialize};
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 use std::{collections::HashMap, fmt::Display};
 
-use crate::{error::RuleSetError, payload::Payload, state::RuleResult, types::MAX_NAME_LENGTH};
+use crate::{
+    error::RuleSetError,
+    payload::Payload,
+    state::RuleResult,
+    types::{ValidationContext, MAX_NAME_LENGTH},
+};
 
 /// Size (in bytes) of a u64 value.
 pub const U64_BYTES: usize = std::mem::size_of::<u64>();
@@ -48,6 +58,11 @@ pub(crate) fn try_cast_slice<A: NoUninit, B: AnyBitPattern>(
     })
 }
 
+/// Length of a byte array padded to a multiple of 8 bytes, keeping the following rules aligned.
+pub(crate) fn padded_len(len: usize) -> usize {
+    len.saturating_add(U64_BYTES - 1) / U64_BYTES * U64_BYTES
+}
+
 /// Struct representing a 32 byte string.
 #[repr(C)]
 #[derive(Copy, Clone, Pod, Zeroable)]
@@ -59,6 +74,17 @@ pub struct Str32 {
 impl Str32 {
     /// The size of the struct in bytes.
     pub const SIZE: usize = MAX_NAME_LENGTH;
+
+    /// Serialize a string into a zero-padded 32 byte array.
+    pub fn serialize(value: &str) -> Result<[u8; Self::SIZE], RuleSetError> {
+        if value.len() > Self::SIZE {
+            return Err(RuleSetError::FieldTooLong);
+        }
+
+        let mut bytes = [0u8; Self::SIZE];
+        bytes[..value.len()].copy_from_slice(value.as_bytes());
+        Ok(bytes)
+    }
 }
 
 impl Display for Str32 {
@@ -84,10 +110,23 @@ pub trait Constraint<'a> {
         update_rule_state: bool,
         rule_set_state_pda: &Option<&AccountInfo>,
         rule_authority: &Option<&AccountInfo>,
+        context: &ValidationContext,
     ) -> RuleResult;
 
     /// Returns the type of the constraint.
     fn constraint_type(&self) -> ConstraintType;
+
+    /// Returns the rules contained under the constraint, if any.
+    fn rules(&self) -> &[RuleV2<'a>] {
+        &[]
+    }
+
+    /// Combines the results of the rules contained under the constraint, given in the order of
+    /// `rules`, into the result of the constraint.  `results` is consumed lazily, so evaluation
+    /// can stop as soon as the result is known.
+    fn combine(&self, _results: &mut dyn Iterator<Item = RuleResult>) -> RuleResult {
+        RuleResult::Error(RuleSetError::UnexpectedRuleSetFailure.into())
+    }
 }
 
 #[repr(u32)]
@@ -130,6 +169,36 @@ pub enum ConstraintType {
     PubkeyMatch,
     /// The comparing `Pubkey` must be a member of the Merkle tree in the rule.
     PubkeyTreeMatch,
+    /// The `Pubkey` must be owned by a program in the set of `Pubkey`s.
+    ProgramOwnedSet,
+    /// The `Clock` value must be inside a time window.
+    TimeWindow,
+    /// A slice of the data of an account must match the bytes in the rule.
+    AccountData,
+    /// The state of a token account must pass the checks in the rule.
+    TokenAccount,
+    /// A token account owned by the `Pubkey` must hold an amount of tokens of the mint.
+    TokenGate,
+    /// Comparison between two values in the `Payload`.
+    FieldCompare,
+    /// Comparison against a range of amounts of tokens being transferred.
+    AmountRange,
+    /// Group threshold, where at least `k` of the rules contained must pass.
+    Threshold,
+    /// At least a number of the additional signers must be present.
+    AdditionalSignerList,
+    /// An instruction of the transaction must match the program and data in the rule.
+    InstructionMatch,
+    /// The royalties paid to the creators in the transaction must cover a share of the sale price.
+    RoyaltyPayment,
+    /// An unexpired attestation signed by the authority must be verified by the ed25519 program.
+    Ed25519Attestation,
+    /// The mint being validated must be in the list of `Pubkey`s.
+    MintListMatch,
+    /// The mint being validated must be a member of the Merkle tree in the rule.
+    MintTreeMatch,
+    /// The rule of an operation of another `RuleSet` must pass.
+    RuleSetReference,
 }
 
 impl ConstraintType {
@@ -137,14 +206,15 @@ impl ConstraintType {
     pub fn to_error(&self) -> ProgramError {
         match self {
             ConstraintType::Uninitialized => RuleSetError::InvalidConstraintType.into(),
-            ConstraintType::AdditionalSigner { .. } => {
+            ConstraintType::AdditionalSigner { .. } | ConstraintType::AdditionalSignerList => {
                 RuleSetError::AdditionalSignerCheckFailed.into()
             }
             ConstraintType::All
             | ConstraintType::Any
             | ConstraintType::Namespace
             | ConstraintType::Not
-            | ConstraintType::Pass => RuleSetError::UnexpectedRuleSetFailure.into(),
+            | ConstraintType::Pass
+            | ConstraintType::RuleSetReference => RuleSetError::UnexpectedRuleSetFailure.into(),
             ConstraintType::Amount => RuleSetError::AmountCheckFailed.into(),
             ConstraintType::Frequency { .. } => RuleSetError::FrequencyCheckFailed.into(),
             ConstraintType::IsWallet { .. } => RuleSetError::IsWalletCheckFailed.into(),
@@ -161,6 +231,21 @@ impl ConstraintType {
             ConstraintType::PubkeyTreeMatch { .. } => {
                 RuleSetError::PubkeyTreeMatchCheckFailed.into()
             }
+            ConstraintType::ProgramOwnedSet => RuleSetError::ProgramOwnedSetCheckFailed.into(),
+            ConstraintType::TimeWindow => RuleSetError::TimeWindowCheckFailed.into(),
+            ConstraintType::AccountData => RuleSetError::AccountDataCheckFailed.into(),
+            ConstraintType::TokenAccount => RuleSetError::TokenAccountCheckFailed.into(),
+            ConstraintType::TokenGate => RuleSetError::TokenGateCheckFailed.into(),
+            ConstraintType::FieldCompare => RuleSetError::FieldCompareCheckFailed.into(),
+            ConstraintType::AmountRange => RuleSetError::AmountCheckFailed.into(),
+            ConstraintType::Threshold => RuleSetError::ThresholdCheckFailed.into(),
+            ConstraintType::InstructionMatch => RuleSetError::InstructionMatchCheckFailed.into(),
+            ConstraintType::RoyaltyPayment => RuleSetError::RoyaltyPaymentCheckFailed.into(),
+            ConstraintType::Ed25519Attestation => {
+                RuleSetError::Ed25519AttestationCheckFailed.into()
+            }
+            ConstraintType::MintListMatch => RuleSetError::MintListMatchCheckFailed.into(),
+            ConstraintType::MintTreeMatch => RuleSetError::MintTreeMatchCheckFailed.into(),
         }
     }
 }
@@ -188,13 +273,28 @@ impl TryFrom<u32> for ConstraintType {
             14 => Ok(ConstraintType::PubkeyListMatch),
             15 => Ok(ConstraintType::PubkeyMatch),
             16 => Ok(ConstraintType::PubkeyTreeMatch),
+            17 => Ok(ConstraintType::ProgramOwnedSet),
+            18 => Ok(ConstraintType::TimeWindow),
+            19 => Ok(ConstraintType::AccountData),
+            20 => Ok(ConstraintType::TokenAccount),
+            21 => Ok(ConstraintType::TokenGate),
+            22 => Ok(ConstraintType::FieldCompare),
+            23 => Ok(ConstraintType::AmountRange),
+            24 => Ok(ConstraintType::Threshold),
+            25 => Ok(ConstraintType::AdditionalSignerList),
+            26 => Ok(ConstraintType::InstructionMatch),
+            27 => Ok(ConstraintType::RoyaltyPayment),
+            28 => Ok(ConstraintType::Ed25519Attestation),
+            29 => Ok(ConstraintType::MintListMatch),
+            30 => Ok(ConstraintType::MintTreeMatch),
+            31 => Ok(ConstraintType::RuleSetReference),
             _ => Err(RuleSetError::InvalidConstraintType),
         }
     }
 }
 
 #[repr(u64)]
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
 /// Operators that can be used to compare against an `Amount` rule.
 pub enum Operator {
     /// Less Than
@@ -207,6 +307,10 @@ pub enum Operator {
     GtEq,
     /// Greater Than
     Gt,
+    /// Not Equal To
+    Ne,
+    /// Multiple Of
+    MultipleOf,
 }
 
 impl TryFrom<u64> for Operator {
@@ -220,7 +324,25 @@ impl TryFrom<u64> for Operator {
             2 => Ok(Operator::Eq),
             3 => Ok(Operator::GtEq),
             4 => Ok(Operator::Gt),
+            5 => Ok(Operator::Ne),
+            6 => Ok(Operator::MultipleOf),
             _ => Err(RuleSetError::InvalidCompareOp),
         }
     }
 }
+
+impl Operator {
+    /// Compares `value` against `target` using the operator.
+    pub fn compare(&self, value: u64, target: u64) -> bool {
+        match self {
+            Operator::Lt => value < target,
+            Operator::LtEq => value <= target,
+            Operator::Eq => value == target,
+            Operator::GtEq => value >= target,
+            Operator::Gt => value > target,
+            Operator::Ne => value != target,
+            // a zero target never matches
+            Operator::MultipleOf => value.checked_rem(target) == Some(0),
+        }
+    }
+}
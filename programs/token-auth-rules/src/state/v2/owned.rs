@@ -0,0 +1,1324 @@
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "serde-with-feature")]
+use serde_with::{As, DisplayFromStr};
+use solana_program::{
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    pubkey::{Pubkey, PUBKEY_BYTES},
+};
+
+use crate::{
+    error::RuleSetError,
+    state::{
+        try_from_bytes,
+        v2::{
+            AccountData, AdditionalSigner, AdditionalSignerList, All, Amount, AmountRange, Any,
+            Ed25519Attestation, FieldCompare, Frequency, InstructionMatch, IsWallet, MintListMatch,
+            MintTreeMatch, Namespace, Not, Operator, PDAMatch, Pass, ProgramOwned,
+            ProgramOwnedList, ProgramOwnedSet, ProgramOwnedTree, PubkeyListMatch, PubkeyMatch,
+            PubkeyTreeMatch, RoyaltyPayment, RuleSetReference, RuleSetV2, Str32, Threshold,
+            TimeWindow, TokenAccount, TokenAccountCheck, TokenGate, HEADER_SECTION, U64_BYTES,
+        },
+        CompareOp, ConstraintType, Header, Rule, RuleSetV1, TimeSource,
+    },
+    types::{LibVersion, RuleSet},
+    utils::get_rule_set_revision_data,
+};
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+/// Owned representation of a `RuleV2` tree, which can be serialized into the `RuleSetV2`
+/// format.  Each variant corresponds to a `ConstraintType`.
+pub enum OwnedRuleV2 {
+    /// A slice of the data of an account must match the bytes in the rule.
+    AccountData {
+        /// The field in the `Payload` to be compared.
+        field: String,
+        /// Offset of the slice in the account data.
+        offset: u64,
+        /// The operator to be used in the comparison.
+        operator: Operator,
+        /// The expected bytes.
+        expected: Vec<u8>,
+        /// The mask applied to the account data and the expected bytes, if any.
+        mask: Option<Vec<u8>>,
+    },
+    /// An additional signer must be present.
+    AdditionalSigner {
+        /// The public key that must have also signed the transaction.
+        #[cfg_attr(feature = "serde-with-feature", serde(with = "As::<DisplayFromStr>"))]
+        account: Pubkey,
+    },
+    /// At least `threshold` of the additional signers must be present.
+    AdditionalSignerList {
+        /// The public keys that may have also signed the transaction.
+        accounts: Vec<Pubkey>,
+        /// The number of public keys that must have also signed the transaction.
+        threshold: u64,
+    },
+    /// Group AND, where every rule contained must pass.
+    All {
+        /// The vector of rules contained under All.
+        rules: Vec<OwnedRuleV2>,
+    },
+    /// Comparison against the amount of tokens being transferred.
+    Amount {
+        /// The field the amount is stored in.
+        field: String,
+        /// The operator to be used in the comparison.
+        operator: Operator,
+        /// The amount to be compared against.
+        amount: u64,
+    },
+    /// Comparison against a range of amounts of tokens being transferred.
+    AmountRange {
+        /// The field the amount is stored in.
+        field: String,
+        /// The minimum amount (inclusive).
+        min: u64,
+        /// The maximum amount (inclusive).
+        max: u64,
+    },
+    /// Group OR, where at least one rule contained must pass.
+    Any {
+        /// The vector of rules contained under Any.
+        rules: Vec<OwnedRuleV2>,
+    },
+    /// An unexpired attestation for the wallet and the mint being validated, signed by the
+    /// authority, must be verified by the ed25519 program.
+    Ed25519Attestation {
+        /// The field in the `Payload` holding the wallet.
+        field: String,
+        /// The authority that must have signed the attestation.
+        #[cfg_attr(feature = "serde-with-feature", serde(with = "As::<DisplayFromStr>"))]
+        authority: Pubkey,
+    },
+    /// Comparison between two values in the `Payload`.
+    FieldCompare {
+        /// The field in the `Payload` holding the value to compare.
+        field: String,
+        /// The operator to be used in the comparison.
+        operator: Operator,
+        /// The field in the `Payload` holding the value compared against.
+        other_field: String,
+    },
+    /// Comparison based on time between operations.
+    Frequency {
+        /// The authority of the frequency account.
+        #[cfg_attr(feature = "serde-with-feature", serde(with = "As::<DisplayFromStr>"))]
+        authority: Pubkey,
+        /// The period (in seconds) which must transpire before the rule will succeed again.
+        period: i64,
+    },
+    /// An instruction of the transaction must match the program and data.
+    InstructionMatch {
+        /// The program the instruction must target.
+        #[cfg_attr(feature = "serde-with-feature", serde(with = "As::<DisplayFromStr>"))]
+        program: Pubkey,
+        /// The leading bytes of the instruction data.
+        discriminator: Vec<u8>,
+        /// The field in the `Payload` holding the instruction account, if any.
+        account_field: Option<String>,
+        /// Index of the instruction account to be compared.
+        account_index: u64,
+    },
+    /// The `Pubkey` in the payload must be a wallet.
+    IsWallet {
+        /// The field in the `Payload` to be checked.
+        field: String,
+    },
+    /// The mint being validated must be in the list of `Pubkey`s.
+    MintListMatch {
+        /// The list of mints to be compared against.
+        mints: Vec<Pubkey>,
+    },
+    /// The mint being validated must be a member of the Merkle tree in the rule.
+    MintTreeMatch {
+        /// The root of the Merkle tree.
+        root: [u8; 32],
+        /// The field in the `Payload` to be compared when looking for the Merkle proof.
+        proof_field: String,
+    },
+    /// A rule that tells the operation finder to use the default namespace rule.
+    Namespace,
+    /// Negation, where the contained rule must fail.
+    Not {
+        /// The rule contained under Not.
+        rule: Box<OwnedRuleV2>,
+    },
+    /// An operation that always succeeds.
+    Pass,
+    /// A resulting PDA derivation of seeds must prove the account is a PDA.
+    PDAMatch {
+        /// The program used for the PDA derivation.  If `None` then the account owner is used.
+        program: Option<Pubkey>,
+        /// The field in the `Payload` to be compared when looking for the PDA.
+        pda_field: String,
+        /// The field in the `Payload` to be compared when looking for the seeds.
+        seeds_field: String,
+    },
+    /// The `Pubkey` must be owned by a given program.
+    ProgramOwned {
+        /// The program that must own the `Pubkey`.
+        #[cfg_attr(feature = "serde-with-feature", serde(with = "As::<DisplayFromStr>"))]
+        program: Pubkey,
+        /// The field in the `Payload` to be compared.
+        field: String,
+    },
+    /// The `Pubkey` must be owned by a program in the list of `Pubkey`s.
+    ProgramOwnedList {
+        /// The programs that may own the `Pubkey`.
+        programs: Vec<Pubkey>,
+        /// The field in the `Payload` to be compared.
+        field: String,
+    },
+    /// The `Pubkey` must be owned by a program in the set of `Pubkey`s.
+    ProgramOwnedSet {
+        /// The programs that may own the `Pubkey`.
+        programs: Vec<Pubkey>,
+        /// The field in the `Payload` to be compared.
+        field: String,
+    },
+    /// The `Pubkey` must be owned by a member of the Merkle tree in the rule.
+    ProgramOwnedTree {
+        /// The root of the Merkle tree.
+        root: [u8; 32],
+        /// The field in the `Payload` to be compared when looking for the `Pubkey`.
+        pubkey_field: String,
+        /// The field in the `Payload` to be compared when looking for the Merkle proof.
+        proof_field: String,
+    },
+    /// The comparing `Pubkey` must be in the list of `Pubkey`s.
+    PubkeyListMatch {
+        /// The list of public keys to be compared against.
+        pubkeys: Vec<Pubkey>,
+        /// The field in the `Payload` to be compared.
+        field: String,
+    },
+    /// Direct comparison between `Pubkey`s.
+    PubkeyMatch {
+        /// The public key to be compared against.
+        #[cfg_attr(feature = "serde-with-feature", serde(with = "As::<DisplayFromStr>"))]
+        pubkey: Pubkey,
+        /// The field in the `Payload` to be compared.
+        field: String,
+    },
+    /// The comparing `Pubkey` must be a member of the Merkle tree in the rule.
+    PubkeyTreeMatch {
+        /// The root of the Merkle tree.
+        root: [u8; 32],
+        /// The field in the `Payload` to be compared when looking for the `Pubkey`.
+        pubkey_field: String,
+        /// The field in the `Payload` to be compared when looking for the Merkle proof.
+        proof_field: String,
+    },
+    /// The royalties paid to the creators in the transaction must cover a share of the sale price.
+    RoyaltyPayment {
+        /// The field in the `Payload` holding the sale price.
+        field: String,
+        /// The mint of the tokens paid.  If `None` then the royalties are paid in lamports.
+        mint: Option<Pubkey>,
+        /// The royalties to be paid, in basis points of the sale price.
+        basis_points: u64,
+        /// The creators the royalties must be paid to.
+        creators: Vec<Pubkey>,
+    },
+    /// The rule of an operation of another `RuleSet` must pass.
+    RuleSetReference {
+        /// The address of the referenced `RuleSet` PDA.
+        #[cfg_attr(feature = "serde-with-feature", serde(with = "As::<DisplayFromStr>"))]
+        rule_set: Pubkey,
+        /// The operation of the referenced `RuleSet`.  If `None` then the operation being
+        /// validated is used.
+        operation: Option<String>,
+        /// The revision of the referenced `RuleSet`.  If `None` then the latest revision is used.
+        revision: Option<usize>,
+    },
+    /// Group threshold, where at least `k` of the rules contained must pass.
+    Threshold {
+        /// The number of rules that must pass.
+        k: u64,
+        /// The vector of rules contained under Threshold.
+        rules: Vec<OwnedRuleV2>,
+    },
+    /// The `Clock` value must be inside the `[start, end)` window.
+    TimeWindow {
+        /// The `Clock` value to compare.
+        source: TimeSource,
+        /// Start of the window (inclusive).
+        start: i64,
+        /// End of the window (exclusive).
+        end: i64,
+    },
+    /// The state of a token account must pass every check.
+    TokenAccount {
+        /// The field in the `Payload` to be compared.
+        field: String,
+        /// The checks to assert against the token account.
+        checks: Vec<TokenAccountCheck>,
+    },
+    /// A token account owned by the `Pubkey` must hold an amount of tokens of the mint.
+    TokenGate {
+        /// The field in the `Payload` holding the token account.
+        field: String,
+        /// The field in the `Payload` holding the owner of the token account.
+        owner_field: String,
+        /// The mint of the tokens.
+        #[cfg_attr(feature = "serde-with-feature", serde(with = "As::<DisplayFromStr>"))]
+        mint: Pubkey,
+        /// The operator to be used in the comparison.
+        operator: Operator,
+        /// The amount to be compared against.
+        amount: u64,
+    },
+}
+
+impl OwnedRuleV2 {
+    /// Deserialize a rule tree from a byte array in the `RuleV2` format.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RuleSetError> {
+        let header = try_from_bytes::<Header>(0, HEADER_SECTION, bytes)?;
+        let constraint_type = ConstraintType::try_from(header.data[0])?;
+
+        let end = HEADER_SECTION
+            .checked_add(header.length())
+            .ok_or(RuleSetError::NumericalOverflow)?;
+        if end > bytes.len() {
+            return Err(RuleSetError::RuleSetReadFailed);
+        }
+        let data = &bytes[HEADER_SECTION..end];
+
+        let rule = match constraint_type {
+            ConstraintType::Uninitialized => return Err(RuleSetError::InvalidConstraintType),
+            ConstraintType::AccountData => {
+                let constraint = AccountData::from_bytes(data)?;
+                OwnedRuleV2::AccountData {
+                    field: constraint.field.to_string(),
+                    offset: *constraint.offset,
+                    operator: Operator::try_from(*constraint.operator)?,
+                    expected: constraint.expected.to_vec(),
+                    mask: constraint.mask.map(|mask| mask.to_vec()),
+                }
+            }
+            ConstraintType::AdditionalSigner => {
+                let constraint = AdditionalSigner::from_bytes(data)?;
+                OwnedRuleV2::AdditionalSigner {
+                    account: *constraint.account,
+                }
+            }
+            ConstraintType::AdditionalSignerList => {
+                let constraint = AdditionalSignerList::from_bytes(data)?;
+                OwnedRuleV2::AdditionalSignerList {
+                    accounts: constraint.accounts.to_vec(),
+                    threshold: *constraint.threshold,
+                }
+            }
+            ConstraintType::All => OwnedRuleV2::All {
+                rules: rules_from_bytes(data)?,
+            },
+            ConstraintType::Amount => {
+                let constraint = Amount::from_bytes(data)?;
+                OwnedRuleV2::Amount {
+                    field: constraint.field.to_string(),
+                    operator: Operator::try_from(*constraint.operator)?,
+                    amount: *constraint.amount,
+                }
+            }
+            ConstraintType::AmountRange => {
+                let constraint = AmountRange::from_bytes(data)?;
+                OwnedRuleV2::AmountRange {
+                    field: constraint.field.to_string(),
+                    min: *constraint.min,
+                    max: *constraint.max,
+                }
+            }
+            ConstraintType::Any => OwnedRuleV2::Any {
+                rules: rules_from_bytes(data)?,
+            },
+            ConstraintType::Ed25519Attestation => {
+                let constraint = Ed25519Attestation::from_bytes(data)?;
+                OwnedRuleV2::Ed25519Attestation {
+                    field: constraint.field.to_string(),
+                    authority: *constraint.authority,
+                }
+            }
+            ConstraintType::FieldCompare => {
+                let constraint = FieldCompare::from_bytes(data)?;
+                OwnedRuleV2::FieldCompare {
+                    field: constraint.field.to_string(),
+                    operator: Operator::try_from(*constraint.operator)?,
+                    other_field: constraint.other_field.to_string(),
+                }
+            }
+            ConstraintType::Frequency => {
+                let constraint = Frequency::from_bytes(data)?;
+                OwnedRuleV2::Frequency {
+                    authority: *constraint.authority,
+                    period: *constraint.period,
+                }
+            }
+            ConstraintType::InstructionMatch => {
+                let constraint = InstructionMatch::from_bytes(data)?;
+                let account_field = constraint.account_field.to_string();
+                OwnedRuleV2::InstructionMatch {
+                    program: *constraint.program,
+                    discriminator: constraint.discriminator.to_vec(),
+                    account_field: (!account_field.is_empty()).then_some(account_field),
+                    account_index: *constraint.account_index,
+                }
+            }
+            ConstraintType::IsWallet => {
+                let constraint = IsWallet::from_bytes(data)?;
+                OwnedRuleV2::IsWallet {
+                    field: constraint.field.to_string(),
+                }
+            }
+            ConstraintType::MintListMatch => {
+                let constraint = MintListMatch::from_bytes(data)?;
+                OwnedRuleV2::MintListMatch {
+                    mints: constraint.mints.to_vec(),
+                }
+            }
+            ConstraintType::MintTreeMatch => {
+                let constraint = MintTreeMatch::from_bytes(data)?;
+                OwnedRuleV2::MintTreeMatch {
+                    root: *constraint.root,
+                    proof_field: constraint.proof_field.to_string(),
+                }
+            }
+            ConstraintType::Namespace => OwnedRuleV2::Namespace,
+            ConstraintType::Not => OwnedRuleV2::Not {
+                rule: Box::new(OwnedRuleV2::from_bytes(data)?),
+            },
+            ConstraintType::Pass => OwnedRuleV2::Pass,
+            ConstraintType::PDAMatch => {
+                let constraint = PDAMatch::from_bytes(data)?;
+                OwnedRuleV2::PDAMatch {
+                    // A zeroed (default) program means the account owner is used.
+                    program: if *constraint.program == Pubkey::default() {
+                        None
+                    } else {
+                        Some(*constraint.program)
+                    },
+                    pda_field: constraint.pda_field.to_string(),
+                    seeds_field: constraint.seeds_field.to_string(),
+                }
+            }
+            ConstraintType::ProgramOwned => {
+                let constraint = ProgramOwned::from_bytes(data)?;
+                OwnedRuleV2::ProgramOwned {
+                    program: *constraint.program,
+                    field: constraint.field.to_string(),
+                }
+            }
+            ConstraintType::ProgramOwnedList => {
+                let constraint = ProgramOwnedList::from_bytes(data)?;
+                OwnedRuleV2::ProgramOwnedList {
+                    programs: constraint.programs.to_vec(),
+                    field: constraint.field.to_string(),
+                }
+            }
+            ConstraintType::ProgramOwnedSet => {
+                let constraint = ProgramOwnedSet::from_bytes(data)?;
+                OwnedRuleV2::ProgramOwnedSet {
+                    programs: constraint.programs.to_vec(),
+                    field: constraint.field.to_string(),
+                }
+            }
+            ConstraintType::ProgramOwnedTree => {
+                let constraint = ProgramOwnedTree::from_bytes(data)?;
+                OwnedRuleV2::ProgramOwnedTree {
+                    root: *constraint.root,
+                    pubkey_field: constraint.pubkey_field.to_string(),
+                    proof_field: constraint.proof_field.to_string(),
+                }
+            }
+            ConstraintType::PubkeyListMatch => {
+                let constraint = PubkeyListMatch::from_bytes(data)?;
+                OwnedRuleV2::PubkeyListMatch {
+                    pubkeys: constraint.pubkeys.to_vec(),
+                    field: constraint.field.to_string(),
+                }
+            }
+            ConstraintType::PubkeyMatch => {
+                let constraint = PubkeyMatch::from_bytes(data)?;
+                OwnedRuleV2::PubkeyMatch {
+                    pubkey: *constraint.pubkey,
+                    field: constraint.field.to_string(),
+                }
+            }
+            ConstraintType::PubkeyTreeMatch => {
+                let constraint = PubkeyTreeMatch::from_bytes(data)?;
+                OwnedRuleV2::PubkeyTreeMatch {
+                    root: *constraint.root,
+                    pubkey_field: constraint.pubkey_field.to_string(),
+                    proof_field: constraint.proof_field.to_string(),
+                }
+            }
+            ConstraintType::RoyaltyPayment => {
+                let constraint = RoyaltyPayment::from_bytes(data)?;
+                OwnedRuleV2::RoyaltyPayment {
+                    field: constraint.field.to_string(),
+                    // A zeroed (default) mint means the royalties are paid in lamports.
+                    mint: if *constraint.mint == Pubkey::default() {
+                        None
+                    } else {
+                        Some(*constraint.mint)
+                    },
+                    basis_points: *constraint.basis_points,
+                    creators: constraint.creators.to_vec(),
+                }
+            }
+            ConstraintType::RuleSetReference => {
+                let constraint = RuleSetReference::from_bytes(data)?;
+                OwnedRuleV2::RuleSetReference {
+                    rule_set: *constraint.rule_set,
+                    // An empty operation means the operation being validated is used.
+                    operation: match constraint.operation.to_string() {
+                        operation if operation.is_empty() => None,
+                        operation => Some(operation),
+                    },
+                    // A `u64::MAX` revision means the latest revision is used.
+                    revision: match *constraint.revision {
+                        u64::MAX => None,
+                        revision => Some(revision as usize),
+                    },
+                }
+            }
+            ConstraintType::Threshold => OwnedRuleV2::Threshold {
+                k: *try_from_bytes::<u64>(0, U64_BYTES, data)?,
+                rules: rules_from_bytes(&data[U64_BYTES..])?,
+            },
+            ConstraintType::TimeWindow => {
+                let constraint = TimeWindow::from_bytes(data)?;
+                OwnedRuleV2::TimeWindow {
+                    source: TimeSource::try_from(*constraint.source)?,
+                    start: *constraint.start,
+                    end: *constraint.end,
+                }
+            }
+            ConstraintType::TokenAccount => {
+                let constraint = TokenAccount::from_bytes(data)?;
+                OwnedRuleV2::TokenAccount {
+                    field: constraint.field.to_string(),
+                    checks: constraint
+                        .checks
+                        .iter()
+                        .map(TokenAccountCheck::try_from)
+                        .collect::<Result<Vec<_>, _>>()?,
+                }
+            }
+            ConstraintType::TokenGate => {
+                let constraint = TokenGate::from_bytes(data)?;
+                OwnedRuleV2::TokenGate {
+                    field: constraint.field.to_string(),
+                    owner_field: constraint.owner_field.to_string(),
+                    mint: *constraint.mint,
+                    operator: Operator::try_from(*constraint.operator)?,
+                    amount: *constraint.amount,
+                }
+            }
+        };
+
+        Ok(rule)
+    }
+
+    /// Serialize the rule tree into the `RuleV2` format.
+    pub fn serialize(&self) -> Result<Vec<u8>, RuleSetError> {
+        match self {
+            OwnedRuleV2::AccountData {
+                field,
+                offset,
+                operator,
+                expected,
+                mask,
+            } => AccountData::serialize(
+                field.clone(),
+                *offset,
+                operator.clone(),
+                expected,
+                mask.as_deref(),
+            ),
+            OwnedRuleV2::AdditionalSigner { account } => AdditionalSigner::serialize(*account),
+            OwnedRuleV2::AdditionalSignerList {
+                accounts,
+                threshold,
+            } => AdditionalSignerList::serialize(*threshold, accounts),
+            OwnedRuleV2::All { rules } => {
+                let rules = serialize_rules(rules)?;
+                All::serialize(&rules.iter().map(|r| r.as_slice()).collect::<Vec<&[u8]>>())
+            }
+            OwnedRuleV2::Amount {
+                field,
+                operator,
+                amount,
+            } => Amount::serialize(field.clone(), operator.clone(), *amount),
+            OwnedRuleV2::AmountRange { field, min, max } => {
+                AmountRange::serialize(field.clone(), *min, *max)
+            }
+            OwnedRuleV2::Any { rules } => {
+                let rules = serialize_rules(rules)?;
+                Any::serialize(&rules.iter().map(|r| r.as_slice()).collect::<Vec<&[u8]>>())
+            }
+            OwnedRuleV2::Ed25519Attestation { field, authority } => {
+                Ed25519Attestation::serialize(field.clone(), *authority)
+            }
+            OwnedRuleV2::FieldCompare {
+                field,
+                operator,
+                other_field,
+            } => FieldCompare::serialize(field.clone(), operator.clone(), other_field.clone()),
+            OwnedRuleV2::Frequency { authority, period } => {
+                Frequency::serialize(*authority, *period)
+            }
+            OwnedRuleV2::InstructionMatch {
+                program,
+                discriminator,
+                account_field,
+                account_index,
+            } => InstructionMatch::serialize(
+                *program,
+                discriminator,
+                account_field.clone(),
+                *account_index,
+            ),
+            OwnedRuleV2::IsWallet { field } => IsWallet::serialize(field.clone()),
+            OwnedRuleV2::MintListMatch { mints } => MintListMatch::serialize(mints),
+            OwnedRuleV2::MintTreeMatch { root, proof_field } => {
+                MintTreeMatch::serialize(proof_field.clone(), root)
+            }
+            OwnedRuleV2::Namespace => Namespace::serialize(),
+            OwnedRuleV2::Not { rule } => Not::serialize(&OwnedRuleV2::serialize(rule)?),
+            OwnedRuleV2::Pass => Pass::serialize(),
+            OwnedRuleV2::PDAMatch {
+                program,
+                pda_field,
+                seeds_field,
+            } => PDAMatch::serialize(pda_field.clone(), *program, seeds_field.clone()),
+            OwnedRuleV2::ProgramOwned { program, field } => {
+                ProgramOwned::serialize(field.clone(), *program)
+            }
+            OwnedRuleV2::ProgramOwnedList { programs, field } => {
+                ProgramOwnedList::serialize(field.clone(), programs)
+            }
+            OwnedRuleV2::ProgramOwnedSet { programs, field } => {
+                ProgramOwnedSet::serialize(field.clone(), programs)
+            }
+            OwnedRuleV2::ProgramOwnedTree {
+                root,
+                pubkey_field,
+                proof_field,
+            } => ProgramOwnedTree::serialize(pubkey_field.clone(), proof_field.clone(), root),
+            OwnedRuleV2::PubkeyListMatch { pubkeys, field } => {
+                PubkeyListMatch::serialize(field.clone(), pubkeys)
+            }
+            OwnedRuleV2::PubkeyMatch { pubkey, field } => {
+                PubkeyMatch::serialize(field.clone(), *pubkey)
+            }
+            OwnedRuleV2::PubkeyTreeMatch {
+                root,
+                pubkey_field,
+                proof_field,
+            } => PubkeyTreeMatch::serialize(pubkey_field.clone(), proof_field.clone(), root),
+            OwnedRuleV2::RoyaltyPayment {
+                field,
+                mint,
+                basis_points,
+                creators,
+            } => RoyaltyPayment::serialize(field.clone(), *mint, *basis_points, creators),
+            OwnedRuleV2::RuleSetReference {
+                rule_set,
+                operation,
+                revision,
+            } => RuleSetReference::serialize(*rule_set, operation.clone(), *revision),
+            OwnedRuleV2::Threshold { k, rules } => {
+                let rules = serialize_rules(rules)?;
+                Threshold::serialize(
+                    *k,
+                    &rules.iter().map(|r| r.as_slice()).collect::<Vec<&[u8]>>(),
+                )
+            }
+            OwnedRuleV2::TimeWindow { source, start, end } => {
+                TimeWindow::serialize(*source, *start, *end)
+            }
+            OwnedRuleV2::TokenAccount { field, checks } => {
+                TokenAccount::serialize(field.clone(), checks)
+            }
+            OwnedRuleV2::TokenGate {
+                field,
+                owner_field,
+                mint,
+                operator,
+                amount,
+            } => TokenGate::serialize(
+                field.clone(),
+                owner_field.clone(),
+                *mint,
+                operator.clone(),
+                *amount,
+            ),
+        }
+    }
+}
+
+/// Deserialize the rules of a group constraint (`All`, `Any` or `Threshold`), stored after the
+/// number of rules.
+fn rules_from_bytes(bytes: &[u8]) -> Result<Vec<OwnedRuleV2>, RuleSetError> {
+    let size = try_from_bytes::<u64>(0, U64_BYTES, bytes)?;
+
+    let mut rules = Vec::with_capacity(*size as usize);
+    let mut offset = U64_BYTES;
+
+    for _ in 0..*size {
+        let header = try_from_bytes::<Header>(offset, HEADER_SECTION, bytes)?;
+        rules.push(OwnedRuleV2::from_bytes(&bytes[offset..])?);
+        offset += HEADER_SECTION + header.length();
+    }
+
+    Ok(rules)
+}
+
+/// Serialize each rule of a list of rules.
+fn serialize_rules(rules: &[OwnedRuleV2]) -> Result<Vec<Vec<u8>>, RuleSetError> {
+    rules.iter().map(|rule| rule.serialize()).collect()
+}
+
+impl From<CompareOp> for Operator {
+    fn from(operator: CompareOp) -> Self {
+        match operator {
+            CompareOp::Lt => Operator::Lt,
+            CompareOp::LtEq => Operator::LtEq,
+            CompareOp::Eq => Operator::Eq,
+            CompareOp::GtEq => Operator::GtEq,
+            CompareOp::Gt => Operator::Gt,
+            CompareOp::Ne => Operator::Ne,
+            CompareOp::MultipleOf => Operator::MultipleOf,
+        }
+    }
+}
+
+impl From<Rule> for OwnedRuleV2 {
+    fn from(rule: Rule) -> Self {
+        match rule {
+            Rule::All { rules } => OwnedRuleV2::All {
+                rules: rules.into_iter().map(OwnedRuleV2::from).collect(),
+            },
+            Rule::Any { rules } => OwnedRuleV2::Any {
+                rules: rules.into_iter().map(OwnedRuleV2::from).collect(),
+            },
+            Rule::Not { rule } => OwnedRuleV2::Not {
+                rule: Box::new(OwnedRuleV2::from(*rule)),
+            },
+            Rule::AdditionalSigner { account } => OwnedRuleV2::AdditionalSigner { account },
+            Rule::AdditionalSignerList {
+                accounts,
+                threshold,
+            } => OwnedRuleV2::AdditionalSignerList {
+                accounts,
+                threshold,
+            },
+            Rule::PubkeyMatch { pubkey, field } => OwnedRuleV2::PubkeyMatch { pubkey, field },
+            Rule::PubkeyListMatch { pubkeys, field } => {
+                OwnedRuleV2::PubkeyListMatch { pubkeys, field }
+            }
+            Rule::PubkeyTreeMatch {
+                root,
+                pubkey_field,
+                proof_field,
+            } => OwnedRuleV2::PubkeyTreeMatch {
+                root,
+                pubkey_field,
+                proof_field,
+            },
+            Rule::PDAMatch {
+                program,
+                pda_field,
+                seeds_field,
+            } => OwnedRuleV2::PDAMatch {
+                program,
+                pda_field,
+                seeds_field,
+            },
+            Rule::ProgramOwned { program, field } => OwnedRuleV2::ProgramOwned { program, field },
+            Rule::ProgramOwnedList { programs, field } => {
+                OwnedRuleV2::ProgramOwnedList { programs, field }
+            }
+            Rule::ProgramOwnedTree {
+                root,
+                pubkey_field,
+                proof_field,
+            } => OwnedRuleV2::ProgramOwnedTree {
+                root,
+                pubkey_field,
+                proof_field,
+            },
+            Rule::Amount {
+                amount,
+                operator,
+                field,
+            } => OwnedRuleV2::Amount {
+                field,
+                operator: operator.into(),
+                amount,
+            },
+            Rule::AmountRange { min, max, field } => OwnedRuleV2::AmountRange { field, min, max },
+            Rule::Frequency { authority, period } => OwnedRuleV2::Frequency { authority, period },
+            Rule::IsWallet { field } => OwnedRuleV2::IsWallet { field },
+            Rule::Pass => OwnedRuleV2::Pass,
+            // Sorted to keep the value deterministic, matching the serialized layout.
+            Rule::ProgramOwnedSet { programs, field } => {
+                let mut programs = programs.into_iter().collect::<Vec<Pubkey>>();
+                programs.sort();
+                OwnedRuleV2::ProgramOwnedSet { programs, field }
+            }
+            Rule::Namespace => OwnedRuleV2::Namespace,
+            Rule::TimeWindow { source, start, end } => {
+                OwnedRuleV2::TimeWindow { source, start, end }
+            }
+            Rule::FieldCompare {
+                field,
+                operator,
+                other_field,
+            } => OwnedRuleV2::FieldCompare {
+                field,
+                operator: operator.into(),
+                other_field,
+            },
+            Rule::Threshold { k, rules } => OwnedRuleV2::Threshold {
+                k,
+                rules: rules.into_iter().map(OwnedRuleV2::from).collect(),
+            },
+            Rule::MintListMatch { mints } => OwnedRuleV2::MintListMatch { mints },
+            Rule::MintTreeMatch { root, proof_field } => {
+                OwnedRuleV2::MintTreeMatch { root, proof_field }
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+/// Owned representation of a `RuleSetV2`, with a list of operations and their `OwnedRuleV2`
+/// trees.
+pub struct OwnedRuleSetV2 {
+    /// Owner (creator) of the RuleSet.
+    #[cfg_attr(feature = "serde-with-feature", serde(with = "As::<DisplayFromStr>"))]
+    pub owner: Pubkey,
+    /// Name of the RuleSet, used in PDA derivation.
+    pub rule_set_name: String,
+    /// The rule that belongs to each `Operation`, in the order they are serialized.  Operation
+    /// names are unique.
+    pub operations: Vec<(String, OwnedRuleV2)>,
+}
+
+impl OwnedRuleSetV2 {
+    /// Create a new empty `RuleSet`.
+    pub fn new(rule_set_name: String, owner: Pubkey) -> Self {
+        Self {
+            owner,
+            rule_set_name,
+            operations: Vec::new(),
+        }
+    }
+
+    /// Deserialize a `RuleSet` from a byte array in the `RuleSetV2` format, keeping the order of
+    /// its operations.  Repeated operations are an error.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, RuleSetError> {
+        let rule_set = RuleSetV2::from_bytes(bytes)?;
+
+        // Rules are stored after the header, owner, name and operations.
+        let mut cursor = U64_BYTES
+            + PUBKEY_BYTES
+            + Str32::SIZE
+            + rule_set
+                .operations
+                .len()
+                .checked_mul(Str32::SIZE)
+                .ok_or(RuleSetError::NumericalOverflow)?;
+
+        let mut owned = Self::new(rule_set.name(), *rule_set.owner);
+
+        for (operation, rule) in rule_set.operations.iter().zip(rule_set.rules.iter()) {
+            owned
+                .add(
+                    operation.to_string(),
+                    OwnedRuleV2::from_bytes(&bytes[cursor..])?,
+                )
+                .map_err(|_| RuleSetError::DuplicatedOperationName)?;
+            cursor += rule.length();
+        }
+
+        Ok(owned)
+    }
+
+    /// Deserialize a revision of a `RuleSet` stored in the data of a `RuleSet` PDA.  If
+    /// `rule_set_revision` is `None`, the latest revision is used.  A `RuleSetV1` revision is
+    /// converted into its `RuleSetV2` representation.
+    pub fn from_rule_set_account(
+        data: &[u8],
+        rule_set_revision: Option<usize>,
+    ) -> Result<Self, ProgramError> {
+        match get_rule_set_revision_data(data, rule_set_revision)? {
+            (LibVersion::V1, serialized_rule_set) => {
+                let rule_set = rmp_serde::from_slice::<RuleSetV1>(serialized_rule_set)
+                    .map_err(|_| RuleSetError::MessagePackDeserializationError)?;
+                Ok(rule_set.into())
+            }
+            (LibVersion::V2, serialized_rule_set) => {
+                Ok(OwnedRuleSetV2::from_bytes(serialized_rule_set)?)
+            }
+        }
+    }
+
+    /// Add a key-value pair at the end of a `RuleSet`.  If this key is already in the `RuleSet`
+    /// nothing is updated and an error is returned.
+    pub fn add(&mut self, operation: String, rule: OwnedRuleV2) -> ProgramResult {
+        if self.get(operation.clone()).is_some() {
+            return Err(RuleSetError::ValueOccupied.into());
+        }
+
+        self.operations.push((operation, rule));
+        Ok(())
+    }
+
+    /// Retrieve the rule tree for a given `Operation`.
+    pub fn get(&self, operation: String) -> Option<&OwnedRuleV2> {
+        self.operations
+            .iter()
+            .find(|(name, _)| *name == operation)
+            .map(|(_, rule)| rule)
+    }
+
+    /// Serialize the `RuleSet` into the `RuleSetV2` format.  Operations are serialized in the
+    /// order they were added.
+    pub fn serialize(&self) -> Result<Vec<u8>, RuleSetError> {
+        let operations = self
+            .operations
+            .iter()
+            .map(|(operation, _)| operation.clone())
+            .collect::<Vec<String>>();
+        let rules = self
+            .operations
+            .iter()
+            .map(|(_, rule)| rule.serialize())
+            .collect::<Result<Vec<Vec<u8>>, RuleSetError>>()?;
+
+        RuleSetV2::serialize(
+            self.owner,
+            &self.rule_set_name,
+            &operations,
+            &rules.iter().map(|r| r.as_slice()).collect::<Vec<&[u8]>>(),
+        )
+    }
+}
+
+impl From<RuleSetV1> for OwnedRuleSetV2 {
+    /// Operations of a `RuleSetV1` are not ordered, so they are converted in lexicographic order.
+    fn from(rule_set: RuleSetV1) -> Self {
+        let owner = *rule_set.owner();
+        let rule_set_name = rule_set.name();
+
+        let mut operations = rule_set
+            .operations
+            .into_iter()
+            .map(|(operation, rule)| (operation, OwnedRuleV2::from(rule)))
+            .collect::<Vec<(String, OwnedRuleV2)>>();
+        operations.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Self {
+            owner,
+            rule_set_name,
+            operations,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OwnedRuleSetV2, OwnedRuleV2};
+    use crate::{
+        error::RuleSetError,
+        state::{
+            v2::{Amount, Any, Operator, ProgramOwnedList, RuleSetV2, TokenAccountCheck},
+            CompareOp, Rule, RuleSetV1, TimeSource,
+        },
+        types::RuleSet,
+    };
+    use solana_program::pubkey::Pubkey;
+
+    #[test]
+    fn test_serialize_matches_constraints() {
+        let owner = Pubkey::new_unique();
+        let programs = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+        let mut rule_set = OwnedRuleSetV2::new("test rule_set".to_string(), owner);
+        rule_set
+            .add(
+                "transfer".to_string(),
+                OwnedRuleV2::Any {
+                    rules: vec![
+                        OwnedRuleV2::Amount {
+                            field: "Amount".to_string(),
+                            operator: Operator::Lt,
+                            amount: 5,
+                        },
+                        OwnedRuleV2::ProgramOwnedList {
+                            programs: programs.clone(),
+                            field: "Destination".to_string(),
+                        },
+                    ],
+                },
+            )
+            .unwrap();
+
+        // Adding the same operation twice fails.
+        assert!(rule_set
+            .add("transfer".to_string(), OwnedRuleV2::Pass)
+            .is_err());
+
+        // Build the same `RuleSet` with the constraint serializers.
+        let amount = Amount::serialize("Amount".to_string(), Operator::Lt, 5).unwrap();
+        let program_owned =
+            ProgramOwnedList::serialize("Destination".to_string(), &programs).unwrap();
+        let any = Any::serialize(&[&amount, &program_owned]).unwrap();
+        let expected =
+            RuleSetV2::serialize(owner, "test rule_set", &["transfer".to_string()], &[&any])
+                .unwrap();
+
+        assert_eq!(rule_set.serialize().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_from_rule_set_v1() {
+        let owner = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+
+        let mut rule_set = RuleSetV1::new("test rule_set".to_string(), owner);
+        rule_set
+            .add(
+                "transfer".to_string(),
+                Rule::All {
+                    rules: vec![
+                        Rule::AdditionalSigner { account: signer },
+                        Rule::Not {
+                            rule: Box::new(Rule::Amount {
+                                amount: 10,
+                                operator: CompareOp::GtEq,
+                                field: "Amount".to_string(),
+                            }),
+                        },
+                    ],
+                },
+            )
+            .unwrap();
+        rule_set
+            .add("transfer:owner".to_string(), Rule::Namespace)
+            .unwrap();
+
+        let owned = OwnedRuleSetV2::from(rule_set);
+
+        assert_eq!(owned.owner, owner);
+        assert_eq!(owned.rule_set_name, "test rule_set");
+        assert_eq!(
+            owned.get("transfer".to_string()),
+            Some(&OwnedRuleV2::All {
+                rules: vec![
+                    OwnedRuleV2::AdditionalSigner { account: signer },
+                    OwnedRuleV2::Not {
+                        rule: Box::new(OwnedRuleV2::Amount {
+                            field: "Amount".to_string(),
+                            operator: Operator::GtEq,
+                            amount: 10,
+                        }),
+                    },
+                ],
+            })
+        );
+
+        // The serialized `RuleSetV2` resolves the namespace fallback.
+        let serialized = owned.serialize().unwrap();
+        let rule_set = RuleSetV2::from_bytes(&serialized).unwrap();
+        assert_eq!(rule_set.name(), "test rule_set");
+        assert!(rule_set.get_rule("transfer:owner".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_from_bytes_keeps_operation_order() {
+        let amount = Amount::serialize("Amount".to_string(), Operator::Lt, 5).unwrap();
+        let programs = ProgramOwnedList::serialize(
+            "Destination".to_string(),
+            &[Pubkey::new_unique(), Pubkey::new_unique()],
+        )
+        .unwrap();
+        let any = Any::serialize(&[&amount, &programs]).unwrap();
+
+        // Operations that are not in lexicographic order.
+        let serialized = RuleSetV2::serialize(
+            Pubkey::new_unique(),
+            "test rule_set",
+            &[
+                "transfer".to_string(),
+                "delegate".to_string(),
+                "burn".to_string(),
+            ],
+            &[&any, &amount, &programs],
+        )
+        .unwrap();
+
+        let owned = OwnedRuleSetV2::from_bytes(&serialized).unwrap();
+        assert_eq!(
+            owned
+                .operations
+                .iter()
+                .map(|(operation, _)| operation.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["transfer", "delegate", "burn"]
+        );
+        assert_eq!(owned.serialize().unwrap(), serialized);
+
+        // `RuleSetV2::serialize` rejects repeated operations, so rename the last one.
+        let mut serialized = serialized;
+        let index = serialized
+            .windows(4)
+            .position(|window| window == b"burn")
+            .unwrap();
+        serialized[index..index + 8].copy_from_slice(b"delegate");
+
+        assert_eq!(
+            OwnedRuleSetV2::from_bytes(&serialized),
+            Err(RuleSetError::DuplicatedOperationName)
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // `ProgramOwnedSet` programs are stored sorted.
+        let mut programs = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        programs.sort();
+
+        let mut rule_set = OwnedRuleSetV2::new("test rule_set".to_string(), Pubkey::new_unique());
+        rule_set
+            .add(
+                "transfer".to_string(),
+                OwnedRuleV2::All {
+                    rules: vec![
+                        OwnedRuleV2::AdditionalSigner {
+                            account: Pubkey::new_unique(),
+                        },
+                        OwnedRuleV2::Any {
+                            rules: vec![
+                                OwnedRuleV2::Amount {
+                                    field: "Amount".to_string(),
+                                    operator: Operator::GtEq,
+                                    amount: 2,
+                                },
+                                OwnedRuleV2::AmountRange {
+                                    field: "Amount".to_string(),
+                                    min: 10,
+                                    max: 100,
+                                },
+                                OwnedRuleV2::Amount {
+                                    field: "Amount".to_string(),
+                                    operator: Operator::MultipleOf,
+                                    amount: 10,
+                                },
+                                OwnedRuleV2::IsWallet {
+                                    field: "Destination".to_string(),
+                                },
+                                OwnedRuleV2::MintListMatch {
+                                    mints: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+                                },
+                                OwnedRuleV2::MintTreeMatch {
+                                    root: [7; 32],
+                                    proof_field: "MintProof".to_string(),
+                                },
+                                OwnedRuleV2::PDAMatch {
+                                    program: None,
+                                    pda_field: "Destination".to_string(),
+                                    seeds_field: "DestinationSeeds".to_string(),
+                                },
+                                OwnedRuleV2::PDAMatch {
+                                    program: Some(Pubkey::new_unique()),
+                                    pda_field: "Destination".to_string(),
+                                    seeds_field: "DestinationSeeds".to_string(),
+                                },
+                            ],
+                        },
+                        OwnedRuleV2::Not {
+                            rule: Box::new(OwnedRuleV2::ProgramOwnedTree {
+                                root: [7; 32],
+                                pubkey_field: "Destination".to_string(),
+                                proof_field: "DestinationProof".to_string(),
+                            }),
+                        },
+                        OwnedRuleV2::Frequency {
+                            authority: Pubkey::new_unique(),
+                            period: 60,
+                        },
+                        OwnedRuleV2::InstructionMatch {
+                            program: Pubkey::new_unique(),
+                            discriminator: vec![1, 2, 3],
+                            account_field: Some("Destination".to_string()),
+                            account_index: 2,
+                        },
+                        OwnedRuleV2::InstructionMatch {
+                            program: Pubkey::new_unique(),
+                            discriminator: vec![],
+                            account_field: None,
+                            account_index: 0,
+                        },
+                        OwnedRuleV2::TimeWindow {
+                            source: TimeSource::Slot,
+                            start: 10,
+                            end: 20,
+                        },
+                        OwnedRuleV2::AccountData {
+                            field: "Destination".to_string(),
+                            offset: 44,
+                            operator: Operator::Eq,
+                            expected: vec![1, 2, 3],
+                            mask: Some(vec![0xff, 0x0f, 0xff]),
+                        },
+                        OwnedRuleV2::AccountData {
+                            field: "Destination".to_string(),
+                            offset: 0,
+                            operator: Operator::Gt,
+                            expected: vec![9; 8],
+                            mask: None,
+                        },
+                        OwnedRuleV2::TokenAccount {
+                            field: "Destination".to_string(),
+                            checks: vec![
+                                TokenAccountCheck::Owner {
+                                    pubkey: Pubkey::new_unique(),
+                                },
+                                TokenAccountCheck::OwnerField {
+                                    field: "Authority".to_string(),
+                                },
+                                TokenAccountCheck::Mint {
+                                    pubkey: Pubkey::new_unique(),
+                                },
+                                TokenAccountCheck::MintField {
+                                    field: "Mint".to_string(),
+                                },
+                                TokenAccountCheck::Delegate {
+                                    pubkey: Pubkey::new_unique(),
+                                },
+                                TokenAccountCheck::DelegateField {
+                                    field: "Delegate".to_string(),
+                                },
+                                TokenAccountCheck::NoDelegate,
+                                TokenAccountCheck::Frozen { frozen: true },
+                            ],
+                        },
+                        OwnedRuleV2::Ed25519Attestation {
+                            field: "Destination".to_string(),
+                            authority: Pubkey::new_unique(),
+                        },
+                        OwnedRuleV2::FieldCompare {
+                            field: "Amount".to_string(),
+                            operator: Operator::Ne,
+                            other_field: "MaxAmount".to_string(),
+                        },
+                        OwnedRuleV2::TokenGate {
+                            field: "Holder".to_string(),
+                            owner_field: "Delegate".to_string(),
+                            mint: Pubkey::new_unique(),
+                            operator: Operator::GtEq,
+                            amount: 1,
+                        },
+                        OwnedRuleV2::Threshold {
+                            k: 2,
+                            rules: vec![
+                                OwnedRuleV2::AdditionalSigner {
+                                    account: Pubkey::new_unique(),
+                                },
+                                OwnedRuleV2::AdditionalSigner {
+                                    account: Pubkey::new_unique(),
+                                },
+                                OwnedRuleV2::Pass,
+                            ],
+                        },
+                        OwnedRuleV2::AdditionalSignerList {
+                            accounts: vec![
+                                Pubkey::new_unique(),
+                                Pubkey::new_unique(),
+                                Pubkey::new_unique(),
+                            ],
+                            threshold: 2,
+                        },
+                    ],
+                },
+            )
+            .unwrap();
+        rule_set
+            .add(
+                "delegate".to_string(),
+                OwnedRuleV2::Any {
+                    rules: vec![
+                        OwnedRuleV2::ProgramOwned {
+                            program: Pubkey::new_unique(),
+                            field: "Delegate".to_string(),
+                        },
+                        OwnedRuleV2::ProgramOwnedList {
+                            programs: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+                            field: "Delegate".to_string(),
+                        },
+                        OwnedRuleV2::ProgramOwnedSet {
+                            programs,
+                            field: "Delegate".to_string(),
+                        },
+                        OwnedRuleV2::PubkeyListMatch {
+                            pubkeys: vec![Pubkey::new_unique()],
+                            field: "Delegate".to_string(),
+                        },
+                        OwnedRuleV2::PubkeyMatch {
+                            pubkey: Pubkey::new_unique(),
+                            field: "Delegate".to_string(),
+                        },
+                        OwnedRuleV2::PubkeyTreeMatch {
+                            root: [9; 32],
+                            pubkey_field: "Delegate".to_string(),
+                            proof_field: "DelegateProof".to_string(),
+                        },
+                        OwnedRuleV2::RoyaltyPayment {
+                            field: "Amount".to_string(),
+                            mint: None,
+                            basis_points: 500,
+                            creators: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+                        },
+                        OwnedRuleV2::RoyaltyPayment {
+                            field: "Amount".to_string(),
+                            mint: Some(Pubkey::new_unique()),
+                            basis_points: 250,
+                            creators: vec![Pubkey::new_unique()],
+                        },
+                        OwnedRuleV2::RuleSetReference {
+                            rule_set: Pubkey::new_unique(),
+                            operation: None,
+                            revision: None,
+                        },
+                        OwnedRuleV2::RuleSetReference {
+                            rule_set: Pubkey::new_unique(),
+                            operation: Some("transfer".to_string()),
+                            revision: Some(2),
+                        },
+                    ],
+                },
+            )
+            .unwrap();
+        rule_set
+            .add("delegate:sale".to_string(), OwnedRuleV2::Namespace)
+            .unwrap();
+        rule_set.add("burn".to_string(), OwnedRuleV2::Pass).unwrap();
+
+        // Bytes round trip.
+        let serialized = rule_set.serialize().unwrap();
+        let decoded = OwnedRuleSetV2::from_bytes(&serialized).unwrap();
+        assert_eq!(decoded, rule_set);
+        assert_eq!(decoded.serialize().unwrap(), serialized);
+
+        // JSON round trip.
+        let json = serde_json::to_string(&decoded).unwrap();
+        let deserialized: OwnedRuleSetV2 = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, rule_set);
+    }
+}
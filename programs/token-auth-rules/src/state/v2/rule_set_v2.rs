@@ -8,7 +8,7 @@ use solana_program::{
 use super::{try_cast_slice, try_from_bytes, Constraint, ConstraintType, RuleV2, Str32, U64_BYTES};
 use crate::{
     error::RuleSetError,
-    types::{Assertable, LibVersion, RuleSet},
+    types::{Assertable, LibVersion, RuleSet, MAX_NAME_LENGTH},
 };
 
 // Length of a empty array.
@@ -76,7 +76,7 @@ impl<'a> RuleSetV2<'a> {
         let mut rules = Vec::with_capacity(size);
 
         for _ in 0..size {
-            let rule = RuleV2::from_bytes(&bytes[cursor..]).unwrap();
+            let rule = RuleV2::from_bytes(&bytes[cursor..])?;
             cursor += rule.length();
             rules.push(rule);
         }
@@ -120,9 +120,10 @@ impl<'a> RuleSetV2<'a> {
         data.extend(owner.as_ref());
 
         // name
-        let mut field_bytes = [0u8; Str32::SIZE];
-        field_bytes[..name.len()].copy_from_slice(name.as_bytes());
-        data.extend(field_bytes);
+        if name.len() > MAX_NAME_LENGTH {
+            return Err(RuleSetError::NameTooLong);
+        }
+        data.extend(Str32::serialize(name)?);
 
         // operations
 
@@ -131,11 +132,9 @@ impl<'a> RuleSetV2<'a> {
             return Err(RuleSetError::DuplicatedOperationName);
         }
 
-        operations.iter().for_each(|x| {
-            let mut field_bytes = [0u8; Str32::SIZE];
-            field_bytes[..x.len()].copy_from_slice(x.as_bytes());
-            data.extend(field_bytes);
-        });
+        for operation in operations {
+            data.extend(Str32::serialize(operation)?);
+        }
 
         // rules
         rules.iter().for_each(|x| data.extend(x.iter()));
@@ -145,8 +144,8 @@ impl<'a> RuleSetV2<'a> {
 
     /// Retrieve the `Rule` tree for a given `Operation`.
     pub fn get(&self, operation: String) -> Option<&RuleV2<'a>> {
-        let mut bytes = [0u8; Str32::SIZE];
-        bytes[..operation.len()].copy_from_slice(operation.as_bytes());
+        // Operations longer than a `Str32` cannot be stored in the `RuleSet`.
+        let bytes = Str32::serialize(&operation).ok()?;
 
         for (i, operation) in self.operations.iter().enumerate() {
             if sol_memcmp(&operation.value, &bytes, bytes.len()) == 0 {
@@ -156,23 +155,10 @@ impl<'a> RuleSetV2<'a> {
 
         None
     }
-}
-
-impl<'a> RuleSet<'a> for RuleSetV2<'a> {
-    fn name(&self) -> String {
-        self.rule_set_name.to_string()
-    }
 
-    fn owner(&self) -> &Pubkey {
-        self.owner
-    }
-
-    fn lib_version(&self) -> u8 {
-        (self.header[0] & 0x000000ff) as u8
-    }
-
-    /// This function returns the rule for an operation by recursively searching through fallbacks
-    fn get_rule(&self, operation: String) -> Result<&dyn Assertable<'a>, ProgramError> {
+    /// Retrieve the `Rule` tree for a given `Operation` by recursively searching through
+    /// namespace fallbacks.
+    pub fn resolve_rule(&self, operation: String) -> Result<&RuleV2<'a>, ProgramError> {
         let rule = self.get(operation.to_string());
 
         match rule {
@@ -184,7 +170,7 @@ impl<'a> RuleSet<'a> for RuleSetV2<'a> {
                         // If it doesn't exist then fail.
                         let split = operation.split(':').collect::<Vec<&str>>();
                         if split.len() > 1 {
-                            self.get_rule(split[0].to_owned())
+                            self.resolve_rule(split[0].to_owned())
                         } else {
                             Err(RuleSetError::OperationNotFound.into())
                         }
@@ -197,14 +183,33 @@ impl<'a> RuleSet<'a> for RuleSetV2<'a> {
     }
 }
 
+impl<'a> RuleSet<'a> for RuleSetV2<'a> {
+    fn name(&self) -> String {
+        self.rule_set_name.to_string()
+    }
+
+    fn owner(&self) -> &Pubkey {
+        self.owner
+    }
+
+    fn lib_version(&self) -> u8 {
+        (self.header[0] & 0x000000ff) as u8
+    }
+
+    /// This function returns the rule for an operation by recursively searching through fallbacks
+    fn get_rule(&self, operation: String) -> Result<&dyn Assertable<'a>, ProgramError> {
+        Ok(self.resolve_rule(operation)?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         error::RuleSetError,
-        state::v2::{Amount, Operator, ProgramOwnedList, RuleSetV2},
+        state::v2::{Amount, Operator, ProgramOwnedList, ProgramOwnedSet, RuleSetV2},
         types::{LibVersion, RuleSet},
     };
-    use solana_program::pubkey::Pubkey;
+    use solana_program::pubkey::{Pubkey, PUBKEY_BYTES};
 
     #[test]
     fn test_create_amount() {
@@ -260,4 +265,44 @@ mod tests {
 
         assert_eq!(error, RuleSetError::DuplicatedOperationName);
     }
+
+    #[test]
+    fn test_unsorted_program_owned_set() {
+        let mut programs = [Pubkey::new_unique(), Pubkey::new_unique()];
+        programs.sort();
+
+        // program owned set rule
+        let program_owned =
+            ProgramOwnedSet::serialize(String::from("Destination"), &programs).unwrap();
+
+        let mut serialized = RuleSetV2::serialize(
+            Pubkey::default(),
+            "Royalties",
+            &["transfer".to_string()],
+            &[&program_owned],
+        )
+        .unwrap();
+
+        // swaps the programs in the serialized rule set
+
+        let offset = serialized.len() - 2 * PUBKEY_BYTES;
+        serialized[offset..offset + PUBKEY_BYTES].copy_from_slice(programs[1].as_ref());
+        serialized[offset + PUBKEY_BYTES..].copy_from_slice(programs[0].as_ref());
+
+        // asserts that we got the expected error
+
+        assert_eq!(
+            RuleSetV2::from_bytes(&serialized).err(),
+            Some(RuleSetError::UnsortedProgramSet)
+        );
+
+        // duplicates the program in the serialized rule set
+
+        serialized[offset + PUBKEY_BYTES..].copy_from_slice(programs[1].as_ref());
+
+        assert_eq!(
+            RuleSetV2::from_bytes(&serialized).err(),
+            Some(RuleSetError::UnsortedProgramSet)
+        );
+    }
 }
@@ -6,7 +6,7 @@ use crate::{
     error::RuleSetError,
     payload::Payload,
     state::{constraint::*, Constraint, ConstraintType, RuleResult, U64_BYTES},
-    types::Assertable,
+    types::{Assertable, ValidationContext},
 };
 
 use super::try_from_bytes;
@@ -50,22 +50,37 @@ impl<'a> RuleV2<'a> {
         let constraint = constraint_from_bytes!(
             constraint_type,
             &data[..length],
+            AccountData,
             AdditionalSigner,
+            AdditionalSignerList,
             All,
             Amount,
+            AmountRange,
             Any,
+            Ed25519Attestation,
+            FieldCompare,
             Frequency,
+            InstructionMatch,
             IsWallet,
+            MintListMatch,
+            MintTreeMatch,
             Namespace,
             Not,
             Pass,
             PDAMatch,
             ProgramOwnedList,
+            ProgramOwnedSet,
             ProgramOwnedTree,
             ProgramOwned,
             PubkeyListMatch,
             PubkeyMatch,
-            PubkeyTreeMatch
+            PubkeyTreeMatch,
+            RoyaltyPayment,
+            RuleSetReference,
+            Threshold,
+            TimeWindow,
+            TokenAccount,
+            TokenGate
         );
 
         Ok(Self { header, constraint })
@@ -85,6 +100,7 @@ impl<'a> Assertable<'a> for RuleV2<'a> {
         update_rule_state: bool,
         rule_set_state_pda: &Option<&AccountInfo>,
         rule_authority: &Option<&AccountInfo>,
+        context: &ValidationContext,
     ) -> ProgramResult {
         let result = self.constraint.validate(
             accounts,
@@ -92,6 +108,7 @@ impl<'a> Assertable<'a> for RuleV2<'a> {
             update_rule_state,
             rule_set_state_pda,
             rule_authority,
+            context,
         );
 
         match result {
@@ -107,6 +124,14 @@ impl<'a> Constraint<'a> for RuleV2<'a> {
         self.constraint.constraint_type()
     }
 
+    fn rules(&self) -> &[RuleV2<'a>] {
+        self.constraint.rules()
+    }
+
+    fn combine(&self, results: &mut dyn Iterator<Item = RuleResult>) -> RuleResult {
+        self.constraint.combine(results)
+    }
+
     fn validate(
         &self,
         accounts: &std::collections::HashMap<
@@ -117,6 +142,7 @@ impl<'a> Constraint<'a> for RuleV2<'a> {
         update_rule_state: bool,
         rule_set_state_pda: &Option<&solana_program::account_info::AccountInfo>,
         rule_authority: &Option<&solana_program::account_info::AccountInfo>,
+        context: &ValidationContext,
     ) -> RuleResult {
         self.constraint.validate(
             accounts,
@@ -124,6 +150,7 @@ impl<'a> Constraint<'a> for RuleV2<'a> {
             update_rule_state,
             rule_set_state_pda,
             rule_authority,
+            context,
         )
     }
 }
@@ -159,7 +186,9 @@ impl Header {
 #[cfg(test)]
 mod tests {
     use super::RuleV2;
-    use crate::state::v2::{Amount, Any, Operator, ProgramOwnedList, Str32};
+    use crate::state::v2::{
+        Amount, Any, Operator, ProgramOwnedList, ProgramOwnedSet, Str32, HEADER_SECTION,
+    };
     use solana_program::pubkey::Pubkey;
 
     #[test]
@@ -208,6 +237,26 @@ mod tests {
         assert_eq!(rule.header.length(), Str32::SIZE + (SIZE * 32));
     }
 
+    #[test]
+    fn test_create_program_owned_set() {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let programs = &[second, first, second];
+
+        let program_owned =
+            ProgramOwnedSet::serialize(String::from("Destination"), programs).unwrap();
+
+        // loads the data using bytemuck
+
+        let rule = RuleV2::from_bytes(&program_owned).unwrap();
+
+        // Duplicates are removed and the programs are sorted.
+        assert_eq!(rule.header.length(), 96);
+
+        let set = ProgramOwnedSet::from_bytes(&program_owned[HEADER_SECTION..]).unwrap();
+        assert_eq!(set.programs, &[first.min(second), first.max(second)]);
+    }
+
     #[test]
     fn test_create_any() {
         let programs_list1 = &[Pubkey::default()];
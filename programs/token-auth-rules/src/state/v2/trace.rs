@@ -0,0 +1,224 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{account_info::AccountInfo, program::MAX_RETURN_DATA, pubkey::Pubkey};
+use std::collections::HashMap;
+
+use crate::{
+    payload::Payload,
+    state::{v2::RuleV2, Constraint, RuleResult},
+    types::ValidationContext,
+};
+
+/// Size (in bytes) of a Borsh serialized `RuleTraceEntry`.
+pub const RULE_TRACE_ENTRY_SERIALIZED_LEN: usize = 14;
+
+/// Maximum number of entries that fit in a Borsh serialized `RuleTrace` returned as the
+/// instruction return data (vector length prefix and `truncated` flag included).
+pub const MAX_RULE_TRACE_ENTRIES: usize = (MAX_RETURN_DATA - 5) / RULE_TRACE_ENTRY_SERIALIZED_LEN;
+
+#[repr(u8)]
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
+/// Outcome of the evaluation of a single constraint.
+pub enum RuleOutcome {
+    /// The constraint succeeded.
+    Success,
+    /// The constraint failed.
+    Failure,
+    /// The program failed to evaluate the constraint.
+    Error,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone)]
+/// Evaluation of a single constraint of a `RuleV2` tree.
+pub struct RuleTraceEntry {
+    /// Depth of the constraint in the tree, starting at 0 for the operation rule.
+    pub depth: u8,
+    /// `ConstraintType` of the constraint.
+    pub constraint_type: u32,
+    /// Outcome of the evaluation.
+    pub outcome: RuleOutcome,
+    /// Error carried by the `RuleResult`, as the `u64` representation of a `ProgramError`.
+    pub error: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Eq, Debug, Clone, Default)]
+/// Evaluation trace of a `RuleV2` tree.  Entries are stored in depth-first order, so the children
+/// of an entry are the entries that follow it with a greater depth.
+pub struct RuleTrace {
+    /// The evaluated constraints.
+    pub entries: Vec<RuleTraceEntry>,
+    /// Whether the trace was truncated to fit in the instruction return data.
+    pub truncated: bool,
+}
+
+impl RuleTrace {
+    /// Walk a `RuleV2` tree, evaluating every constraint without updating any rule state.  Each
+    /// constraint is evaluated once: the result of a group constraint is combined from the results
+    /// of its rules.
+    pub fn evaluate(
+        rule: &RuleV2,
+        accounts: &HashMap<Pubkey, &AccountInfo>,
+        payload: &Payload,
+        rule_set_state_pda: &Option<&AccountInfo>,
+        rule_authority: &Option<&AccountInfo>,
+        context: &ValidationContext,
+    ) -> Self {
+        let mut trace = RuleTrace::default();
+        trace.walk(
+            rule,
+            0,
+            accounts,
+            payload,
+            rule_set_state_pda,
+            rule_authority,
+            context,
+        );
+        trace
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn walk(
+        &mut self,
+        rule: &RuleV2,
+        depth: u8,
+        accounts: &HashMap<Pubkey, &AccountInfo>,
+        payload: &Payload,
+        rule_set_state_pda: &Option<&AccountInfo>,
+        rule_authority: &Option<&AccountInfo>,
+        context: &ValidationContext,
+    ) -> RuleResult {
+        // The entry is reserved before the children are walked to keep the depth-first order;
+        // once the trace is full, constraints are still evaluated but no longer recorded.
+        let index = if self.entries.len() < MAX_RULE_TRACE_ENTRIES {
+            self.entries.push(RuleTraceEntry {
+                depth,
+                constraint_type: rule.constraint_type() as u32,
+                outcome: RuleOutcome::Error,
+                error: 0,
+            });
+            Some(self.entries.len() - 1)
+        } else {
+            self.truncated = true;
+            None
+        };
+
+        let result = if rule.rules().is_empty() {
+            rule.validate(
+                accounts,
+                payload,
+                false,
+                rule_set_state_pda,
+                rule_authority,
+                context,
+            )
+        } else {
+            let results = rule
+                .rules()
+                .iter()
+                .map(|child| {
+                    self.walk(
+                        child,
+                        depth.saturating_add(1),
+                        accounts,
+                        payload,
+                        rule_set_state_pda,
+                        rule_authority,
+                        context,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            rule.combine(&mut results.into_iter())
+        };
+
+        if let Some(index) = index {
+            let (outcome, error) = match &result {
+                RuleResult::Success(err) => (RuleOutcome::Success, err),
+                RuleResult::Failure(err) => (RuleOutcome::Failure, err),
+                RuleResult::Error(err) => (RuleOutcome::Error, err),
+            };
+
+            let entry = &mut self.entries[index];
+            entry.outcome = outcome;
+            entry.error = error.clone().into();
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RuleOutcome, RuleTrace, RuleTraceEntry, RULE_TRACE_ENTRY_SERIALIZED_LEN};
+    use crate::{
+        payload::Payload,
+        state::{
+            v2::{All, Any, Not, Pass, RuleV2},
+            Constraint, RuleResult,
+        },
+        types::ValidationContext,
+    };
+    use borsh::BorshSerialize;
+    use solana_program::pubkey::Pubkey;
+    use std::{cell::RefCell, collections::HashMap};
+
+    #[test]
+    fn test_rule_trace_entry_len() {
+        let entry = RuleTraceEntry {
+            depth: 0,
+            constraint_type: 0,
+            outcome: super::RuleOutcome::Success,
+            error: 0,
+        };
+
+        assert_eq!(
+            entry.try_to_vec().unwrap().len(),
+            RULE_TRACE_ENTRY_SERIALIZED_LEN
+        );
+        assert_eq!(RuleTrace::default().try_to_vec().unwrap().len(), 5);
+    }
+
+    #[test]
+    fn test_trace_combines_child_results() {
+        // Any(Not(Pass), All(Pass, Not(Pass)))
+        let pass = Pass::serialize().unwrap();
+        let not = Not::serialize(&pass).unwrap();
+        let all = All::serialize(&[&pass, &not]).unwrap();
+        let any = Any::serialize(&[&not, &all]).unwrap();
+        let rule = RuleV2::from_bytes(&any).unwrap();
+
+        let accounts = HashMap::new();
+        let payload = Payload::new();
+        let context = ValidationContext {
+            mint: Pubkey::default(),
+            operation: String::from("Transfer"),
+            rule_set_revision: None,
+            rule_sets: vec![],
+            frequency_updates: RefCell::default(),
+        };
+
+        let trace = RuleTrace::evaluate(&rule, &accounts, &payload, &None, &None, &context);
+
+        let outcomes = trace
+            .entries
+            .iter()
+            .map(|entry| (entry.depth, entry.outcome))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            outcomes,
+            vec![
+                (0, RuleOutcome::Failure),
+                (1, RuleOutcome::Failure),
+                (2, RuleOutcome::Success),
+                (1, RuleOutcome::Failure),
+                (2, RuleOutcome::Success),
+                (2, RuleOutcome::Failure),
+                (3, RuleOutcome::Success),
+            ]
+        );
+        assert!(!trace.truncated);
+
+        // The root entry matches the result of the validation.
+        let result = rule.validate(&accounts, &payload, false, &None, &None, &context);
+        assert!(matches!(result, RuleResult::Failure(_)));
+    }
+}
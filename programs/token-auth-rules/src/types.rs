@@ -1,11 +1,11 @@
-use std::collections::HashMap;
+use std::{cell::RefCell, collections::HashMap};
 
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
     pubkey::Pubkey,
 };
 
-use crate::{error::RuleSetError, payload::Payload};
+use crate::{error::RuleSetError, payload::Payload, state::FrequencyState};
 
 /// Max name length for any of the names used in this crate.
 pub const MAX_NAME_LENGTH: usize = 32;
@@ -28,6 +28,24 @@ impl TryFrom<u8> for LibVersion {
     }
 }
 
+/// Context of the validation of a `RuleSet`, available to every rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationContext {
+    /// The mint of the token the operation is performed on.
+    pub mint: Pubkey,
+    /// The operation being validated, as requested by the caller (before any namespace
+    /// fallback).
+    pub operation: String,
+    /// The revision of the `RuleSet` being validated, if it was read from a `RuleSet` PDA.
+    pub rule_set_revision: Option<usize>,
+    /// Addresses of the `RuleSet` PDAs being validated, from the outermost one to the one
+    /// containing the rule, used to detect cycles of `RuleSet` references.
+    pub rule_sets: Vec<Pubkey>,
+    /// State of the `Frequency` rules recorded when `update_rule_state` is set, stored in the
+    /// `RuleSet` state PDA once the validation succeeds.
+    pub frequency_updates: RefCell<Vec<FrequencyState>>,
+}
+
 pub trait Assertable<'a> {
     fn validate(
         &self,
@@ -36,6 +54,7 @@ pub trait Assertable<'a> {
         update_rule_state: bool,
         rule_set_state_pda: &Option<&AccountInfo>,
         rule_authority: &Option<&AccountInfo>,
+        context: &ValidationContext,
     ) -> ProgramResult;
 }
 
@@ -2,26 +2,28 @@
 use crate::{
     error::RuleSetError,
     payload::ProofInfo,
+    pda::PREFIX,
     state::{
-        RuleSetHeader, RuleSetRevisionMapV1, RULE_SET_REV_MAP_VERSION,
-        RULE_SET_SERIALIZED_HEADER_LEN,
+        RuleSetHeader, RuleSetRevisionMapV1, RuleSetRevisionMapV2, RULE_SET_REV_MAP_V2_VERSION,
+        RULE_SET_REV_MAP_VERSION, RULE_SET_SERIALIZED_HEADER_LEN,
     },
+    types::LibVersion,
 };
-use borsh::BorshDeserialize;
+use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
+    instruction::Instruction,
     msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
     program_memory::sol_memcmp,
     pubkey::{Pubkey, PUBKEY_BYTES},
     rent::Rent,
-    system_instruction,
-    sysvar::Sysvar,
+    system_instruction, system_program,
+    sysvar::{instructions::load_instruction_at_checked, Sysvar},
 };
-// TODO: Uncomment this when the syscall is available.
-//use solana_zk_token_sdk::curve25519::curve_syscall_traits::CURVE25519_EDWARDS;
+use solana_zk_token_sdk::curve25519::edwards::{validate_edwards, PodEdwardsPoint};
 
 /// Create account almost from scratch, lifted from
 /// <https://github.com/solana-labs/solana-program-library/tree/master/associated-token-account/program/src/processor.rs#L51-L98>
@@ -97,6 +99,28 @@ pub fn resize_or_reallocate_account_raw<'a>(
     Ok(())
 }
 
+/// Close an account owned by this program by transferring all of its lamports to the
+/// destination account, zeroing its data and assigning it back to the System Program.
+#[inline(always)]
+pub fn close_account_raw<'a>(
+    target_account: &AccountInfo<'a>,
+    destination_account: &AccountInfo<'a>,
+) -> ProgramResult {
+    let destination_lamports = destination_account
+        .lamports()
+        .checked_add(target_account.lamports())
+        .ok_or(RuleSetError::NumericalOverflow)?;
+
+    **destination_account.try_borrow_mut_lamports()? = destination_lamports;
+    **target_account.try_borrow_mut_lamports()? = 0;
+
+    target_account.try_borrow_mut_data()?.fill(0);
+    target_account.realloc(0, false)?;
+    target_account.assign(&system_program::ID);
+
+    Ok(())
+}
+
 /// Verify the derivation of the seeds against the given account.
 pub fn assert_derivation(
     program_id: &Pubkey,
@@ -143,16 +167,90 @@ pub fn compute_merkle_root(leaf: &Pubkey, merkle_proof: &ProofInfo) -> [u8; 32]
     computed_hash
 }
 
+/// Compute the SHA-256 hash of a user-pre-serialized `RuleSet`.  This is the hash stored in the
+/// revision map for each revision and checked by `ValidateArgs::V2`.
+pub fn compute_rule_set_hash(serialized_rule_set: &[u8]) -> [u8; 32] {
+    solana_program::hash::hash(serialized_rule_set).to_bytes()
+}
+
+/// Get the start and end location of a `RuleSet` revision in the PDA.  If `rule_set_revision` is
+/// `None`, the location of the latest revision is returned.
+pub fn get_revision_location(
+    revision_map: &RuleSetRevisionMapV2,
+    rev_map_location: usize,
+    rule_set_revision: Option<usize>,
+) -> Result<(usize, usize), ProgramError> {
+    match rule_set_revision {
+        Some(revision) => {
+            let start = revision_map
+                .rule_set_revisions
+                .get(revision)
+                .ok_or(RuleSetError::RuleSetRevisionNotAvailable)?;
+
+            let end_index = revision
+                .checked_add(1)
+                .ok_or(RuleSetError::NumericalOverflow)?;
+
+            let end = revision_map
+                .rule_set_revisions
+                .get(end_index)
+                .unwrap_or(&rev_map_location);
+            Ok((*start, *end))
+        }
+        None => {
+            let start = revision_map
+                .rule_set_revisions
+                .last()
+                .ok_or(RuleSetError::RuleSetRevisionNotAvailable)?;
+            Ok((*start, rev_map_location))
+        }
+    }
+}
+
+/// Get the user-pre-serialized `RuleSet` data and its lib version for a revision stored in the
+/// data of a `RuleSet` PDA.  If `rule_set_revision` is `None`, the latest revision is used.
+pub fn get_rule_set_revision_data(
+    data: &[u8],
+    rule_set_revision: Option<usize>,
+) -> Result<(LibVersion, &[u8]), ProgramError> {
+    let (revision_map, rev_map_location) = get_revision_map_from_data(data)?;
+    let (start, end) = get_revision_location(&revision_map, rev_map_location, rule_set_revision)?;
+
+    if end > data.len() {
+        return Err(RuleSetError::DataTypeMismatch.into());
+    }
+
+    match data.get(start) {
+        Some(lib_version) => match LibVersion::try_from(*lib_version)? {
+            // `RuleSetV1` is stored after its lib version.
+            LibVersion::V1 => Ok((LibVersion::V1, &data[start + 1..end])),
+            // `RuleSetV2` already incorporates the lib version.
+            LibVersion::V2 => Ok((LibVersion::V2, &data[start..end])),
+        },
+        None => Err(RuleSetError::DataTypeMismatch.into()),
+    }
+}
+
 /// Get a revision map by looking at the header, finding its location, and deserializing it.
+/// A `RuleSetRevisionMapV1` is upgraded to a `RuleSetRevisionMapV2` with no update authority set.
 pub fn get_existing_revision_map(
     rule_set_pda_info: &AccountInfo,
-) -> Result<(RuleSetRevisionMapV1, usize), ProgramError> {
+) -> Result<(RuleSetRevisionMapV2, usize), ProgramError> {
     // Mutably borrow the existing `RuleSet` PDA data.
     let data = rule_set_pda_info
         .data
         .try_borrow()
         .map_err(|_| ProgramError::AccountBorrowFailed)?;
 
+    get_revision_map_from_data(&data)
+}
+
+/// Get a revision map from the data of a `RuleSet` PDA, along with the location of the revision
+/// map version.  This can be used off-chain to inspect a `RuleSet` PDA, e.g. to check whether
+/// the `RuleSet` is frozen.
+pub fn get_revision_map_from_data(
+    data: &[u8],
+) -> Result<(RuleSetRevisionMapV2, usize), ProgramError> {
     // Deserialize header.
     let header = if data.len() >= RULE_SET_SERIALIZED_HEADER_LEN {
         RuleSetHeader::try_from_slice(&data[..RULE_SET_SERIALIZED_HEADER_LEN])?
@@ -161,26 +259,103 @@ pub fn get_existing_revision_map(
     };
 
     // Get revision map version location from header and use it check revision map version.
-    match data.get(header.rev_map_version_location) {
-        Some(&RULE_SET_REV_MAP_VERSION) => {
-            // Increment starting location by size of the revision map version.
-            let start = header
-                .rev_map_version_location
-                .checked_add(1)
-                .ok_or(RuleSetError::NumericalOverflow)?;
+    let rev_map_version = match data.get(header.rev_map_version_location) {
+        Some(&version)
+            if version == RULE_SET_REV_MAP_VERSION || version == RULE_SET_REV_MAP_V2_VERSION =>
+        {
+            version
+        }
+        Some(_) => return Err(RuleSetError::UnsupportedRuleSetRevMapVersion.into()),
+        None => return Err(RuleSetError::DataTypeMismatch.into()),
+    };
 
-            // Deserialize revision map.
-            if start < data.len() {
-                let mut location = &data[start..];
-                let revision_map = RuleSetRevisionMapV1::deserialize(&mut location)?;
+    // Increment starting location by size of the revision map version.
+    let start = header
+        .rev_map_version_location
+        .checked_add(1)
+        .ok_or(RuleSetError::NumericalOverflow)?;
 
-                Ok((revision_map, header.rev_map_version_location))
-            } else {
-                Err(RuleSetError::DataTypeMismatch.into())
-            }
-        }
-        Some(_) => Err(RuleSetError::UnsupportedRuleSetRevMapVersion.into()),
-        None => Err(RuleSetError::DataTypeMismatch.into()),
+    // Deserialize revision map.
+    if start < data.len() {
+        let mut location = &data[start..];
+        let revision_map = if rev_map_version == RULE_SET_REV_MAP_VERSION {
+            RuleSetRevisionMapV1::deserialize(&mut location)?.into()
+        } else {
+            RuleSetRevisionMapV2::deserialize(&mut location)?
+        };
+
+        Ok((revision_map, header.rev_map_version_location))
+    } else {
+        Err(RuleSetError::DataTypeMismatch.into())
+    }
+}
+
+/// Write a revision map, preceded by its version, to the `RuleSet` PDA at the given revision map
+/// version location, resizing the PDA so that the revision map is at the end of the account.
+pub fn write_revision_map<'a>(
+    rule_set_pda_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    revision_map: &RuleSetRevisionMapV2,
+    rev_map_version_location: usize,
+) -> ProgramResult {
+    // Borsh serialize the revision map.
+    let mut serialized_rev_map = Vec::new();
+    revision_map
+        .serialize(&mut serialized_rev_map)
+        .map_err(|_| RuleSetError::BorshSerializationError)?;
+
+    let start = rev_map_version_location
+        .checked_add(1)
+        .ok_or(RuleSetError::NumericalOverflow)?;
+    let end = start
+        .checked_add(serialized_rev_map.len())
+        .ok_or(RuleSetError::NumericalOverflow)?;
+
+    if end != rule_set_pda_info.data_len() {
+        resize_or_reallocate_account_raw(rule_set_pda_info, payer_info, system_program_info, end)?;
+    }
+
+    let data = &mut rule_set_pda_info
+        .try_borrow_mut_data()
+        .map_err(|_| ProgramError::AccountBorrowFailed)?;
+
+    data[rev_map_version_location] = RULE_SET_REV_MAP_V2_VERSION;
+    data[start..end].copy_from_slice(&serialized_rev_map);
+
+    Ok(())
+}
+
+/// Assert that the given authority is allowed to update the `RuleSet`.  If no update authority is
+/// stored in the revision map (or the `RuleSet` PDA does not hold a `RuleSet` yet), the authority
+/// must be the `RuleSet` owner, which is verified using the `RuleSet` PDA derivation.
+pub fn assert_update_authority(
+    program_id: &Pubkey,
+    rule_set_pda_info: &AccountInfo,
+    authority: &Pubkey,
+    rule_set_name: &str,
+) -> ProgramResult {
+    let update_authority =
+        if rule_set_pda_info.data_is_empty() || is_zeroed(&rule_set_pda_info.data.borrow()) {
+            None
+        } else {
+            let (revision_map, _) = get_existing_revision_map(rule_set_pda_info)?;
+            revision_map.update_authority
+        };
+
+    match update_authority {
+        Some(update_authority) if cmp_pubkeys(&update_authority, authority) => Ok(()),
+        Some(_) => Err(RuleSetError::UpdateAuthorityMismatch.into()),
+        None => assert_derivation(
+            program_id,
+            rule_set_pda_info.key,
+            &[
+                PREFIX.as_bytes(),
+                authority.as_ref(),
+                rule_set_name.as_bytes(),
+            ],
+        )
+        .map(|_| ()),
     }
 }
 
@@ -199,22 +374,26 @@ pub fn get_latest_revision(rule_set_pda_info: &AccountInfo) -> Result<Option<usi
     }
 }
 
+/// Load every instruction of the currently executing transaction from the instructions sysvar.
+pub fn load_instructions(
+    instructions_sysvar_info: &AccountInfo,
+) -> Result<Vec<Instruction>, ProgramError> {
+    let mut instructions = Vec::new();
+
+    loop {
+        match load_instruction_at_checked(instructions.len(), instructions_sysvar_info) {
+            Ok(instruction) => instructions.push(instruction),
+            // the index is past the last instruction
+            Err(ProgramError::InvalidArgument) => return Ok(instructions),
+            Err(error) => return Err(error),
+        }
+    }
+}
+
 /// Return whether the pubkey is on the Edwards 25519 curve.
 pub fn is_on_curve(pubkey: &Pubkey) -> bool {
-    let _point = pubkey.to_bytes();
-    let mut _validate_result = 0u8;
-    // TODO: Uncomment this when the syscall is available.
-    // let result = unsafe {
-    //     solana_program::syscalls::sol_curve_validate_point(
-    //         CURVE25519_EDWARDS,
-    //         &point as *const u8,
-    //         &mut validate_result,
-    //     )
-    // };
-
-    // For now return false instead of checking the result.
-    // result == 0
-    false
+    // Uses the `sol_curve_validate_point` syscall on-chain.
+    validate_edwards(&PodEdwardsPoint(pubkey.to_bytes()))
 }
 
 /// See if a slice contains all zeroes.  Useful for checking an account's data.
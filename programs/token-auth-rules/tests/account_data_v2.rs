@@ -0,0 +1,215 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    payload::{Payload, PayloadType},
+    state::{AccountData, Operator, RuleSetV2},
+};
+use solana_program::{instruction::AccountMeta, program_pack::Pack, pubkey::Pubkey};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{create_mint, program_test, PayloadKey};
+
+// Offset of the `decimals` field in a SPL Token mint.
+const DECIMALS_OFFSET: u64 = 44;
+
+// Decimals of the mint used in the test.
+const DECIMALS: u8 = 6;
+
+#[tokio::test]
+async fn account_data_v2() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // The mint must have the expected decimals and be initialized.
+    let initialized = AccountData::serialize(
+        PayloadKey::Destination.to_string(),
+        DECIMALS_OFFSET,
+        Operator::Eq,
+        &[DECIMALS, 1],
+        None,
+    )
+    .unwrap();
+
+    // The mint must have at most 9 decimals.
+    let max_decimals = AccountData::serialize(
+        PayloadKey::Destination.to_string(),
+        DECIMALS_OFFSET,
+        Operator::LtEq,
+        &[9],
+        None,
+    )
+    .unwrap();
+
+    // The mint must have at least 9 decimals.
+    let min_decimals = AccountData::serialize(
+        PayloadKey::Destination.to_string(),
+        DECIMALS_OFFSET,
+        Operator::GtEq,
+        &[9],
+        None,
+    )
+    .unwrap();
+
+    // The mint authority must be set, only checking the lowest bit of the `COption` tag.
+    let has_mint_authority = AccountData::serialize(
+        PayloadKey::Destination.to_string(),
+        0,
+        Operator::Eq,
+        &[0xff, 0, 0, 0],
+        Some(&[0x01, 0, 0, 0]),
+    )
+    .unwrap();
+
+    // The slice goes past the end of the mint data.
+    let out_of_bounds = AccountData::serialize(
+        PayloadKey::Destination.to_string(),
+        spl_token::state::Mint::LEN as u64 - 2,
+        Operator::Eq,
+        &[0; 8],
+        None,
+    )
+    .unwrap();
+
+    // Create a RuleSet.
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "test rule_set",
+        &[
+            "initialized".to_string(),
+            "max_decimals".to_string(),
+            "min_decimals".to_string(),
+            "has_mint_authority".to_string(),
+            "out_of_bounds".to_string(),
+        ],
+        &[
+            &initialized,
+            &max_decimals,
+            &min_decimals,
+            &has_mint_authority,
+            &out_of_bounds,
+        ],
+    )
+    .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, "test rule_set".to_string())
+            .await;
+
+    // --------------------------------
+    // Create the mint
+    // --------------------------------
+    let mint = Keypair::new();
+    let authority = Keypair::new();
+
+    create_mint(&mut context, &mint, &authority.pubkey(), None, DECIMALS)
+        .await
+        .unwrap();
+
+    let accounts = vec![AccountMeta::new_readonly(mint.pubkey(), false)];
+
+    // --------------------------------
+    // Validate fail missing account
+    // --------------------------------
+    let validate_ix = utils::validate_ix(
+        rule_set_addr,
+        mint.pubkey(),
+        "initialized".to_string(),
+        account_data_payload(mint.pubkey()),
+        vec![],
+    );
+
+    // Fail to validate operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::MissingAccount);
+
+    // --------------------------------
+    // Validate pass equal bytes
+    // --------------------------------
+    let validate_ix = utils::validate_ix(
+        rule_set_addr,
+        mint.pubkey(),
+        "initialized".to_string(),
+        account_data_payload(mint.pubkey()),
+        accounts.clone(),
+    );
+
+    // Validate operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // --------------------------------
+    // Validate pass numeric comparison
+    // --------------------------------
+    let validate_ix = utils::validate_ix(
+        rule_set_addr,
+        mint.pubkey(),
+        "max_decimals".to_string(),
+        account_data_payload(mint.pubkey()),
+        accounts.clone(),
+    );
+
+    // Validate operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // --------------------------------
+    // Validate fail numeric comparison
+    // --------------------------------
+    let validate_ix = utils::validate_ix(
+        rule_set_addr,
+        mint.pubkey(),
+        "min_decimals".to_string(),
+        account_data_payload(mint.pubkey()),
+        accounts.clone(),
+    );
+
+    // Fail to validate operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::AccountDataCheckFailed);
+
+    // --------------------------------
+    // Validate pass masked bytes
+    // --------------------------------
+    let validate_ix = utils::validate_ix(
+        rule_set_addr,
+        mint.pubkey(),
+        "has_mint_authority".to_string(),
+        account_data_payload(mint.pubkey()),
+        accounts.clone(),
+    );
+
+    // Validate operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // --------------------------------
+    // Validate fail data too short
+    // --------------------------------
+    let validate_ix = utils::validate_ix(
+        rule_set_addr,
+        mint.pubkey(),
+        "out_of_bounds".to_string(),
+        account_data_payload(mint.pubkey()),
+        accounts,
+    );
+
+    // Fail to validate operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::DataSliceUnexpectedIndexError);
+}
+
+fn account_data_payload(mint: Pubkey) -> Payload {
+    Payload::from([(
+        PayloadKey::Destination.to_string(),
+        PayloadType::Pubkey(mint),
+    )])
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    payload::Payload,
+    state::{Rule, RuleSetV1},
+};
+use solana_program::instruction::AccountMeta;
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{program_test, transfer_validate_ix, Operation};
+
+#[tokio::test]
+async fn test_additional_signer_list() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // Create some rules.
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+
+    // At least two of the three signers must sign.
+    let overall_rule = Rule::AdditionalSignerList {
+        accounts: signers.iter().map(|signer| signer.pubkey()).collect(),
+        threshold: 2,
+    };
+
+    // Create a RuleSet.
+    let mut rule_set = RuleSetV1::new("test rule_set".to_string(), context.payer.pubkey());
+    rule_set
+        .add(
+            Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            overall_rule,
+        )
+        .unwrap();
+
+    println!("{:#?}", rule_set);
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain!(&mut context, rule_set, "test rule_set".to_string()).await;
+
+    // --------------------------------
+    // Validate fail missing accounts
+    // --------------------------------
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+
+    // Missing signer accounts count as not signed.
+    let validate_ix = transfer_validate_ix(rule_set_addr, mint, Payload::default(), vec![]);
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::AdditionalSignerCheckFailed);
+
+    // --------------------------------
+    // Validate fail
+    // --------------------------------
+    // Only the first signer signs.
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        Payload::default(),
+        vec![
+            AccountMeta::new_readonly(signers[0].pubkey(), true),
+            AccountMeta::new_readonly(signers[1].pubkey(), false),
+        ],
+    );
+
+    // Fail to validate Transfer operation.
+    let err =
+        process_failing_validate_ix!(&mut context, validate_ix, vec![&signers[0]], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::AdditionalSignerCheckFailed);
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    // The last two signers sign.
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        Payload::default(),
+        vec![
+            AccountMeta::new_readonly(signers[1].pubkey(), true),
+            AccountMeta::new_readonly(signers[2].pubkey(), true),
+        ],
+    );
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(
+        &mut context,
+        validate_ix,
+        vec![&signers[1], &signers[2]],
+        None
+    )
+    .await;
+}
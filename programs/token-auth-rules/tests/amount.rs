@@ -42,23 +42,58 @@ async fn test_greater_than_amount() {
     parametric_amount_check(CompareOp::Gt, 100, 100, 101).await;
 }
 
+#[tokio::test]
+async fn test_not_equal_to_amount() {
+    parametric_amount_check(CompareOp::Ne, 100, 100, 99).await;
+}
+
+#[tokio::test]
+async fn test_multiple_of_amount() {
+    parametric_amount_check(CompareOp::MultipleOf, 10, 105, 110).await;
+}
+
+#[tokio::test]
+async fn test_amount_range_fail_less_than() {
+    parametric_amount_range_check(10, 100, 9, 10).await;
+}
+
+#[tokio::test]
+async fn test_amount_range_fail_greater_than() {
+    parametric_amount_range_check(10, 100, 101, 100).await;
+}
+
 async fn parametric_amount_check(
     operator: CompareOp,
     amount: u64,
     fail_amount: u64,
     pass_amount: u64,
 ) {
-    let mut context = program_test().start_with_context().await;
-    // --------------------------------
-    // Create RuleSet
-    // --------------------------------
     // Create a rule.
-    let less_than_amount_check = Rule::Amount {
+    let amount_check = Rule::Amount {
         amount,
         operator,
         field: PayloadKey::Amount.to_string(),
     };
 
+    amount_rule_check(amount_check, fail_amount, pass_amount).await;
+}
+
+async fn parametric_amount_range_check(min: u64, max: u64, fail_amount: u64, pass_amount: u64) {
+    // Create a rule.
+    let amount_range_check = Rule::AmountRange {
+        min,
+        max,
+        field: PayloadKey::Amount.to_string(),
+    };
+
+    amount_rule_check(amount_range_check, fail_amount, pass_amount).await;
+}
+
+async fn amount_rule_check(rule: Rule, fail_amount: u64, pass_amount: u64) {
+    let mut context = program_test().start_with_context().await;
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
     // Create a RuleSet.
     let mut rule_set = RuleSetV1::new("test rule_set".to_string(), context.payer.pubkey());
     rule_set
@@ -67,7 +102,7 @@ async fn parametric_amount_check(
                 scenario: utils::TransferScenario::Holder,
             }
             .to_string(),
-            less_than_amount_check,
+            rule,
         )
         .unwrap();
 
@@ -6,7 +6,7 @@ use mpl_token_auth_rules::{
     error::RuleSetError,
     instruction::{builders::ValidateBuilder, InstructionBuilder, ValidateArgs},
     payload::{Payload, PayloadType},
-    state::{Amount, Operator, RuleSetV2},
+    state::{Amount, AmountRange, Operator, RuleSetV2},
 };
 use solana_program_test::tokio;
 use solana_sdk::{signature::Signer, signer::keypair::Keypair};
@@ -42,20 +42,51 @@ async fn test_greater_than_amount_v2() {
     parametric_amount_check(Operator::Gt, 100, 100, 101).await;
 }
 
+#[tokio::test]
+async fn test_not_equal_to_amount_v2() {
+    parametric_amount_check(Operator::Ne, 100, 100, 99).await;
+}
+
+#[tokio::test]
+async fn test_multiple_of_amount_v2() {
+    parametric_amount_check(Operator::MultipleOf, 10, 105, 110).await;
+}
+
+#[tokio::test]
+async fn test_amount_range_fail_less_than_v2() {
+    parametric_amount_range_check(10, 100, 9, 10).await;
+}
+
+#[tokio::test]
+async fn test_amount_range_fail_greater_than_v2() {
+    parametric_amount_range_check(10, 100, 101, 100).await;
+}
+
 async fn parametric_amount_check(
     operator: Operator,
     amount: u64,
     fail_amount: u64,
     pass_amount: u64,
 ) {
+    // Create a rule.
+    let amount_check = Amount::serialize(PayloadKey::Amount.to_string(), operator, amount).unwrap();
+
+    amount_rule_check(amount_check, fail_amount, pass_amount).await;
+}
+
+async fn parametric_amount_range_check(min: u64, max: u64, fail_amount: u64, pass_amount: u64) {
+    // Create a rule.
+    let amount_range_check =
+        AmountRange::serialize(PayloadKey::Amount.to_string(), min, max).unwrap();
+
+    amount_rule_check(amount_range_check, fail_amount, pass_amount).await;
+}
+
+async fn amount_rule_check(rule: Vec<u8>, fail_amount: u64, pass_amount: u64) {
     let mut context = program_test().start_with_context().await;
     // --------------------------------
     // Create RuleSet
     // --------------------------------
-    // Create a rule.
-    let less_than_amount_check =
-        Amount::serialize(PayloadKey::Amount.to_string(), operator, amount).unwrap();
-
     // Create a RuleSet.
     let rule_set = RuleSetV2::serialize(
         context.payer.pubkey(),
@@ -64,7 +95,7 @@ async fn parametric_amount_check(
             scenario: utils::TransferScenario::Holder,
         }
         .to_string()],
-        &[&less_than_amount_check],
+        &[&rule],
     )
     .unwrap();
 
@@ -0,0 +1,240 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    instruction::{
+        builders::{CloseRuleSetBuilder, ValidateBuilder, WriteToBufferBuilder},
+        CloseRuleSetArgs, InstructionBuilder, ValidateArgs, WriteToBufferArgs,
+    },
+    payload::Payload,
+    state::{Rule, RuleSetV1},
+};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair, transaction::Transaction};
+use utils::{program_test, Operation};
+
+#[tokio::test]
+async fn close_rule_set() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // Create a RuleSet.
+    let mut rule_set = RuleSetV1::new("test rule_set".to_string(), context.payer.pubkey());
+    rule_set
+        .add(
+            Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            Rule::Pass,
+        )
+        .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain!(&mut context, rule_set.clone(), "test rule_set".to_string())
+            .await;
+
+    let rule_set_lamports = context
+        .banks_client
+        .get_account(rule_set_addr)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // --------------------------------
+    // Close RuleSet
+    // --------------------------------
+    let destination = Keypair::new().pubkey();
+
+    // Create a `close_rule_set` instruction.
+    let close_ix = CloseRuleSetBuilder::new()
+        .payer(context.payer.pubkey())
+        .destination(destination)
+        .rule_set_pda(rule_set_addr)
+        .build(CloseRuleSetArgs::V1 {
+            rule_set_name: "test rule_set".to_string(),
+        })
+        .unwrap()
+        .instruction();
+
+    // Add it to a transaction.
+    let close_tx = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    // Process the transaction.
+    context
+        .banks_client
+        .process_transaction(close_tx)
+        .await
+        .unwrap();
+
+    // The `RuleSet` PDA no longer exists and its lamports were sent to the destination.
+    assert!(context
+        .banks_client
+        .get_account(rule_set_addr)
+        .await
+        .unwrap()
+        .is_none());
+
+    let destination_lamports = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(destination_lamports, rule_set_lamports);
+
+    // --------------------------------
+    // Recreate RuleSet
+    // --------------------------------
+    // Move forward to avoid duplicate transactions.
+    context.warp_to_slot(3).unwrap();
+
+    // Put the RuleSet back on chain at the same address.
+    let recreated_rule_set_addr =
+        create_rule_set_on_chain!(&mut context, rule_set, "test rule_set".to_string()).await;
+    assert_eq!(recreated_rule_set_addr, rule_set_addr);
+
+    // Create a `validate` instruction.
+    let validate_ix = ValidateBuilder::new()
+        .rule_set_pda(rule_set_addr)
+        .mint(Keypair::new().pubkey())
+        .additional_rule_accounts(vec![])
+        .build(ValidateArgs::V1 {
+            operation: Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            payload: Payload::default(),
+            update_rule_state: false,
+            rule_set_revision: None,
+        })
+        .unwrap()
+        .instruction();
+
+    // Validate Transfer operation against the recreated `RuleSet`.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+}
+
+#[tokio::test]
+async fn close_buffer() {
+    let mut context = program_test().start_with_context().await;
+
+    let (buffer_pda, _buffer_bump) =
+        mpl_token_auth_rules::pda::find_buffer_address(context.payer.pubkey());
+
+    // Create a `write_to_buffer` instruction.
+    let write_to_buffer_ix = WriteToBufferBuilder::new()
+        .payer(context.payer.pubkey())
+        .buffer_pda(buffer_pda)
+        .build(WriteToBufferArgs::V1 {
+            serialized_rule_set: vec![1; 100],
+            overwrite: true,
+        })
+        .unwrap()
+        .instruction();
+
+    // Create a `close_rule_set` instruction for the buffer only.
+    let destination = Keypair::new().pubkey();
+    let close_ix = CloseRuleSetBuilder::new()
+        .payer(context.payer.pubkey())
+        .destination(destination)
+        .buffer_pda(buffer_pda)
+        .build(CloseRuleSetArgs::V1 {
+            rule_set_name: String::new(),
+        })
+        .unwrap()
+        .instruction();
+
+    // Write to and close the buffer in the same transaction.
+    let tx = Transaction::new_signed_with_payer(
+        &[write_to_buffer_ix, close_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    // Process the transaction.
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // The buffer PDA no longer exists and its lamports were sent to the destination.
+    assert!(context
+        .banks_client
+        .get_account(buffer_pda)
+        .await
+        .unwrap()
+        .is_none());
+
+    assert!(
+        context
+            .banks_client
+            .get_account(destination)
+            .await
+            .unwrap()
+            .unwrap()
+            .lamports
+            > 0
+    );
+}
+
+#[tokio::test]
+async fn close_rule_set_wrong_payer_fails() {
+    let mut context = program_test().start_with_context().await;
+
+    // Create a RuleSet.
+    let mut rule_set = RuleSetV1::new("test rule_set".to_string(), context.payer.pubkey());
+    rule_set
+        .add(
+            Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            Rule::Pass,
+        )
+        .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain!(&mut context, rule_set, "test rule_set".to_string()).await;
+
+    // Create a `close_rule_set` instruction signed by someone other than the `RuleSet` creator.
+    let other_payer = Keypair::new();
+    let close_ix = CloseRuleSetBuilder::new()
+        .payer(other_payer.pubkey())
+        .destination(other_payer.pubkey())
+        .rule_set_pda(rule_set_addr)
+        .build(CloseRuleSetArgs::V1 {
+            rule_set_name: "test rule_set".to_string(),
+        })
+        .unwrap()
+        .instruction();
+
+    // Add it to a transaction.
+    let close_tx = Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &other_payer],
+        context.last_blockhash,
+    );
+
+    // Fail to close the `RuleSet`.
+    let err = context
+        .banks_client
+        .process_transaction(close_tx)
+        .await
+        .expect_err("close should fail");
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::DerivedKeyInvalid);
+}
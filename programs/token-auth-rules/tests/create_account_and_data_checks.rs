@@ -674,3 +674,72 @@ async fn create_rule_set_to_wrong_pda_fails() {
     // Check that error is what we expect.
     assert_custom_error!(err, RuleSetError::DerivedKeyInvalid);
 }
+
+#[tokio::test]
+async fn create_rule_set_lint_fails() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // Create a RuleSet with a Namespace rule that has no operation to fall back to.
+    let mut rule_set = RuleSetV1::new("test rule_set".to_string(), context.payer.pubkey());
+    rule_set
+        .add(
+            Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            Rule::Namespace,
+        )
+        .unwrap();
+
+    // Serialize the RuleSet using RMP serde.
+    let mut serialized_rule_set = Vec::new();
+    rule_set
+        .serialize(&mut Serializer::new(&mut serialized_rule_set))
+        .unwrap();
+
+    // Find RuleSet PDA.
+    let (rule_set_addr, _rule_set_bump) = mpl_token_auth_rules::pda::find_rule_set_address(
+        context.payer.pubkey(),
+        "test rule_set".to_string(),
+    );
+
+    // --------------------------------
+    // Fail on-chain creation with lint
+    // --------------------------------
+    let create_ix = CreateOrUpdateBuilder::new()
+        .payer(context.payer.pubkey())
+        .rule_set_pda(rule_set_addr)
+        .build(CreateOrUpdateArgs::V2 {
+            serialized_rule_set: serialized_rule_set.clone(),
+            lint: true,
+        })
+        .unwrap()
+        .instruction();
+
+    let err = utils::process_instructions(&mut context, &[create_ix], &[])
+        .await
+        .expect_err("Creation should fail");
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::MissingNamespaceFallback);
+
+    // --------------------------------
+    // Succeed on-chain creation without lint
+    // --------------------------------
+    let create_ix = CreateOrUpdateBuilder::new()
+        .payer(context.payer.pubkey())
+        .rule_set_pda(rule_set_addr)
+        .build(CreateOrUpdateArgs::V2 {
+            serialized_rule_set,
+            lint: false,
+        })
+        .unwrap()
+        .instruction();
+
+    utils::process_instructions(&mut context, &[create_ix], &[])
+        .await
+        .expect("Creation should succeed");
+}
@@ -0,0 +1,215 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    payload::{Payload, PayloadType},
+    state::{Ed25519Attestation, RuleSetV2},
+};
+use solana_program::{
+    ed25519_program,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    sysvar,
+};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{process_instructions, program_test, transfer_validate_ix, Operation, PayloadKey};
+
+#[tokio::test]
+async fn ed25519_attestation_v2() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // The destination must be approved by the compliance authority.
+    let authority = Keypair::new();
+    let rule =
+        Ed25519Attestation::serialize(PayloadKey::Destination.to_string(), authority.pubkey())
+            .unwrap();
+
+    // Create a RuleSet.
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "test rule_set",
+        &[Operation::Transfer {
+            scenario: utils::TransferScenario::Holder,
+        }
+        .to_string()],
+        &[&rule],
+    )
+    .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, "test rule_set".to_string())
+            .await;
+
+    // --------------------------------
+    // Validate fail missing attestation
+    // --------------------------------
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+    let destination = Keypair::new().pubkey();
+
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        ed25519_attestation_payload(destination),
+        vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::Ed25519AttestationCheckFailed);
+
+    // --------------------------------
+    // Validate fail wrong authority
+    // --------------------------------
+    let attestation_ix = ed25519_ix(
+        &Keypair::new(),
+        &Ed25519Attestation::message(&destination, &mint, i64::MAX),
+    );
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        ed25519_attestation_payload(destination),
+        vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_instructions(&mut context, &[attestation_ix, validate_ix], &[])
+        .await
+        .unwrap_err();
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::Ed25519AttestationCheckFailed);
+
+    // --------------------------------
+    // Validate fail wrong mint
+    // --------------------------------
+    let attestation_ix = ed25519_ix(
+        &authority,
+        &Ed25519Attestation::message(&destination, &Keypair::new().pubkey(), i64::MAX),
+    );
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        ed25519_attestation_payload(destination),
+        vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_instructions(&mut context, &[attestation_ix, validate_ix], &[])
+        .await
+        .unwrap_err();
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::Ed25519AttestationCheckFailed);
+
+    // --------------------------------
+    // Validate fail attestation for a different mint
+    // --------------------------------
+    let attestation_ix = ed25519_ix(
+        &authority,
+        &Ed25519Attestation::message(&destination, &mint, i64::MAX),
+    );
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        Keypair::new().pubkey(),
+        ed25519_attestation_payload(destination),
+        vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_instructions(&mut context, &[attestation_ix, validate_ix], &[])
+        .await
+        .unwrap_err();
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::Ed25519AttestationCheckFailed);
+
+    // --------------------------------
+    // Validate fail stale attestation
+    // --------------------------------
+    let attestation_ix = ed25519_ix(
+        &authority,
+        &Ed25519Attestation::message(&destination, &mint, 1),
+    );
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        ed25519_attestation_payload(destination),
+        vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_instructions(&mut context, &[attestation_ix, validate_ix], &[])
+        .await
+        .unwrap_err();
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::Ed25519AttestationCheckFailed);
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    let attestation_ix = ed25519_ix(
+        &authority,
+        &Ed25519Attestation::message(&destination, &mint, i64::MAX),
+    );
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        ed25519_attestation_payload(destination),
+        vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+    );
+
+    // Validate Transfer operation.
+    process_instructions(&mut context, &[attestation_ix, validate_ix], &[])
+        .await
+        .unwrap();
+}
+
+/// Build an ed25519 program instruction verifying the signature of `message` by `signer`, with
+/// the public key, signature and message stored in the instruction data.
+fn ed25519_ix(signer: &Keypair, message: &[u8]) -> Instruction {
+    // num_signatures and padding, followed by a single signature offsets
+    const DATA_START: u16 = 2 + 14;
+    let public_key_offset = DATA_START;
+    let signature_offset = public_key_offset + 32;
+    let message_offset = signature_offset + 64;
+
+    let mut data = vec![1, 0];
+    for value in [
+        signature_offset,
+        u16::MAX,
+        public_key_offset,
+        u16::MAX,
+        message_offset,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend(value.to_le_bytes());
+    }
+    data.extend(signer.pubkey().as_ref());
+    data.extend(signer.sign_message(message).as_ref());
+    data.extend(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+fn ed25519_attestation_payload(destination: Pubkey) -> Payload {
+    Payload::from([(
+        PayloadKey::Destination.to_string(),
+        PayloadType::Pubkey(destination),
+    )])
+}
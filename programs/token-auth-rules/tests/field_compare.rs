@@ -0,0 +1,138 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    payload::{Payload, PayloadType},
+    state::{CompareOp, Rule, RuleSetV1},
+};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{program_test, transfer_validate_ix, Operation, PayloadKey};
+
+// Field holding the maximum amount that can be transferred.
+const MAX_AMOUNT: &str = "MaxAmount";
+
+#[tokio::test]
+async fn field_compare() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // Only allow transfers to the same wallet, up to the maximum amount.
+    let self_transfer = Rule::FieldCompare {
+        field: PayloadKey::Source.to_string(),
+        operator: CompareOp::Eq,
+        other_field: PayloadKey::Destination.to_string(),
+    };
+
+    let max_amount = Rule::FieldCompare {
+        field: PayloadKey::Amount.to_string(),
+        operator: CompareOp::LtEq,
+        other_field: MAX_AMOUNT.to_string(),
+    };
+
+    let overall_rule = Rule::All {
+        rules: vec![self_transfer, max_amount],
+    };
+
+    // Create a RuleSet.
+    let mut rule_set = RuleSetV1::new("test rule_set".to_string(), context.payer.pubkey());
+    rule_set
+        .add(
+            Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            overall_rule,
+        )
+        .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain!(&mut context, rule_set, "test rule_set".to_string()).await;
+
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+    let wallet = Keypair::new().pubkey();
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    let payload = Payload::from([
+        (PayloadKey::Source.to_string(), PayloadType::Pubkey(wallet)),
+        (
+            PayloadKey::Destination.to_string(),
+            PayloadType::Pubkey(wallet),
+        ),
+        (PayloadKey::Amount.to_string(), PayloadType::Number(2)),
+        (MAX_AMOUNT.to_string(), PayloadType::Number(2)),
+    ]);
+
+    let validate_ix = transfer_validate_ix(rule_set_addr, mint, payload, vec![]);
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // --------------------------------
+    // Validate fail different wallets
+    // --------------------------------
+    let payload = Payload::from([
+        (PayloadKey::Source.to_string(), PayloadType::Pubkey(wallet)),
+        (
+            PayloadKey::Destination.to_string(),
+            PayloadType::Pubkey(Keypair::new().pubkey()),
+        ),
+        (PayloadKey::Amount.to_string(), PayloadType::Number(2)),
+        (MAX_AMOUNT.to_string(), PayloadType::Number(2)),
+    ]);
+
+    let validate_ix = transfer_validate_ix(rule_set_addr, mint, payload, vec![]);
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::FieldCompareCheckFailed);
+
+    // --------------------------------
+    // Validate fail amount over the maximum
+    // --------------------------------
+    let payload = Payload::from([
+        (PayloadKey::Source.to_string(), PayloadType::Pubkey(wallet)),
+        (
+            PayloadKey::Destination.to_string(),
+            PayloadType::Pubkey(wallet),
+        ),
+        (PayloadKey::Amount.to_string(), PayloadType::Number(3)),
+        (MAX_AMOUNT.to_string(), PayloadType::Number(2)),
+    ]);
+
+    let validate_ix = transfer_validate_ix(rule_set_addr, mint, payload, vec![]);
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::FieldCompareCheckFailed);
+
+    // --------------------------------
+    // Validate fail values of different types
+    // --------------------------------
+    let payload = Payload::from([
+        (PayloadKey::Source.to_string(), PayloadType::Pubkey(wallet)),
+        (PayloadKey::Destination.to_string(), PayloadType::Number(2)),
+        (PayloadKey::Amount.to_string(), PayloadType::Number(2)),
+        (MAX_AMOUNT.to_string(), PayloadType::Number(2)),
+    ]);
+
+    let validate_ix = transfer_validate_ix(rule_set_addr, mint, payload, vec![]);
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::DataTypeMismatch);
+}
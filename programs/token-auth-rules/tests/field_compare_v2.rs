@@ -0,0 +1,141 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    payload::{Payload, PayloadType},
+    state::{All, FieldCompare, Operator, RuleSetV2},
+};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{program_test, transfer_validate_ix, Operation, PayloadKey};
+
+// Field holding the minimum amount that must be transferred.
+const MIN_AMOUNT: &str = "MinAmount";
+
+#[tokio::test]
+async fn field_compare_v2() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // Only allow transfers to a different wallet, over the minimum amount.
+    let not_self_transfer = FieldCompare::serialize(
+        PayloadKey::Source.to_string(),
+        Operator::Ne,
+        PayloadKey::Destination.to_string(),
+    )
+    .unwrap();
+
+    let min_amount = FieldCompare::serialize(
+        PayloadKey::Amount.to_string(),
+        Operator::Gt,
+        MIN_AMOUNT.to_string(),
+    )
+    .unwrap();
+
+    let overall_rule = All::serialize(&[&not_self_transfer, &min_amount]).unwrap();
+
+    // Create a RuleSet.
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "test rule_set",
+        &[Operation::Transfer {
+            scenario: utils::TransferScenario::Holder,
+        }
+        .to_string()],
+        &[&overall_rule],
+    )
+    .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, "test rule_set".to_string())
+            .await;
+
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+    let wallet = Keypair::new().pubkey();
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    let payload = Payload::from([
+        (PayloadKey::Source.to_string(), PayloadType::Pubkey(wallet)),
+        (
+            PayloadKey::Destination.to_string(),
+            PayloadType::Pubkey(Keypair::new().pubkey()),
+        ),
+        (PayloadKey::Amount.to_string(), PayloadType::Number(3)),
+        (MIN_AMOUNT.to_string(), PayloadType::Number(2)),
+    ]);
+
+    let validate_ix = transfer_validate_ix(rule_set_addr, mint, payload, vec![]);
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // --------------------------------
+    // Validate fail same wallet
+    // --------------------------------
+    let payload = Payload::from([
+        (PayloadKey::Source.to_string(), PayloadType::Pubkey(wallet)),
+        (
+            PayloadKey::Destination.to_string(),
+            PayloadType::Pubkey(wallet),
+        ),
+        (PayloadKey::Amount.to_string(), PayloadType::Number(3)),
+        (MIN_AMOUNT.to_string(), PayloadType::Number(2)),
+    ]);
+
+    let validate_ix = transfer_validate_ix(rule_set_addr, mint, payload, vec![]);
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::FieldCompareCheckFailed);
+
+    // --------------------------------
+    // Validate fail amount at the minimum
+    // --------------------------------
+    let payload = Payload::from([
+        (PayloadKey::Source.to_string(), PayloadType::Pubkey(wallet)),
+        (
+            PayloadKey::Destination.to_string(),
+            PayloadType::Pubkey(Keypair::new().pubkey()),
+        ),
+        (PayloadKey::Amount.to_string(), PayloadType::Number(2)),
+        (MIN_AMOUNT.to_string(), PayloadType::Number(2)),
+    ]);
+
+    let validate_ix = transfer_validate_ix(rule_set_addr, mint, payload, vec![]);
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::FieldCompareCheckFailed);
+
+    // --------------------------------
+    // Validate fail missing value
+    // --------------------------------
+    let payload = Payload::from([
+        (PayloadKey::Source.to_string(), PayloadType::Pubkey(wallet)),
+        (
+            PayloadKey::Destination.to_string(),
+            PayloadType::Pubkey(Keypair::new().pubkey()),
+        ),
+        (PayloadKey::Amount.to_string(), PayloadType::Number(3)),
+    ]);
+
+    let validate_ix = transfer_validate_ix(rule_set_addr, mint, payload, vec![]);
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::MissingPayloadValue);
+}
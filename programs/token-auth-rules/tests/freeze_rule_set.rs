@@ -0,0 +1,144 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    instruction::{
+        builders::{CloseRuleSetBuilder, FreezeRuleSetBuilder, TransferUpdateAuthorityBuilder},
+        CloseRuleSetArgs, FreezeRuleSetArgs, InstructionBuilder, TransferUpdateAuthorityArgs,
+    },
+    state::{Rule, RuleSetV1},
+    utils::get_revision_map_from_data,
+};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{create_or_update_ix, process_instructions, program_test, Operation};
+
+#[tokio::test]
+async fn freeze_rule_set() {
+    let mut context = program_test().start_with_context().await;
+    let owner = context.payer.pubkey();
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    let mut rule_set = RuleSetV1::new("test rule_set".to_string(), owner);
+    rule_set
+        .add(
+            Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            Rule::Pass,
+        )
+        .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain!(&mut context, rule_set.clone(), "test rule_set".to_string())
+            .await;
+
+    // --------------------------------
+    // Freeze RuleSet
+    // --------------------------------
+    // Fail to freeze the `RuleSet` from an account that is not the update authority.
+    let other_authority = Keypair::new();
+    let freeze_ix = FreezeRuleSetBuilder::new()
+        .payer(other_authority.pubkey())
+        .rule_set_pda(rule_set_addr)
+        .build(FreezeRuleSetArgs::V1 {
+            rule_set_name: "test rule_set".to_string(),
+        })
+        .unwrap()
+        .instruction();
+
+    let err = process_instructions(&mut context, &[freeze_ix], &[&other_authority])
+        .await
+        .unwrap_err();
+    assert_custom_error!(err, RuleSetError::DerivedKeyInvalid);
+
+    // Freeze the `RuleSet`.
+    let freeze_ix = FreezeRuleSetBuilder::new()
+        .payer(owner)
+        .rule_set_pda(rule_set_addr)
+        .build(FreezeRuleSetArgs::V1 {
+            rule_set_name: "test rule_set".to_string(),
+        })
+        .unwrap()
+        .instruction();
+
+    process_instructions(&mut context, &[freeze_ix], &[])
+        .await
+        .unwrap();
+
+    // The frozen flag can be read by following the header to the revision map.
+    let data = context
+        .banks_client
+        .get_account(rule_set_addr)
+        .await
+        .unwrap()
+        .unwrap()
+        .data;
+    let (revision_map, _) = get_revision_map_from_data(&data).unwrap();
+    assert!(revision_map.frozen);
+
+    // --------------------------------
+    // Fail operations on frozen RuleSet
+    // --------------------------------
+    // Fail to update the `RuleSet`.  Move forward to avoid duplicating the creation transaction.
+    context.warp_to_slot(3).unwrap();
+    let update_ix = create_or_update_ix(owner, rule_set_addr, &rule_set);
+    let err = process_instructions(&mut context, &[update_ix], &[])
+        .await
+        .unwrap_err();
+    assert_custom_error!(err, RuleSetError::RuleSetFrozen);
+
+    // Fail to close the `RuleSet`.
+    let close_ix = CloseRuleSetBuilder::new()
+        .payer(owner)
+        .destination(owner)
+        .rule_set_pda(rule_set_addr)
+        .build(CloseRuleSetArgs::V1 {
+            rule_set_name: "test rule_set".to_string(),
+        })
+        .unwrap()
+        .instruction();
+
+    let err = process_instructions(&mut context, &[close_ix], &[])
+        .await
+        .unwrap_err();
+    assert_custom_error!(err, RuleSetError::RuleSetFrozen);
+
+    // Fail to transfer the update authority.
+    let transfer_ix = TransferUpdateAuthorityBuilder::new()
+        .payer(owner)
+        .rule_set_pda(rule_set_addr)
+        .build(TransferUpdateAuthorityArgs::V1 {
+            rule_set_name: "test rule_set".to_string(),
+            new_update_authority: other_authority.pubkey(),
+        })
+        .unwrap()
+        .instruction();
+
+    let err = process_instructions(&mut context, &[transfer_ix], &[])
+        .await
+        .unwrap_err();
+    assert_custom_error!(err, RuleSetError::RuleSetFrozen);
+
+    // Fail to freeze the `RuleSet` again.  Move forward to avoid duplicate transactions.
+    context.warp_to_slot(4).unwrap();
+    let freeze_ix = FreezeRuleSetBuilder::new()
+        .payer(owner)
+        .rule_set_pda(rule_set_addr)
+        .build(FreezeRuleSetArgs::V1 {
+            rule_set_name: "test rule_set".to_string(),
+        })
+        .unwrap()
+        .instruction();
+
+    let err = process_instructions(&mut context, &[freeze_ix], &[])
+        .await
+        .unwrap_err();
+    assert_custom_error!(err, RuleSetError::RuleSetFrozen);
+}
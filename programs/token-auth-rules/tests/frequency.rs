@@ -2,18 +2,22 @@
 
 pub mod utils;
 
+use borsh::BorshDeserialize;
 use mpl_token_auth_rules::{
     error::RuleSetError,
     instruction::{builders::ValidateBuilder, InstructionBuilder, ValidateArgs},
     payload::Payload,
     pda::find_rule_set_state_address,
-    state::{Rule, RuleSetV1},
+    state::{FrequencyAccount, FrequencyState, Rule, RuleSetV1},
 };
-use solana_program::program_error::ProgramError;
+use solana_program::{clock::Clock, program_error::ProgramError};
 use solana_program_test::{tokio, BanksClientError};
 use solana_sdk::{signature::Signer, signer::keypair::Keypair, transaction::TransactionError};
 use utils::{program_test, Operation};
 
+// Period (in seconds) between allowed operations.
+const PERIOD: i64 = 60;
+
 #[tokio::test]
 async fn test_frequency() {
     let mut context = program_test().start_with_context().await;
@@ -25,6 +29,7 @@ async fn test_frequency() {
     let rule_authority = Keypair::new();
     let rule = Rule::Frequency {
         authority: rule_authority.pubkey(),
+        period: PERIOD,
     };
 
     // Create a RuleSet.
@@ -117,8 +122,7 @@ async fn test_frequency() {
     assert_custom_error!(err, RuleSetError::RuleAuthorityIsNotSigner);
 
     // --------------------------------
-    // Validate not implemented
-    // (this will become pass later)
+    // Validate pass
     // --------------------------------
     // Create a `validate` instruction.
     let validate_ix = ValidateBuilder::new()
@@ -140,10 +144,81 @@ async fn test_frequency() {
         .unwrap()
         .instruction();
 
+    // Validate Transfer operation, which creates the `RuleSet` state PDA.
+    process_passing_validate_ix!(
+        &mut context,
+        validate_ix.clone(),
+        vec![&rule_authority],
+        None
+    )
+    .await;
+
+    // Check that the last update time and period were stored in the `RuleSet` state PDA.
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    let state_account = context
+        .banks_client
+        .get_account(rule_set_state_addr)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        FrequencyAccount::try_from_slice(&state_account.data).unwrap(),
+        FrequencyAccount::new(vec![FrequencyState {
+            operation: Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            period: PERIOD,
+            last_update: clock.unix_timestamp,
+        }])
+    );
+
+    // --------------------------------
+    // Validate fail within period
+    // --------------------------------
+    // Move forward to avoid duplicate transactions.
+    context.warp_to_slot(3).unwrap();
+
     // Fail to validate Transfer operation.
-    let err =
-        process_failing_validate_ix!(&mut context, validate_ix, vec![&rule_authority], None).await;
+    let err = process_failing_validate_ix!(
+        &mut context,
+        validate_ix.clone(),
+        vec![&rule_authority],
+        None
+    )
+    .await;
 
     // Check that error is what we expect.
-    assert_custom_error!(err, RuleSetError::NotImplemented);
+    assert_custom_error!(err, RuleSetError::FrequencyCheckFailed);
+
+    // --------------------------------
+    // Validate pass after period
+    // --------------------------------
+    // Move forward and advance the clock by the period.
+    context.warp_to_slot(4).unwrap();
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += PERIOD;
+    context.set_sysvar(&clock);
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![&rule_authority], None).await;
+
+    // Check that the last update time was updated.
+    let state_account = context
+        .banks_client
+        .get_account(rule_set_state_addr)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        FrequencyAccount::try_from_slice(&state_account.data).unwrap(),
+        FrequencyAccount::new(vec![FrequencyState {
+            operation: Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            period: PERIOD,
+            last_update: clock.unix_timestamp,
+        }])
+    );
 }
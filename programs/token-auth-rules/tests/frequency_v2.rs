@@ -2,18 +2,22 @@
 
 pub mod utils;
 
+use borsh::BorshDeserialize;
 use mpl_token_auth_rules::{
     error::RuleSetError,
     instruction::{builders::ValidateBuilder, InstructionBuilder, ValidateArgs},
     payload::Payload,
     pda::find_rule_set_state_address,
-    state::{Frequency, RuleSetV2},
+    state::{Frequency, FrequencyAccount, FrequencyState, Pass, RuleSetV2},
 };
-use solana_program::program_error::ProgramError;
+use solana_program::{clock::Clock, program_error::ProgramError};
 use solana_program_test::{tokio, BanksClientError};
 use solana_sdk::{signature::Signer, signer::keypair::Keypair, transaction::TransactionError};
 use utils::{program_test, Operation};
 
+// Period (in seconds) between allowed operations.
+const PERIOD: i64 = 60;
+
 #[tokio::test]
 async fn test_frequency_v2() {
     let mut context = program_test().start_with_context().await;
@@ -23,7 +27,7 @@ async fn test_frequency_v2() {
     // --------------------------------
     // Create a Rule.
     let rule_authority = Keypair::new();
-    let rule = Frequency::serialize(rule_authority.pubkey()).unwrap();
+    let rule = Frequency::serialize(rule_authority.pubkey(), PERIOD).unwrap();
 
     // Create a RuleSet.
     let rule_set = RuleSetV2::serialize(
@@ -114,8 +118,7 @@ async fn test_frequency_v2() {
     assert_custom_error!(err, RuleSetError::RuleAuthorityIsNotSigner);
 
     // --------------------------------
-    // Validate not implemented
-    // (this will become pass later)
+    // Validate pass
     // --------------------------------
     // Create a `validate` instruction.
     let validate_ix = ValidateBuilder::new()
@@ -137,10 +140,234 @@ async fn test_frequency_v2() {
         .unwrap()
         .instruction();
 
+    // Validate Transfer operation, which creates the `RuleSet` state PDA.
+    process_passing_validate_ix!(
+        &mut context,
+        validate_ix.clone(),
+        vec![&rule_authority],
+        None
+    )
+    .await;
+
+    // Check that the last update time and period were stored in the `RuleSet` state PDA.
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    let state_account = context
+        .banks_client
+        .get_account(rule_set_state_addr)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        FrequencyAccount::try_from_slice(&state_account.data).unwrap(),
+        FrequencyAccount::new(vec![FrequencyState {
+            operation: Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            period: PERIOD,
+            last_update: clock.unix_timestamp,
+        }])
+    );
+
+    // --------------------------------
+    // Validate fail within period
+    // --------------------------------
+    // Move forward to avoid duplicate transactions.
+    context.warp_to_slot(3).unwrap();
+
     // Fail to validate Transfer operation.
-    let err =
-        process_failing_validate_ix!(&mut context, validate_ix, vec![&rule_authority], None).await;
+    let err = process_failing_validate_ix!(
+        &mut context,
+        validate_ix.clone(),
+        vec![&rule_authority],
+        None
+    )
+    .await;
 
     // Check that error is what we expect.
-    assert_custom_error!(err, RuleSetError::NotImplemented);
+    assert_custom_error!(err, RuleSetError::FrequencyCheckFailed);
+
+    // --------------------------------
+    // Validate pass after period
+    // --------------------------------
+    // Move forward and advance the clock by the period.
+    context.warp_to_slot(4).unwrap();
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += PERIOD;
+    context.set_sysvar(&clock);
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![&rule_authority], None).await;
+
+    // Check that the last update time was updated.
+    let state_account = context
+        .banks_client
+        .get_account(rule_set_state_addr)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        FrequencyAccount::try_from_slice(&state_account.data).unwrap(),
+        FrequencyAccount::new(vec![FrequencyState {
+            operation: Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            period: PERIOD,
+            last_update: clock.unix_timestamp,
+        }])
+    );
+}
+
+#[tokio::test]
+async fn test_frequency_v2_per_operation() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // Create a Frequency rule for two operations and a Pass rule for a third one.
+    let rule_authority = Keypair::new();
+    let rule = Frequency::serialize(rule_authority.pubkey(), PERIOD).unwrap();
+    let pass = Pass::serialize().unwrap();
+
+    let holder = Operation::Transfer {
+        scenario: utils::TransferScenario::Holder,
+    }
+    .to_string();
+    let sale = Operation::Transfer {
+        scenario: utils::TransferScenario::SaleDelegate,
+    }
+    .to_string();
+    let wallet = Operation::Transfer {
+        scenario: utils::TransferScenario::WalletToWallet,
+    }
+    .to_string();
+
+    // Create a RuleSet.
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "test rule_set",
+        &[holder.clone(), sale.clone(), wallet.clone()],
+        &[&rule, &rule, &pass],
+    )
+    .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, "test rule_set".to_string())
+            .await;
+
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+
+    let (rule_set_state_addr, _rule_set_bump) =
+        find_rule_set_state_address(context.payer.pubkey(), "test rule_set".to_string(), mint);
+
+    let payer = context.payer.pubkey();
+    let validate_ix = |operation: &String| {
+        ValidateBuilder::new()
+            .rule_set_pda(rule_set_addr)
+            .mint(mint)
+            .payer(payer)
+            .rule_authority(rule_authority.pubkey())
+            .rule_set_state_pda(rule_set_state_addr)
+            .additional_rule_accounts(vec![])
+            .build(ValidateArgs::V1 {
+                operation: operation.clone(),
+                payload: Payload::default(),
+                update_rule_state: true,
+                rule_set_revision: None,
+            })
+            .unwrap()
+            .instruction()
+    };
+
+    // --------------------------------
+    // Validate pass without state
+    // --------------------------------
+    // Validate the operation without a Frequency rule.
+    process_passing_validate_ix!(
+        &mut context,
+        validate_ix(&wallet),
+        vec![&rule_authority],
+        None
+    )
+    .await;
+
+    // Check that the `RuleSet` state PDA was not created.
+    assert!(context
+        .banks_client
+        .get_account(rule_set_state_addr)
+        .await
+        .unwrap()
+        .is_none());
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    // Validate the first operation, which creates the `RuleSet` state PDA.
+    process_passing_validate_ix!(
+        &mut context,
+        validate_ix(&holder),
+        vec![&rule_authority],
+        None
+    )
+    .await;
+
+    let holder_update: Clock = context.banks_client.get_sysvar().await.unwrap();
+
+    // --------------------------------
+    // Validate fail within period
+    // --------------------------------
+    // Move forward to avoid duplicate transactions.
+    context.warp_to_slot(3).unwrap();
+
+    // Fail to validate the first operation again.
+    let err = process_failing_validate_ix!(
+        &mut context,
+        validate_ix(&holder),
+        vec![&rule_authority],
+        None
+    )
+    .await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::FrequencyCheckFailed);
+
+    // --------------------------------
+    // Validate pass for another operation
+    // --------------------------------
+    // The second operation has its own state, so it is not limited by the first one.
+    process_passing_validate_ix!(
+        &mut context,
+        validate_ix(&sale),
+        vec![&rule_authority],
+        None
+    )
+    .await;
+
+    // Check that the state of both operations is stored in the `RuleSet` state PDA.
+    let sale_update: Clock = context.banks_client.get_sysvar().await.unwrap();
+    let state_account = context
+        .banks_client
+        .get_account(rule_set_state_addr)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        FrequencyAccount::try_from_slice(&state_account.data).unwrap(),
+        FrequencyAccount::new(vec![
+            FrequencyState {
+                operation: holder,
+                period: PERIOD,
+                last_update: holder_update.unix_timestamp,
+            },
+            FrequencyState {
+                operation: sale,
+                period: PERIOD,
+                last_update: sale_update.unix_timestamp,
+            },
+        ])
+    );
 }
@@ -0,0 +1,133 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    payload::{Payload, PayloadType},
+    state::{InstructionMatch, RuleSetV2},
+};
+use solana_program::{
+    instruction::AccountMeta, pubkey::Pubkey, system_instruction, system_program, sysvar,
+};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{process_instructions, program_test, transfer_validate_ix, Operation, PayloadKey};
+
+#[tokio::test]
+async fn instruction_match_v2() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // The transaction must also transfer lamports to the destination.
+    let rule = InstructionMatch::serialize(
+        system_program::ID,
+        // `SystemInstruction::Transfer`
+        &u32::to_le_bytes(2),
+        Some(PayloadKey::Destination.to_string()),
+        1,
+    )
+    .unwrap();
+
+    // Create a RuleSet.
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "test rule_set",
+        &[Operation::Transfer {
+            scenario: utils::TransferScenario::Holder,
+        }
+        .to_string()],
+        &[&rule],
+    )
+    .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, "test rule_set".to_string())
+            .await;
+
+    // --------------------------------
+    // Validate fail missing account
+    // --------------------------------
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+    let destination = Keypair::new().pubkey();
+
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        instruction_match_payload(destination),
+        vec![],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::MissingAccount);
+
+    // --------------------------------
+    // Validate fail no matching instruction
+    // --------------------------------
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        instruction_match_payload(destination),
+        vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::InstructionMatchCheckFailed);
+
+    // --------------------------------
+    // Validate fail account mismatch
+    // --------------------------------
+    // The lamports are transferred to another account.
+    let transfer_ix =
+        system_instruction::transfer(&context.payer.pubkey(), &Keypair::new().pubkey(), 1_000_000);
+
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        instruction_match_payload(destination),
+        vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_instructions(&mut context, &[transfer_ix, validate_ix], &[])
+        .await
+        .unwrap_err();
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::InstructionMatchCheckFailed);
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    let transfer_ix =
+        system_instruction::transfer(&context.payer.pubkey(), &destination, 1_000_000);
+
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        instruction_match_payload(destination),
+        vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+    );
+
+    // Validate Transfer operation.
+    process_instructions(&mut context, &[transfer_ix, validate_ix], &[])
+        .await
+        .unwrap();
+}
+
+fn instruction_match_payload(destination: Pubkey) -> Payload {
+    Payload::from([(
+        PayloadKey::Destination.to_string(),
+        PayloadType::Pubkey(destination),
+    )])
+}
@@ -8,8 +8,13 @@ use mpl_token_auth_rules::{
     payload::{Payload, PayloadType},
     state::{IsWallet, RuleSetV2},
 };
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_instruction, system_program,
+};
 use solana_program_test::tokio;
-use solana_sdk::{instruction::AccountMeta, signature::Signer, signer::keypair::Keypair};
+use solana_sdk::{signature::Signer, signer::keypair::Keypair, transaction::Transaction};
 use utils::{program_test, Operation, PayloadKey};
 
 #[tokio::test]
@@ -40,25 +45,105 @@ async fn is_wallet_v2() {
             .await;
 
     // --------------------------------
-    // Validate not implemented
-    // (this will become pass later)
+    // Fund accounts
     // --------------------------------
     // Keypair to check.
     let wallet = Keypair::new();
 
+    // PDA owned by the System Program, which can hold lamports but cannot sign.
+    let (system_owned_pda, _bump) =
+        Pubkey::find_program_address(&[b"system_owned_pda"], &mpl_token_auth_rules::ID);
+
+    // Keypair account owned by a program other than the System Program.
+    let program_owned_account = Keypair::new();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            system_instruction::transfer(
+                &context.payer.pubkey(),
+                &wallet.pubkey(),
+                rent.minimum_balance(0),
+            ),
+            system_instruction::transfer(
+                &context.payer.pubkey(),
+                &system_owned_pda,
+                rent.minimum_balance(0),
+            ),
+            system_instruction::create_account(
+                &context.payer.pubkey(),
+                &program_owned_account.pubkey(),
+                rent.minimum_balance(0),
+                0,
+                &mpl_token_auth_rules::ID,
+            ),
+        ],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &program_owned_account],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Verify account ownership.
+    let on_chain_account = context
+        .banks_client
+        .get_account(system_owned_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(system_program::ID, on_chain_account.owner);
+
     // Create a Keypair to simulate a token mint address.
     let mint = Keypair::new().pubkey();
 
-    let payload = Payload::from([(
-        PayloadKey::Source.to_string(),
-        PayloadType::Pubkey(wallet.pubkey()),
-    )]);
+    // --------------------------------
+    // Validate fail system-owned PDA
+    // --------------------------------
+    let err = process_failing_validate_ix!(
+        &mut context,
+        is_wallet_validate_ix(rule_set_addr, mint, system_owned_pda),
+        vec![],
+        None
+    )
+    .await;
 
-    // Create a `validate` instruction.
-    let validate_ix = ValidateBuilder::new()
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::IsWalletCheckFailed);
+
+    // --------------------------------
+    // Validate fail not owned by the System Program
+    // --------------------------------
+    let err = process_failing_validate_ix!(
+        &mut context,
+        is_wallet_validate_ix(rule_set_addr, mint, program_owned_account.pubkey()),
+        vec![],
+        None
+    )
+    .await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::IsWalletCheckFailed);
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    process_passing_validate_ix!(
+        &mut context,
+        is_wallet_validate_ix(rule_set_addr, mint, wallet.pubkey()),
+        vec![],
+        None
+    )
+    .await;
+}
+
+fn is_wallet_validate_ix(rule_set_addr: Pubkey, mint: Pubkey, source: Pubkey) -> Instruction {
+    let payload = Payload::from([(PayloadKey::Source.to_string(), PayloadType::Pubkey(source))]);
+
+    ValidateBuilder::new()
         .rule_set_pda(rule_set_addr)
         .mint(mint)
-        .additional_rule_accounts(vec![AccountMeta::new_readonly(wallet.pubkey(), false)])
+        .additional_rule_accounts(vec![AccountMeta::new_readonly(source, false)])
         .build(ValidateArgs::V1 {
             operation: Operation::Transfer {
                 scenario: utils::TransferScenario::Holder,
@@ -69,11 +154,5 @@ async fn is_wallet_v2() {
             rule_set_revision: None,
         })
         .unwrap()
-        .instruction();
-
-    // Fail to validate Transfer operation.
-    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
-
-    // Check that error is what we expect.
-    assert_custom_error!(err, RuleSetError::NotImplemented);
+        .instruction()
 }
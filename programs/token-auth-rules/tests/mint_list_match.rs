@@ -0,0 +1,69 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    payload::Payload,
+    state::{Rule, RuleSetV1},
+};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{program_test, transfer_validate_ix, Operation};
+
+#[tokio::test]
+async fn test_mint_list_match() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // The mint must be one of the collection mints.
+    let mint_1 = Keypair::new().pubkey();
+    let mint_2 = Keypair::new().pubkey();
+
+    let rule = Rule::MintListMatch {
+        mints: vec![mint_1, mint_2],
+    };
+
+    // Create a RuleSet.
+    let mut rule_set = RuleSetV1::new("test rule_set".to_string(), context.payer.pubkey());
+    rule_set
+        .add(
+            Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            rule,
+        )
+        .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain!(&mut context, rule_set, "test rule_set".to_string()).await;
+
+    // --------------------------------
+    // Validate fail
+    // --------------------------------
+    // The mint passed to `Validate` is not in the list.
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        Keypair::new().pubkey(),
+        Payload::default(),
+        vec![],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::MintListMatchCheckFailed);
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    let validate_ix = transfer_validate_ix(rule_set_addr, mint_2, Payload::default(), vec![]);
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    payload::{Payload, PayloadType, ProofInfo},
+    state::{MintTreeMatch, RuleSetV2},
+};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::tokio;
+use solana_sdk::signature::Signer;
+use utils::{program_test, transfer_validate_ix, Operation, PayloadKey};
+
+#[tokio::test]
+async fn mint_tree_match_v2() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // Merkle tree root generated in a different test program.
+    let tree_root: [u8; 32] = [
+        132, 141, 27, 31, 23, 154, 145, 128, 32, 62, 122, 224, 248, 128, 37, 139, 200, 46, 163,
+        238, 76, 123, 155, 141, 73, 12, 111, 192, 122, 80, 126, 155,
+    ];
+
+    // Create a Rule: The mint must be a member of the collection Merkle tree.
+    let rule = MintTreeMatch::serialize(PayloadKey::MintProof.to_string(), &tree_root).unwrap();
+
+    // Create a RuleSet.
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "test rule_set",
+        &[Operation::Transfer {
+            scenario: utils::TransferScenario::Holder,
+        }
+        .to_string()],
+        &[&rule],
+    )
+    .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, "test rule_set".to_string())
+            .await;
+
+    // Merkle tree leaf node generated in a different test program, used as the mint.
+    let mint = Pubkey::from([
+        2, 157, 245, 156, 21, 37, 147, 96, 42, 190, 206, 14, 24, 1, 106, 49, 167, 236, 38, 73, 98,
+        53, 60, 9, 154, 31, 240, 126, 210, 197, 76, 7,
+    ]);
+
+    // Merkle tree proof generated in a different test program.
+    let proof: Vec<[u8; 32]> = vec![
+        [
+            246, 54, 96, 185, 234, 119, 124, 220, 54, 137, 25, 200, 18, 12, 114, 75, 211, 203, 154,
+            229, 197, 53, 164, 84, 38, 56, 20, 74, 192, 119, 37, 175,
+        ],
+        [
+            193, 84, 33, 232, 119, 107, 227, 166, 30, 233, 40, 10, 51, 229, 90, 59, 165, 212, 67,
+            193, 159, 126, 26, 200, 13, 209, 162, 98, 52, 125, 240, 77,
+        ],
+        [
+            238, 14, 13, 214, 124, 172, 89, 7, 66, 168, 226, 88, 92, 22, 18, 17, 94, 96, 37, 234,
+            101, 96, 129, 26, 137, 222, 96, 86, 245, 11, 199, 140,
+        ],
+    ];
+
+    // --------------------------------
+    // Validate fail
+    // --------------------------------
+    // The proof is valid for the leaf, but a different mint is passed to `Validate`.
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        Pubkey::new_unique(),
+        mint_tree_match_payload(proof.clone()),
+        vec![],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::MintTreeMatchCheckFailed);
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    let validate_ix =
+        transfer_validate_ix(rule_set_addr, mint, mint_tree_match_payload(proof), vec![]);
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+}
+
+fn mint_tree_match_payload(proof: Vec<[u8; 32]>) -> Payload {
+    Payload::from([(
+        PayloadKey::MintProof.to_string(),
+        PayloadType::MerkleProof(ProofInfo::new(proof)),
+    )])
+}
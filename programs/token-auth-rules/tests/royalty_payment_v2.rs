@@ -0,0 +1,246 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    payload::{Payload, PayloadType},
+    state::{RoyaltyPayment, RuleSetV2},
+};
+use solana_program::{instruction::AccountMeta, system_instruction, sysvar};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{
+    create_associated_token_account, create_mint, process_instructions, program_test,
+    transfer_validate_ix, Operation, PayloadKey,
+};
+
+#[tokio::test]
+async fn royalty_payment_lamports_v2() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // The creators must be paid at least 5% of the sale price.
+    let creators = [Keypair::new().pubkey(), Keypair::new().pubkey()];
+    let rule =
+        RoyaltyPayment::serialize(PayloadKey::Amount.to_string(), None, 500, &creators).unwrap();
+
+    // Create a RuleSet.
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "test rule_set",
+        &[Operation::Transfer {
+            scenario: utils::TransferScenario::Holder,
+        }
+        .to_string()],
+        &[&rule],
+    )
+    .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, "test rule_set".to_string())
+            .await;
+
+    // --------------------------------
+    // Validate fail insufficient royalties
+    // --------------------------------
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+    let price = 1_000_000_000;
+
+    // Only 3% of the sale price is paid.
+    let payer = context.payer.pubkey();
+    let royalty_ixs = [
+        system_instruction::transfer(&payer, &creators[0], 30_000_000),
+        transfer_validate_ix(
+            rule_set_addr,
+            mint,
+            royalty_payment_payload(price),
+            vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+        ),
+    ];
+
+    // Fail to validate Transfer operation.
+    let err = process_instructions(&mut context, &royalty_ixs, &[])
+        .await
+        .unwrap_err();
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::RoyaltyPaymentCheckFailed);
+
+    // --------------------------------
+    // Validate fail payment to another account
+    // --------------------------------
+    let royalty_ixs = [
+        system_instruction::transfer(&payer, &Keypair::new().pubkey(), 50_000_000),
+        transfer_validate_ix(
+            rule_set_addr,
+            mint,
+            royalty_payment_payload(price),
+            vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+        ),
+    ];
+
+    // Fail to validate Transfer operation.
+    let err = process_instructions(&mut context, &royalty_ixs, &[])
+        .await
+        .unwrap_err();
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::RoyaltyPaymentCheckFailed);
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    // 5% of the sale price is split between the creators.
+    let royalty_ixs = [
+        system_instruction::transfer(&payer, &creators[0], 30_000_000),
+        system_instruction::transfer(&payer, &creators[1], 20_000_000),
+        transfer_validate_ix(
+            rule_set_addr,
+            mint,
+            royalty_payment_payload(price),
+            vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+        ),
+    ];
+
+    // Validate Transfer operation.
+    process_instructions(&mut context, &royalty_ixs, &[])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn royalty_payment_tokens_v2() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create the token accounts
+    // --------------------------------
+    let payment_mint = Keypair::new();
+    let authority = Keypair::new();
+
+    create_mint(&mut context, &payment_mint, &authority.pubkey(), None, 0)
+        .await
+        .unwrap();
+
+    let buyer = Keypair::new();
+    let buyer_account =
+        create_associated_token_account(&mut context, &buyer, &payment_mint.pubkey())
+            .await
+            .unwrap();
+
+    let creator = Keypair::new();
+    let creator_account =
+        create_associated_token_account(&mut context, &creator, &payment_mint.pubkey())
+            .await
+            .unwrap();
+
+    let mint_to_ix = spl_token::instruction::mint_to(
+        &spl_token::ID,
+        &payment_mint.pubkey(),
+        &buyer_account,
+        &authority.pubkey(),
+        &[],
+        1_000,
+    )
+    .unwrap();
+
+    process_instructions(&mut context, &[mint_to_ix], &[&authority])
+        .await
+        .unwrap();
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // The creator must be paid at least 10% of the sale price in tokens of the payment mint.
+    let rule = RoyaltyPayment::serialize(
+        PayloadKey::Amount.to_string(),
+        Some(payment_mint.pubkey()),
+        1_000,
+        &[creator.pubkey()],
+    )
+    .unwrap();
+
+    // Create a RuleSet.
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "test rule_set",
+        &[Operation::Transfer {
+            scenario: utils::TransferScenario::Holder,
+        }
+        .to_string()],
+        &[&rule],
+    )
+    .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, "test rule_set".to_string())
+            .await;
+
+    // --------------------------------
+    // Validate fail missing token account
+    // --------------------------------
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+
+    let transfer_ix = spl_token::instruction::transfer_checked(
+        &spl_token::ID,
+        &buyer_account,
+        &payment_mint.pubkey(),
+        &creator_account,
+        &buyer.pubkey(),
+        &[],
+        10,
+        0,
+    )
+    .unwrap();
+
+    // The creator token account is not provided, so the payment is not counted.
+    let royalty_ixs = [
+        transfer_ix.clone(),
+        transfer_validate_ix(
+            rule_set_addr,
+            mint,
+            royalty_payment_payload(100),
+            vec![AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+        ),
+    ];
+
+    // Fail to validate Transfer operation.
+    let err = process_instructions(&mut context, &royalty_ixs, &[&buyer])
+        .await
+        .unwrap_err();
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::RoyaltyPaymentCheckFailed);
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    let royalty_ixs = [
+        transfer_ix,
+        transfer_validate_ix(
+            rule_set_addr,
+            mint,
+            royalty_payment_payload(100),
+            vec![
+                AccountMeta::new_readonly(creator_account, false),
+                AccountMeta::new_readonly(sysvar::instructions::ID, false),
+            ],
+        ),
+    ];
+
+    // Validate Transfer operation.
+    process_instructions(&mut context, &royalty_ixs, &[&buyer])
+        .await
+        .unwrap();
+}
+
+fn royalty_payment_payload(price: u64) -> Payload {
+    Payload::from([(PayloadKey::Amount.to_string(), PayloadType::Number(price))])
+}
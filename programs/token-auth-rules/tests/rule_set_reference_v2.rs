@@ -0,0 +1,207 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    payload::{Payload, PayloadType},
+    pda::find_rule_set_address,
+    state::{Pass, PubkeyListMatch, RuleSetReference, RuleSetV2},
+};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{program_test, transfer_validate_ix, Operation, PayloadKey};
+
+#[tokio::test]
+async fn rule_set_reference_v2() {
+    let mut context = program_test().start_with_context().await;
+
+    let operation = Operation::Transfer {
+        scenario: utils::TransferScenario::Holder,
+    }
+    .to_string();
+
+    // --------------------------------
+    // Create RuleSets
+    // --------------------------------
+    // The shared RuleSet holds the marketplace allowlist.
+    let marketplace = Keypair::new().pubkey();
+
+    let rule =
+        PubkeyListMatch::serialize(PayloadKey::Authority.to_string(), &[marketplace]).unwrap();
+
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "shared rule_set",
+        &[operation.clone()],
+        &[&rule],
+    )
+    .unwrap();
+
+    let shared_addr =
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, "shared rule_set".to_string())
+            .await;
+
+    // The collection RuleSet references the shared RuleSet for the same operation.
+    let rule = RuleSetReference::serialize(shared_addr, None, None).unwrap();
+
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "test rule_set",
+        &[operation.clone()],
+        &[&rule],
+    )
+    .unwrap();
+
+    let rule_set_addr =
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, "test rule_set".to_string())
+            .await;
+
+    // --------------------------------
+    // Validate fail missing RuleSet
+    // --------------------------------
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        rule_set_reference_payload(marketplace),
+        vec![],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::MissingAccount);
+
+    // --------------------------------
+    // Validate fail shared rule
+    // --------------------------------
+    // The authority is not in the allowlist of the shared RuleSet.
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        rule_set_reference_payload(Keypair::new().pubkey()),
+        rule_set_reference_accounts(vec![shared_addr]),
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::PubkeyListMatchCheckFailed);
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        rule_set_reference_payload(marketplace),
+        rule_set_reference_accounts(vec![shared_addr]),
+    );
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // --------------------------------
+    // Validate fail cycle
+    // --------------------------------
+    // A RuleSet referencing itself.
+    let (cycle_addr, _) =
+        find_rule_set_address(context.payer.pubkey(), "cycle rule_set".to_string());
+
+    let rule = RuleSetReference::serialize(cycle_addr, None, None).unwrap();
+
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "cycle rule_set",
+        &[operation.clone()],
+        &[&rule],
+    )
+    .unwrap();
+
+    create_rule_set_on_chain_serialized!(&mut context, rule_set, "cycle rule_set".to_string())
+        .await;
+
+    let validate_ix = transfer_validate_ix(
+        cycle_addr,
+        mint,
+        rule_set_reference_payload(marketplace),
+        rule_set_reference_accounts(vec![cycle_addr]),
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::RuleSetReferenceCycle);
+
+    // --------------------------------
+    // Validate fail depth
+    // --------------------------------
+    // A chain of RuleSets, each referencing the next one, the last one passing.
+    let names = (0..5)
+        .map(|index| format!("depth {} rule_set", index))
+        .collect::<Vec<String>>();
+
+    let addresses = names
+        .iter()
+        .map(|name| find_rule_set_address(context.payer.pubkey(), name.clone()).0)
+        .collect::<Vec<Pubkey>>();
+
+    for (index, name) in names.iter().enumerate() {
+        let rule = match addresses.get(index + 1) {
+            Some(next) => RuleSetReference::serialize(*next, None, None).unwrap(),
+            None => Pass::serialize().unwrap(),
+        };
+
+        let rule_set =
+            RuleSetV2::serialize(context.payer.pubkey(), name, &[operation.clone()], &[&rule])
+                .unwrap();
+
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, name.clone()).await;
+    }
+
+    // Five chained RuleSets exceed the maximum depth.
+    let validate_ix = transfer_validate_ix(
+        addresses[0],
+        mint,
+        rule_set_reference_payload(marketplace),
+        rule_set_reference_accounts(addresses.clone()),
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::RuleSetReferenceTooDeep);
+
+    // Four chained RuleSets are allowed.
+    let validate_ix = transfer_validate_ix(
+        addresses[1],
+        mint,
+        rule_set_reference_payload(marketplace),
+        rule_set_reference_accounts(addresses[1..].to_vec()),
+    );
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+}
+
+fn rule_set_reference_payload(authority: Pubkey) -> Payload {
+    Payload::from([(
+        PayloadKey::Authority.to_string(),
+        PayloadType::Pubkey(authority),
+    )])
+}
+
+fn rule_set_reference_accounts(rule_sets: Vec<Pubkey>) -> Vec<AccountMeta> {
+    rule_sets
+        .into_iter()
+        .map(|rule_set| AccountMeta::new_readonly(rule_set, false))
+        .collect()
+}
@@ -0,0 +1,254 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use borsh::BorshDeserialize;
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    instruction::{builders::SimulateBuilder, InstructionBuilder, SimulateArgs},
+    payload::{Payload, PayloadType},
+    state::{
+        AdditionalSigner, All, Amount, Any, ConstraintType, Not, Operator, Pass, Rule, RuleOutcome,
+        RuleSetV1, RuleSetV2, RuleTrace, RuleTraceEntry,
+    },
+};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{signature::Signer, signer::keypair::Keypair, transaction::Transaction};
+use utils::{program_test, Operation, PayloadKey};
+
+async fn process_simulate_ix(
+    context: &mut ProgramTestContext,
+    simulate_ix: Instruction,
+    additional_signers: &[&Keypair],
+) -> RuleTrace {
+    let mut signers = vec![&context.payer];
+    signers.extend(additional_signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[simulate_ix],
+        Some(&context.payer.pubkey()),
+        &signers,
+        context.last_blockhash,
+    );
+
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+    result.result.unwrap();
+
+    let return_data = result.metadata.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, mpl_token_auth_rules::ID);
+
+    RuleTrace::try_from_slice(&return_data.data).unwrap()
+}
+
+fn entry(
+    depth: u8,
+    constraint_type: ConstraintType,
+    outcome: RuleOutcome,
+    error: RuleSetError,
+) -> RuleTraceEntry {
+    RuleTraceEntry {
+        depth,
+        constraint_type: constraint_type as u32,
+        outcome,
+        error: ProgramError::from(error).into(),
+    }
+}
+
+#[tokio::test]
+async fn test_simulate() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // Create some rules.
+    let second_signer = Keypair::new();
+
+    let adtl_signer = AdditionalSigner::serialize(second_signer.pubkey()).unwrap();
+    let amount_check = Amount::serialize(PayloadKey::Amount.to_string(), Operator::Lt, 5).unwrap();
+    let pass = Pass::serialize().unwrap();
+    let not_pass = Not::serialize(&pass).unwrap();
+    let any = Any::serialize(&[&amount_check, &not_pass]).unwrap();
+
+    let overall_rule = All::serialize(&[&adtl_signer, &any]).unwrap();
+
+    // Create a RuleSet.
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "test rule_set",
+        &[Operation::Transfer {
+            scenario: utils::TransferScenario::Holder,
+        }
+        .to_string()],
+        &[&overall_rule],
+    )
+    .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, "test rule_set".to_string())
+            .await;
+
+    // --------------------------------
+    // Simulate a failing validation
+    // --------------------------------
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+
+    // Create a `simulate` instruction without the additional signer signing and with the amount
+    // missing from the payload.
+    let simulate_ix = SimulateBuilder::new()
+        .rule_set_pda(rule_set_addr)
+        .mint(mint)
+        .additional_rule_accounts(vec![AccountMeta::new_readonly(
+            second_signer.pubkey(),
+            false,
+        )])
+        .build(SimulateArgs::V1 {
+            operation: Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            payload: Payload::default(),
+            rule_set_revision: None,
+        })
+        .unwrap()
+        .instruction();
+
+    // The instruction succeeds and returns the evaluation of every constraint.
+    let trace = process_simulate_ix(&mut context, simulate_ix, &[]).await;
+
+    assert!(!trace.truncated);
+    assert_eq!(
+        trace.entries,
+        vec![
+            entry(
+                0,
+                ConstraintType::All,
+                RuleOutcome::Failure,
+                RuleSetError::AdditionalSignerCheckFailed
+            ),
+            entry(
+                1,
+                ConstraintType::AdditionalSigner,
+                RuleOutcome::Failure,
+                RuleSetError::AdditionalSignerCheckFailed
+            ),
+            entry(
+                1,
+                ConstraintType::Any,
+                RuleOutcome::Failure,
+                RuleSetError::UnexpectedRuleSetFailure
+            ),
+            entry(
+                2,
+                ConstraintType::Amount,
+                RuleOutcome::Error,
+                RuleSetError::MissingPayloadValue
+            ),
+            entry(
+                2,
+                ConstraintType::Not,
+                RuleOutcome::Failure,
+                RuleSetError::UnexpectedRuleSetFailure
+            ),
+            entry(
+                3,
+                ConstraintType::Pass,
+                RuleOutcome::Success,
+                RuleSetError::UnexpectedRuleSetFailure
+            ),
+        ]
+    );
+
+    // --------------------------------
+    // Simulate a passing validation
+    // --------------------------------
+    let payload = Payload::from([(PayloadKey::Amount.to_string(), PayloadType::Number(4))]);
+
+    let simulate_ix = SimulateBuilder::new()
+        .rule_set_pda(rule_set_addr)
+        .mint(mint)
+        .additional_rule_accounts(vec![AccountMeta::new_readonly(
+            second_signer.pubkey(),
+            true,
+        )])
+        .build(SimulateArgs::V1 {
+            operation: Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            payload,
+            rule_set_revision: None,
+        })
+        .unwrap()
+        .instruction();
+
+    let trace = process_simulate_ix(&mut context, simulate_ix, &[&second_signer]).await;
+
+    assert_eq!(trace.entries.len(), 6);
+    assert_eq!(trace.entries[0].outcome, RuleOutcome::Success);
+    assert_eq!(
+        trace.entries[3],
+        entry(
+            2,
+            ConstraintType::Amount,
+            RuleOutcome::Success,
+            RuleSetError::AmountCheckFailed
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_simulate_v1_rule_set_fails() {
+    let mut context = program_test().start_with_context().await;
+
+    // Create a RuleSet.
+    let mut rule_set = RuleSetV1::new("test rule_set".to_string(), context.payer.pubkey());
+    rule_set
+        .add(
+            Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            Rule::Pass,
+        )
+        .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain!(&mut context, rule_set, "test rule_set".to_string()).await;
+
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+
+    let simulate_ix = SimulateBuilder::new()
+        .rule_set_pda(rule_set_addr)
+        .mint(mint)
+        .additional_rule_accounts(vec![])
+        .build(SimulateArgs::V1 {
+            operation: Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            payload: Payload::default(),
+            rule_set_revision: None,
+        })
+        .unwrap()
+        .instruction();
+
+    // Only `RuleSetV2` rule trees can be traced.
+    let err = utils::process_instructions(&mut context, &[simulate_ix], &[])
+        .await
+        .unwrap_err();
+
+    assert_custom_error!(err, RuleSetError::UnsupportedRuleSetVersion);
+}
@@ -0,0 +1,147 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    payload::Payload,
+    state::{Rule, RuleSetV1},
+};
+use solana_program::instruction::AccountMeta;
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{program_test, transfer_validate_ix, Operation};
+
+#[tokio::test]
+async fn test_threshold() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // Create some rules.
+    let guardians = [Keypair::new(), Keypair::new(), Keypair::new()];
+
+    // At least two of the three guardians must sign.
+    let overall_rule = Rule::Threshold {
+        k: 2,
+        rules: guardians
+            .iter()
+            .map(|guardian| Rule::AdditionalSigner {
+                account: guardian.pubkey(),
+            })
+            .collect(),
+    };
+
+    // Create a RuleSet.
+    let mut rule_set = RuleSetV1::new("test rule_set".to_string(), context.payer.pubkey());
+    rule_set
+        .add(
+            Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            overall_rule,
+        )
+        .unwrap();
+
+    println!("{:#?}", rule_set);
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain!(&mut context, rule_set, "test rule_set".to_string()).await;
+
+    // --------------------------------
+    // Validate fail
+    // --------------------------------
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+
+    // Only the first guardian signs.
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        Payload::default(),
+        vec![
+            AccountMeta::new_readonly(guardians[0].pubkey(), true),
+            AccountMeta::new_readonly(guardians[1].pubkey(), false),
+            AccountMeta::new_readonly(guardians[2].pubkey(), false),
+        ],
+    );
+
+    // Fail to validate Transfer operation.
+    let err =
+        process_failing_validate_ix!(&mut context, validate_ix, vec![&guardians[0]], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::ThresholdCheckFailed);
+
+    // --------------------------------
+    // Validate fail threshold unreachable
+    // --------------------------------
+    // The first two guardians do not sign, so the third guardian is never checked and its
+    // missing account does not roll up.
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        Payload::default(),
+        vec![
+            AccountMeta::new_readonly(guardians[0].pubkey(), false),
+            AccountMeta::new_readonly(guardians[1].pubkey(), false),
+        ],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::ThresholdCheckFailed);
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    // The first and last guardians sign.
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        Payload::default(),
+        vec![
+            AccountMeta::new_readonly(guardians[0].pubkey(), true),
+            AccountMeta::new_readonly(guardians[1].pubkey(), false),
+            AccountMeta::new_readonly(guardians[2].pubkey(), true),
+        ],
+    );
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(
+        &mut context,
+        validate_ix,
+        vec![&guardians[0], &guardians[2]],
+        None
+    )
+    .await;
+
+    // --------------------------------
+    // Validate pass threshold reached
+    // --------------------------------
+    // The first two guardians sign, so the third guardian is never checked and its missing
+    // account does not roll up.
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        Payload::default(),
+        vec![
+            AccountMeta::new_readonly(guardians[0].pubkey(), true),
+            AccountMeta::new_readonly(guardians[1].pubkey(), true),
+        ],
+    );
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(
+        &mut context,
+        validate_ix,
+        vec![&guardians[0], &guardians[1]],
+        None
+    )
+    .await;
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    instruction::{builders::ValidateBuilder, InstructionBuilder, ValidateArgs},
+    payload::Payload,
+    state::{All, RuleSetV2, TimeSource, TimeWindow},
+};
+use solana_program::clock::Clock;
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{program_test, Operation};
+
+// Seconds until the reveal date.
+const LOCKUP: i64 = 3600;
+
+// End (exclusive) of the slot window.
+const END_SLOT: i64 = 100;
+
+#[tokio::test]
+async fn time_window_v2() {
+    let mut context = program_test().start_with_context().await;
+
+    // Lock transfers until the reveal date.
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    let reveal = clock.unix_timestamp + LOCKUP;
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // Create some rules.
+    let after_reveal = TimeWindow::serialize(TimeSource::UnixTimestamp, reveal, i64::MAX).unwrap();
+    let before_end_slot = TimeWindow::serialize(TimeSource::Slot, 0, END_SLOT).unwrap();
+    let overall_rule = All::serialize(&[&after_reveal, &before_end_slot]).unwrap();
+
+    // Create a RuleSet.
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "test rule_set",
+        &[Operation::Transfer {
+            scenario: utils::TransferScenario::Holder,
+        }
+        .to_string()],
+        &[&overall_rule],
+    )
+    .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, "test rule_set".to_string())
+            .await;
+
+    // --------------------------------
+    // Validate fail before the reveal date
+    // --------------------------------
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+
+    // Create a `validate` instruction.
+    let validate_ix = ValidateBuilder::new()
+        .rule_set_pda(rule_set_addr)
+        .mint(mint)
+        .additional_rule_accounts(vec![])
+        .build(ValidateArgs::V1 {
+            operation: Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            payload: Payload::default(),
+            update_rule_state: false,
+            rule_set_revision: None,
+        })
+        .unwrap()
+        .instruction();
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix.clone(), vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::TimeWindowCheckFailed);
+
+    // --------------------------------
+    // Validate pass at the reveal date
+    // --------------------------------
+    // Move forward and advance the clock to the reveal date.
+    context.warp_to_slot(3).unwrap();
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp = reveal;
+    context.set_sysvar(&clock);
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(&mut context, validate_ix.clone(), vec![], None).await;
+
+    // --------------------------------
+    // Validate fail at the end of the slot window
+    // --------------------------------
+    // Move forward to the end slot, which is excluded from the window.
+    context.warp_to_slot(END_SLOT as u64).unwrap();
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp = reveal;
+    context.set_sysvar(&clock);
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::TimeWindowCheckFailed);
+}
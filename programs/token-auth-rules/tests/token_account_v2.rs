@@ -0,0 +1,185 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    payload::{Payload, PayloadType},
+    state::{RuleSetV2, TokenAccount, TokenAccountCheck},
+};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{
+    create_associated_token_account, create_mint, process_instructions, program_test,
+    transfer_validate_ix, Operation, PayloadKey,
+};
+
+#[tokio::test]
+async fn token_account_v2() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create the token account
+    // --------------------------------
+    let mint = Keypair::new();
+    let owner = Keypair::new();
+
+    create_mint(
+        &mut context,
+        &mint,
+        &owner.pubkey(),
+        Some(&owner.pubkey()),
+        0,
+    )
+    .await
+    .unwrap();
+
+    let token_account = create_associated_token_account(&mut context, &owner, &mint.pubkey())
+        .await
+        .unwrap();
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // The destination token account must belong to the authority, hold the mint, have no
+    // delegate and not be frozen.
+    let rule = TokenAccount::serialize(
+        PayloadKey::Destination.to_string(),
+        &[
+            TokenAccountCheck::OwnerField {
+                field: PayloadKey::Authority.to_string(),
+            },
+            TokenAccountCheck::Mint {
+                pubkey: mint.pubkey(),
+            },
+            TokenAccountCheck::NoDelegate,
+            TokenAccountCheck::Frozen { frozen: false },
+        ],
+    )
+    .unwrap();
+
+    // Create a RuleSet.
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "test rule_set",
+        &[Operation::Transfer {
+            scenario: utils::TransferScenario::Holder,
+        }
+        .to_string()],
+        &[&rule],
+    )
+    .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, "test rule_set".to_string())
+            .await;
+
+    // --------------------------------
+    // Validate fail missing account
+    // --------------------------------
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint.pubkey(),
+        token_account_payload(token_account, owner.pubkey()),
+        vec![],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::MissingAccount);
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint.pubkey(),
+        token_account_payload(token_account, owner.pubkey()),
+        vec![AccountMeta::new_readonly(token_account, false)],
+    );
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // --------------------------------
+    // Validate fail owner mismatch
+    // --------------------------------
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint.pubkey(),
+        token_account_payload(token_account, Keypair::new().pubkey()),
+        vec![AccountMeta::new_readonly(token_account, false)],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::TokenAccountCheckFailed);
+
+    // --------------------------------
+    // Validate fail not a token account
+    // --------------------------------
+    // The mint is owned by the SPL Token program but is not a token account.
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint.pubkey(),
+        token_account_payload(mint.pubkey(), owner.pubkey()),
+        vec![AccountMeta::new_readonly(mint.pubkey(), false)],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::TokenAccountCheckFailed);
+
+    // --------------------------------
+    // Validate fail frozen account
+    // --------------------------------
+    let freeze_ix = spl_token::instruction::freeze_account(
+        &spl_token::ID,
+        &token_account,
+        &mint.pubkey(),
+        &owner.pubkey(),
+        &[],
+    )
+    .unwrap();
+
+    process_instructions(&mut context, &[freeze_ix], &[&owner])
+        .await
+        .unwrap();
+
+    // Move forward so the same `validate` transaction is processed again.
+    context.warp_to_slot(2).unwrap();
+
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint.pubkey(),
+        token_account_payload(token_account, owner.pubkey()),
+        vec![AccountMeta::new_readonly(token_account, false)],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::TokenAccountCheckFailed);
+}
+
+fn token_account_payload(destination: Pubkey, authority: Pubkey) -> Payload {
+    Payload::from([
+        (
+            PayloadKey::Destination.to_string(),
+            PayloadType::Pubkey(destination),
+        ),
+        (
+            PayloadKey::Authority.to_string(),
+            PayloadType::Pubkey(authority),
+        ),
+    ])
+}
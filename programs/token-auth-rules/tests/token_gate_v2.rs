@@ -0,0 +1,188 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    payload::{Payload, PayloadType},
+    state::{Operator, RuleSetV2, TokenGate},
+};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{
+    create_associated_token_account, create_mint, process_instructions, program_test,
+    transfer_validate_ix, Operation, PayloadKey,
+};
+
+#[tokio::test]
+async fn token_gate_v2() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create the pass mint
+    // --------------------------------
+    let pass_mint = Keypair::new();
+    let authority = Keypair::new();
+
+    create_mint(&mut context, &pass_mint, &authority.pubkey(), None, 0)
+        .await
+        .unwrap();
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    // The delegate must hold at least one token of the pass mint.
+    let rule = TokenGate::serialize(
+        PayloadKey::Holder.to_string(),
+        PayloadKey::Delegate.to_string(),
+        pass_mint.pubkey(),
+        Operator::GtEq,
+        1,
+    )
+    .unwrap();
+
+    // Create a RuleSet.
+    let rule_set = RuleSetV2::serialize(
+        context.payer.pubkey(),
+        "test rule_set",
+        &[Operation::Transfer {
+            scenario: utils::TransferScenario::Holder,
+        }
+        .to_string()],
+        &[&rule],
+    )
+    .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain_serialized!(&mut context, rule_set, "test rule_set".to_string())
+            .await;
+
+    // --------------------------------
+    // Validate fail empty token account
+    // --------------------------------
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+    let delegate = Keypair::new();
+
+    let pass_account =
+        create_associated_token_account(&mut context, &delegate, &pass_mint.pubkey())
+            .await
+            .unwrap();
+
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        token_gate_payload(pass_account, delegate.pubkey()),
+        vec![AccountMeta::new_readonly(pass_account, false)],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::TokenGateCheckFailed);
+
+    // --------------------------------
+    // Validate pass
+    // --------------------------------
+    let mint_to_ix = spl_token::instruction::mint_to(
+        &spl_token::ID,
+        &pass_mint.pubkey(),
+        &pass_account,
+        &authority.pubkey(),
+        &[],
+        1,
+    )
+    .unwrap();
+
+    process_instructions(&mut context, &[mint_to_ix], &[&authority])
+        .await
+        .unwrap();
+
+    // Move forward so the same `validate` transaction is processed again.
+    context.warp_to_slot(2).unwrap();
+
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        token_gate_payload(pass_account, delegate.pubkey()),
+        vec![AccountMeta::new_readonly(pass_account, false)],
+    );
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // --------------------------------
+    // Validate fail owner mismatch
+    // --------------------------------
+    // The token account does not belong to the delegate.
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        token_gate_payload(pass_account, Keypair::new().pubkey()),
+        vec![AccountMeta::new_readonly(pass_account, false)],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::TokenGateCheckFailed);
+
+    // --------------------------------
+    // Validate fail mint mismatch
+    // --------------------------------
+    // The delegate holds a token of a different mint.
+    let other_mint = Keypair::new();
+
+    create_mint(&mut context, &other_mint, &authority.pubkey(), None, 0)
+        .await
+        .unwrap();
+
+    let other_account =
+        create_associated_token_account(&mut context, &delegate, &other_mint.pubkey())
+            .await
+            .unwrap();
+
+    let mint_to_ix = spl_token::instruction::mint_to(
+        &spl_token::ID,
+        &other_mint.pubkey(),
+        &other_account,
+        &authority.pubkey(),
+        &[],
+        1,
+    )
+    .unwrap();
+
+    process_instructions(&mut context, &[mint_to_ix], &[&authority])
+        .await
+        .unwrap();
+
+    let validate_ix = transfer_validate_ix(
+        rule_set_addr,
+        mint,
+        token_gate_payload(other_account, delegate.pubkey()),
+        vec![AccountMeta::new_readonly(other_account, false)],
+    );
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::TokenGateCheckFailed);
+}
+
+fn token_gate_payload(token_account: Pubkey, delegate: Pubkey) -> Payload {
+    Payload::from([
+        (
+            PayloadKey::Holder.to_string(),
+            PayloadType::Pubkey(token_account),
+        ),
+        (
+            PayloadKey::Delegate.to_string(),
+            PayloadType::Pubkey(delegate),
+        ),
+    ])
+}
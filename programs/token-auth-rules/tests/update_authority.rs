@@ -0,0 +1,167 @@
+#![cfg(feature = "test-sbf")]
+
+pub mod utils;
+
+use mpl_token_auth_rules::{
+    error::RuleSetError,
+    instruction::{
+        builders::{AcceptUpdateAuthorityBuilder, TransferUpdateAuthorityBuilder, ValidateBuilder},
+        AcceptUpdateAuthorityArgs, InstructionBuilder, TransferUpdateAuthorityArgs, ValidateArgs,
+    },
+    payload::Payload,
+    state::{Rule, RuleSetV1},
+};
+use solana_program::system_instruction;
+use solana_program_test::tokio;
+use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use utils::{create_or_update_ix, process_instructions, program_test, Operation};
+
+#[tokio::test]
+async fn transfer_and_accept_update_authority() {
+    let mut context = program_test().start_with_context().await;
+    let creator = context.payer.pubkey();
+
+    // --------------------------------
+    // Create RuleSet
+    // --------------------------------
+    let mut rule_set = RuleSetV1::new("test rule_set".to_string(), creator);
+    rule_set
+        .add(
+            Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            Rule::Pass,
+        )
+        .unwrap();
+
+    // Put the RuleSet on chain.
+    let rule_set_addr =
+        create_rule_set_on_chain!(&mut context, rule_set, "test rule_set".to_string()).await;
+
+    // Fund the new update authority so it can pay for `RuleSet` updates.
+    let new_authority = Keypair::new();
+    let fund_ix = system_instruction::transfer(&creator, &new_authority.pubkey(), 1_000_000_000);
+    process_instructions(&mut context, &[fund_ix], &[])
+        .await
+        .unwrap();
+
+    // --------------------------------
+    // Transfer update authority
+    // --------------------------------
+    // Fail to transfer the update authority from an account that is not the update authority.
+    let transfer_ix = TransferUpdateAuthorityBuilder::new()
+        .payer(new_authority.pubkey())
+        .rule_set_pda(rule_set_addr)
+        .build(TransferUpdateAuthorityArgs::V1 {
+            rule_set_name: "test rule_set".to_string(),
+            new_update_authority: new_authority.pubkey(),
+        })
+        .unwrap()
+        .instruction();
+
+    let err = process_instructions(&mut context, &[transfer_ix], &[&new_authority])
+        .await
+        .unwrap_err();
+    assert_custom_error!(err, RuleSetError::DerivedKeyInvalid);
+
+    // Propose the new update authority.
+    let transfer_ix = TransferUpdateAuthorityBuilder::new()
+        .payer(creator)
+        .rule_set_pda(rule_set_addr)
+        .build(TransferUpdateAuthorityArgs::V1 {
+            rule_set_name: "test rule_set".to_string(),
+            new_update_authority: new_authority.pubkey(),
+        })
+        .unwrap()
+        .instruction();
+
+    process_instructions(&mut context, &[transfer_ix], &[])
+        .await
+        .unwrap();
+
+    // --------------------------------
+    // Accept update authority
+    // --------------------------------
+    // Fail to accept the update authority from an account that was not proposed.
+    let other_authority = Keypair::new();
+    let accept_ix = AcceptUpdateAuthorityBuilder::new()
+        .payer(other_authority.pubkey())
+        .rule_set_pda(rule_set_addr)
+        .build(AcceptUpdateAuthorityArgs::V1)
+        .unwrap()
+        .instruction();
+
+    let err = process_instructions(&mut context, &[accept_ix], &[&other_authority])
+        .await
+        .unwrap_err();
+    assert_custom_error!(err, RuleSetError::PendingUpdateAuthorityMismatch);
+
+    // Accept the update authority.
+    let accept_ix = AcceptUpdateAuthorityBuilder::new()
+        .payer(new_authority.pubkey())
+        .rule_set_pda(rule_set_addr)
+        .build(AcceptUpdateAuthorityArgs::V1)
+        .unwrap()
+        .instruction();
+
+    process_instructions(&mut context, &[accept_ix], &[&new_authority])
+        .await
+        .unwrap();
+
+    // --------------------------------
+    // Update RuleSet
+    // --------------------------------
+    // The `RuleSet` owner stays the creator, so the PDA address does not change.
+    let mut rule_set = RuleSetV1::new("test rule_set".to_string(), creator);
+    rule_set
+        .add(
+            Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            Rule::Not {
+                rule: Box::new(Rule::Pass),
+            },
+        )
+        .unwrap();
+
+    // Fail to update the `RuleSet` with the creator.
+    let update_ix = create_or_update_ix(creator, rule_set_addr, &rule_set);
+    let err = process_instructions(&mut context, &[update_ix], &[])
+        .await
+        .unwrap_err();
+    assert_custom_error!(err, RuleSetError::UpdateAuthorityMismatch);
+
+    // Update the `RuleSet` with the new update authority.
+    let update_ix = create_or_update_ix(new_authority.pubkey(), rule_set_addr, &rule_set);
+    process_instructions(&mut context, &[update_ix], &[&new_authority])
+        .await
+        .unwrap();
+
+    // --------------------------------
+    // Validate fail
+    // --------------------------------
+    // Create a `validate` instruction.
+    let validate_ix = ValidateBuilder::new()
+        .rule_set_pda(rule_set_addr)
+        .mint(Keypair::new().pubkey())
+        .additional_rule_accounts(vec![])
+        .build(ValidateArgs::V1 {
+            operation: Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            payload: Payload::default(),
+            update_rule_state: false,
+            rule_set_revision: None,
+        })
+        .unwrap()
+        .instruction();
+
+    // Fail to validate Transfer operation against the updated `RuleSet`.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::UnexpectedRuleSetFailure);
+}
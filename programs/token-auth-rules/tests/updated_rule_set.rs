@@ -7,17 +7,23 @@ use mpl_token_auth_rules::{
     error::RuleSetError,
     instruction::{builders::ValidateBuilder, InstructionBuilder, ValidateArgs},
     payload::{Payload, PayloadType},
+    pda::find_rule_set_address,
     state::{
-        CompareOp, Rule, RuleSetHeader, RuleSetRevisionMapV1, RuleSetV1, RULE_SET_REV_MAP_VERSION,
-        RULE_SET_SERIALIZED_HEADER_LEN,
+        CompareOp, Rule, RuleSetHeader, RuleSetRevisionMapV1, RuleSetRevisionMapV2, RuleSetV1,
+        RULE_SET_REV_MAP_V2_VERSION, RULE_SET_REV_MAP_VERSION, RULE_SET_SERIALIZED_HEADER_LEN,
     },
     types::LibVersion,
+    utils::compute_rule_set_hash,
 };
 use rmp_serde::Serializer;
 use serde::Serialize;
 use solana_program::instruction::AccountMeta;
 use solana_program_test::tokio;
-use solana_sdk::{signature::Signer, signer::keypair::Keypair};
+use solana_sdk::{
+    account::{AccountSharedData, WritableAccount},
+    signature::Signer,
+    signer::keypair::Keypair,
+};
 use utils::{cmp_slice, program_test, Operation, PayloadKey};
 
 #[tokio::test]
@@ -232,8 +238,9 @@ async fn test_update_ruleset_data_integrity() {
         .unwrap()
         .data;
 
-    // Check all the RuleSets, saving their start locations for later use.
+    // Check all the RuleSets, saving their start locations and hashes for later use.
     let mut offsets = vec![RULE_SET_SERIALIZED_HEADER_LEN];
+    let mut hashes = vec![];
     for n in 0..rule_sets.len() {
         // Offset n is the `RuleSet` lib version location.
         let rule_set_version_loc = offsets[n];
@@ -262,7 +269,8 @@ async fn test_update_ruleset_data_integrity() {
         );
 
         // The end of `RuleSet` n is the offset for the next item.
-        offsets.push(rule_set_end)
+        offsets.push(rule_set_end);
+        hashes.push(compute_rule_set_hash(&serialized_rule_set));
     }
 
     // The final offset is the end of the last `RuleSet` and thus the start of the revision map.
@@ -270,18 +278,21 @@ async fn test_update_ruleset_data_integrity() {
 
     // Check the revision map version.
     assert_eq!(
-        data[rev_map_version_loc], RULE_SET_REV_MAP_VERSION,
+        data[rev_map_version_loc], RULE_SET_REV_MAP_V2_VERSION,
         "The buffer doesn't match the revision map version"
     );
 
     // Create revision map using the known locations of the two `RuleSet`s in this test.
-    let mut revision_map = RuleSetRevisionMapV1::default();
+    let mut revision_map = RuleSetRevisionMapV2::default();
 
     // Push the `RuleSet` locations.
     for loc in offsets.iter().take(rule_sets.len()) {
         revision_map.rule_set_revisions.push(*loc);
     }
 
+    // Push the `RuleSet` hashes.
+    revision_map.revision_hashes = hashes;
+
     // Borsh serialize the revision map.
     let mut serialized_rev_map = Vec::new();
     revision_map.serialize(&mut serialized_rev_map).unwrap();
@@ -593,3 +604,252 @@ async fn test_correct_rule_set_is_used_after_update() {
     // Validate Transfer operation.
     process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
 }
+
+#[tokio::test]
+async fn test_validate_with_rule_set_hash() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create RuleSets
+    // --------------------------------
+    let additional_signer = Keypair::new();
+    let adtl_signer_rule = Rule::AdditionalSigner {
+        account: additional_signer.pubkey(),
+    };
+
+    // Create a RuleSet.
+    let mut first_rule_set = RuleSetV1::new("test rule_set".to_string(), context.payer.pubkey());
+    first_rule_set
+        .add(
+            Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            adtl_signer_rule,
+        )
+        .unwrap();
+
+    // Put the RuleSet on chain.
+    let _rule_set_addr = create_rule_set_on_chain!(
+        &mut context,
+        first_rule_set.clone(),
+        "test rule_set".to_string()
+    )
+    .await;
+
+    // Create a new RuleSet.
+    let mut second_rule_set = RuleSetV1::new("test rule_set".to_string(), context.payer.pubkey());
+    second_rule_set
+        .add(
+            Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            Rule::Pass,
+        )
+        .unwrap();
+
+    // Put the updated RuleSet on chain.
+    let rule_set_addr = create_rule_set_on_chain!(
+        &mut context,
+        second_rule_set.clone(),
+        "test rule_set".to_string()
+    )
+    .await;
+
+    // Compute the expected hash of each revision.
+    let mut serialized_rule_set = Vec::new();
+    first_rule_set
+        .serialize(&mut Serializer::new(&mut serialized_rule_set))
+        .unwrap();
+    let first_hash = compute_rule_set_hash(&serialized_rule_set);
+
+    let mut serialized_rule_set = Vec::new();
+    second_rule_set
+        .serialize(&mut Serializer::new(&mut serialized_rule_set))
+        .unwrap();
+    let second_hash = compute_rule_set_hash(&serialized_rule_set);
+
+    // --------------------------------
+    // Validate pass with the hash of the latest revision.
+    // --------------------------------
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+
+    let validate_ix = ValidateBuilder::new()
+        .rule_set_pda(rule_set_addr)
+        .mint(mint)
+        .additional_rule_accounts(vec![])
+        .build(ValidateArgs::V2 {
+            operation: Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            payload: Payload::default(),
+            update_rule_state: false,
+            rule_set_revision: None,
+            rule_set_hash: second_hash,
+        })
+        .unwrap()
+        .instruction();
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // --------------------------------
+    // Validate pass with the hash of the pinned first revision.
+    // --------------------------------
+    let validate_ix = ValidateBuilder::new()
+        .rule_set_pda(rule_set_addr)
+        .mint(mint)
+        .additional_rule_accounts(vec![AccountMeta::new_readonly(
+            additional_signer.pubkey(),
+            true,
+        )])
+        .build(ValidateArgs::V2 {
+            operation: Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            payload: Payload::default(),
+            update_rule_state: false,
+            rule_set_revision: Some(0),
+            rule_set_hash: first_hash,
+        })
+        .unwrap()
+        .instruction();
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![&additional_signer], None).await;
+
+    // --------------------------------
+    // Validate fail when the hash does not match the revision.
+    // --------------------------------
+    let validate_ix = ValidateBuilder::new()
+        .rule_set_pda(rule_set_addr)
+        .mint(mint)
+        .additional_rule_accounts(vec![])
+        .build(ValidateArgs::V2 {
+            operation: Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            payload: Payload::default(),
+            update_rule_state: false,
+            rule_set_revision: None,
+            rule_set_hash: first_hash,
+        })
+        .unwrap()
+        .instruction();
+
+    // Fail to validate Transfer operation.
+    let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // Check that error is what we expect.
+    assert_custom_error!(err, RuleSetError::RuleSetHashMismatch);
+}
+
+#[tokio::test]
+async fn test_validate_with_rule_set_hash_unavailable() {
+    let mut context = program_test().start_with_context().await;
+
+    // --------------------------------
+    // Create a legacy RuleSet
+    // --------------------------------
+    let mut rule_set = RuleSetV1::new("test rule_set".to_string(), context.payer.pubkey());
+    rule_set
+        .add(
+            Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            Rule::Pass,
+        )
+        .unwrap();
+
+    let mut serialized_rule_set = Vec::new();
+    rule_set
+        .serialize(&mut Serializer::new(&mut serialized_rule_set))
+        .unwrap();
+
+    // Lay out the PDA as written before hashes were stored, with a `RuleSetRevisionMapV1`.
+    let rev_map_version_loc = RULE_SET_SERIALIZED_HEADER_LEN + 1 + serialized_rule_set.len();
+
+    let mut data = Vec::new();
+    RuleSetHeader::new(rev_map_version_loc)
+        .serialize(&mut data)
+        .unwrap();
+    data.push(LibVersion::V1 as u8);
+    data.extend_from_slice(&serialized_rule_set);
+    data.push(RULE_SET_REV_MAP_VERSION);
+    RuleSetRevisionMapV1 {
+        rule_set_revisions: vec![RULE_SET_SERIALIZED_HEADER_LEN],
+    }
+    .serialize(&mut data)
+    .unwrap();
+
+    let (rule_set_addr, _) =
+        find_rule_set_address(context.payer.pubkey(), "test rule_set".to_string());
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let mut account = AccountSharedData::new(
+        rent.minimum_balance(data.len()),
+        data.len(),
+        &mpl_token_auth_rules::ID,
+    );
+    account.set_data_from_slice(&data);
+    context.set_account(&rule_set_addr, &account);
+
+    // --------------------------------
+    // Validate pass without a hash.
+    // --------------------------------
+    // Create a Keypair to simulate a token mint address.
+    let mint = Keypair::new().pubkey();
+
+    let validate_ix = ValidateBuilder::new()
+        .rule_set_pda(rule_set_addr)
+        .mint(mint)
+        .additional_rule_accounts(vec![])
+        .build(ValidateArgs::V1 {
+            operation: Operation::Transfer {
+                scenario: utils::TransferScenario::Holder,
+            }
+            .to_string(),
+            payload: Payload::default(),
+            update_rule_state: false,
+            rule_set_revision: None,
+        })
+        .unwrap()
+        .instruction();
+
+    // Validate Transfer operation.
+    process_passing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+    // --------------------------------
+    // Validate fail with a hash, since none was stored for the revision.
+    // --------------------------------
+    for rule_set_hash in [[0; 32], compute_rule_set_hash(&serialized_rule_set)] {
+        let validate_ix = ValidateBuilder::new()
+            .rule_set_pda(rule_set_addr)
+            .mint(mint)
+            .additional_rule_accounts(vec![])
+            .build(ValidateArgs::V2 {
+                operation: Operation::Transfer {
+                    scenario: utils::TransferScenario::Holder,
+                }
+                .to_string(),
+                payload: Payload::default(),
+                update_rule_state: false,
+                rule_set_revision: None,
+                rule_set_hash,
+            })
+            .unwrap()
+            .instruction();
+
+        // Fail to validate Transfer operation.
+        let err = process_failing_validate_ix!(&mut context, validate_ix, vec![], None).await;
+
+        // Check that error is what we expect.
+        assert_custom_error!(err, RuleSetError::RuleSetHashUnavailable);
+    }
+}
@@ -1,14 +1,19 @@
 use mpl_token_auth_rules::{
     instruction::{
-        builders::{CreateOrUpdateBuilder, PuffRuleSetBuilder, WriteToBufferBuilder},
-        CreateOrUpdateArgs, InstructionBuilder, PuffRuleSetArgs, WriteToBufferArgs,
+        builders::{
+            CreateOrUpdateBuilder, PuffRuleSetBuilder, ValidateBuilder, WriteToBufferBuilder,
+        },
+        CreateOrUpdateArgs, InstructionBuilder, PuffRuleSetArgs, ValidateArgs, WriteToBufferArgs,
     },
-    payload::ProofInfo,
+    payload::{Payload, ProofInfo},
     state::RuleSetV1,
 };
 use rmp_serde::Serializer;
 use serde::Serialize;
-use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
 use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
 use solana_sdk::{
     compute_budget::ComputeBudgetInstruction, program_pack::Pack, signature::Signer,
@@ -161,6 +166,9 @@ pub enum PayloadKey {
     DestinationSeeds,
     /// A token holder.
     Holder,
+    /// Merkle proof for the mint of the operation, e.g. when the mint is a member of a Merkle
+    /// tree.
+    MintProof,
     /// The source of the operation, e.g. the owner initiating a transfer.
     Source,
     /// Seeds for a PDA source of the operation, e.g. when the source is a PDA.
@@ -187,6 +195,7 @@ impl ToString for PayloadKey {
             PayloadKey::DestinationSeeds => "DestinationSeeds",
             PayloadKey::DestinationProof => "DestinationProof",
             PayloadKey::Holder => "Holder",
+            PayloadKey::MintProof => "MintProof",
             PayloadKey::Source => "Source",
             PayloadKey::SourceSeeds => "SourceSeeds",
         }
@@ -696,6 +705,85 @@ pub async fn create_associated_token_account(
     ))
 }
 
+pub async fn process_instructions(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    additional_signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let mut signers = vec![&context.payer];
+    signers.extend(additional_signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &signers,
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await
+}
+
+pub fn create_or_update_ix(
+    payer: Pubkey,
+    rule_set_addr: Pubkey,
+    rule_set: &RuleSetV1,
+) -> Instruction {
+    // Serialize the RuleSet using RMP serde.
+    let mut serialized_rule_set = Vec::new();
+    rule_set
+        .serialize(&mut Serializer::new(&mut serialized_rule_set))
+        .unwrap();
+
+    CreateOrUpdateBuilder::new()
+        .payer(payer)
+        .rule_set_pda(rule_set_addr)
+        .build(CreateOrUpdateArgs::V1 {
+            serialized_rule_set,
+        })
+        .unwrap()
+        .instruction()
+}
+
+pub fn validate_ix(
+    rule_set_addr: Pubkey,
+    mint: Pubkey,
+    operation: String,
+    payload: Payload,
+    additional_rule_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    ValidateBuilder::new()
+        .rule_set_pda(rule_set_addr)
+        .mint(mint)
+        .additional_rule_accounts(additional_rule_accounts)
+        .build(ValidateArgs::V1 {
+            operation,
+            payload,
+            update_rule_state: false,
+            rule_set_revision: None,
+        })
+        .unwrap()
+        .instruction()
+}
+
+// Validate the Transfer operation of a holder, which most tests put their rules on.
+pub fn transfer_validate_ix(
+    rule_set_addr: Pubkey,
+    mint: Pubkey,
+    payload: Payload,
+    additional_rule_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    validate_ix(
+        rule_set_addr,
+        mint,
+        Operation::Transfer {
+            scenario: TransferScenario::Holder,
+        }
+        .to_string(),
+        payload,
+        additional_rule_accounts,
+    )
+}
+
 pub fn cmp_slice<T: PartialEq>(a: &[T], b: &[T]) -> bool {
     let matching = a.iter().zip(b.iter()).filter(|&(a, b)| a == b).count();
     matching == a.len() && matching == b.len()
@@ -455,6 +455,7 @@ async fn validate_update_rule_state_incorrect_auth() {
     let rule_authority = Keypair::new();
     let rule = Rule::Frequency {
         authority: rule_authority.pubkey(),
+        period: 60,
     };
 
     // Create a RuleSet.
@@ -518,6 +519,7 @@ async fn validate_update_rule_state_missing_auth() {
     let rule_authority = Keypair::new();
     let rule = Rule::Frequency {
         authority: rule_authority.pubkey(),
+        period: 60,
     };
 
     // Create a RuleSet.